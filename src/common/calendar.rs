@@ -0,0 +1,76 @@
+//! Conversions between dates in the proleptic gregorian calendar and days since the unix epoch.
+
+/// Days since the unix epoch of a date in the proleptic gregorian calendar
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Date in the proleptic gregorian calendar of the given days since the unix epoch
+#[cfg(feature = "helix")]
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Parse a RFC3339 timestamp, e.g. `2021-02-19T23:47:00.8091512Z`, into seconds and nanoseconds since the unix epoch
+#[cfg(all(
+    feature = "eventsub",
+    any(all(feature = "tower", feature = "hmac"), feature = "pubsub")
+))]
+pub(crate) fn parse_rfc3339(s: &str) -> Option<(i64, u32)> {
+    fn num(s: &str) -> Option<i64> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        s.parse().ok()
+    }
+
+    let (date, time) = s.split_once(['T', 't'])?;
+    let mut date = date.splitn(3, '-');
+    let (year, month, day) = (num(date.next()?)?, num(date.next()?)?, num(date.next()?)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let days = days_from_civil(year, month as u32, day as u32);
+
+    let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, 0)
+    } else {
+        let split = time.rfind(['+', '-'])?;
+        let (time, offset) = time.split_at(split);
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes) = offset[1..].split_once(':')?;
+        (time, sign * (num(hours)? * 3600 + num(minutes)? * 60))
+    };
+    let (time, nanos) = match time.split_once('.') {
+        Some((time, fraction)) => {
+            let digits = fraction.get(..fraction.len().min(9))?;
+            let nanos = num(digits)? * 10_i64.pow(9 - digits.len() as u32);
+            (time, nanos)
+        }
+        None => (time, 0),
+    };
+    let mut time = time.splitn(3, ':');
+    let (hour, minute, second) = (num(time.next()?)?, num(time.next()?)?, num(time.next()?)?);
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let secs = days * 86400 + hour * 3600 + minute * 60 + second - offset;
+    Some((secs, nanos as u32))
+}
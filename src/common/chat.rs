@@ -1,8 +1,12 @@
 //! Common types for chat messages.
+//!
+//! See [`render`] for rendering messages.
 
 use crate::types;
 use serde_derive::{Deserialize, Serialize};
 
+pub mod render;
+
 /// A message
 // XXX: this struct can never be deny_unknown_fields
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -0,0 +1,377 @@
+//! Rendering chat messages as plain text, HTML or a list of tokens.
+//!
+//! Chat messages from [`channel.chat.message`](crate::eventsub::channel::ChannelChatMessageV1), [`channel.chat.notification`](crate::eventsub::channel::ChannelChatNotificationV1)
+//! and messages held by automod all implement [`RenderMessage`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use twitch_api::common::chat::{
+//!     render::{HtmlRenderer, RenderMessage, Token},
+//!     Message,
+//! };
+//!
+//! let message: Message = serde_json::from_str(
+//!     r#"{"text":"Hi chat <3 Kappa","fragments":[{"type":"text","text":"Hi chat <3 "},{"type":"emote","text":"Kappa","emote":{"id":"25","emote_set_id":"0","owner_id":"0","format":["static"]}}]}"#,
+//! )?;
+//! assert_eq!(message.to_plain_text(), "Hi chat <3 Kappa");
+//! assert!(matches!(message.tokens()[1], Token::Emote { code: "Kappa", .. }));
+//! assert_eq!(
+//!     message.to_html(&HtmlRenderer::new()),
+//!     r#"Hi chat &lt;3 <img class="emote" src="https://static-cdn.jtvnw.net/emoticons/v2/25/default/dark/1.0" alt="Kappa" title="Kappa">"#
+//! );
+//! # Ok::<(), Box<dyn std::error::Error + 'static>>(())
+//! ```
+use std::fmt::Write as _;
+
+use super::{Fragment, Message};
+use crate::types;
+
+/// A part of a chat message
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Token<'a> {
+    /// Plain text
+    Text(&'a str),
+    /// An emote
+    Emote {
+        /// The code of the emote, e.g `Kappa`
+        code: &'a str,
+        /// ID of the emote
+        id: &'a types::EmoteIdRef,
+        /// The emote is available as an animated GIF
+        animated: bool,
+    },
+    /// A cheermote
+    Cheermote {
+        /// Message text of the cheermote, e.g `Cheer100`
+        text: &'a str,
+        /// Prefix of the cheermote, e.g `Cheer`
+        prefix: &'a str,
+        /// Amount of bits cheered
+        bits: i32,
+        /// Tier level of the cheermote
+        tier: i32,
+    },
+    /// A mention of a user
+    Mention {
+        /// Message text of the mention, e.g `@justintv`
+        text: &'a str,
+        /// ID of the mentioned user
+        user_id: &'a types::UserIdRef,
+        /// Login of the mentioned user
+        user_login: &'a types::UserNameRef,
+    },
+}
+
+impl<'a> Token<'a> {
+    /// Get the text of this token as it was written in chat
+    pub fn text(&self) -> &'a str {
+        match self {
+            Self::Text(text) => text,
+            Self::Emote { code, .. } => code,
+            Self::Cheermote { text, .. } => text,
+            Self::Mention { text, .. } => text,
+        }
+    }
+}
+
+/// A chat message that can be rendered
+pub trait RenderMessage {
+    /// Get the parts of this message
+    fn tokens(&self) -> Vec<Token<'_>>;
+
+    /// Render the message as plain text, with emotes and cheermotes as their codes
+    fn to_plain_text(&self) -> String { self.tokens().iter().map(Token::text).collect() }
+
+    /// Render the message as HTML
+    fn to_html(&self, renderer: &HtmlRenderer<'_>) -> String { renderer.render(&self.tokens()) }
+}
+
+impl RenderMessage for Message {
+    fn tokens(&self) -> Vec<Token<'_>> {
+        self.fragments
+            .iter()
+            .map(|fragment| match fragment {
+                Fragment::Text { text } => Token::Text(text),
+                Fragment::Emote { text, emote } => Token::Emote {
+                    code: text,
+                    id: &emote.id,
+                    animated: emote
+                        .format
+                        .contains(&types::EmoteAnimationSetting::Animated),
+                },
+                Fragment::Cheermote { text, cheermote } => Token::Cheermote {
+                    text,
+                    prefix: &cheermote.prefix,
+                    bits: cheermote.bits,
+                    tier: cheermote.tier,
+                },
+                Fragment::Mention { text, mention } => Token::Mention {
+                    text,
+                    user_id: &mention.user_id,
+                    user_login: &mention.user_login,
+                },
+            })
+            .collect()
+    }
+}
+
+/// Automod messages don't know the formats of emotes, so they are always rendered as static.
+#[cfg(feature = "eventsub")]
+#[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
+impl RenderMessage for crate::eventsub::automod::message::AutomodMessage {
+    fn tokens(&self) -> Vec<Token<'_>> {
+        use crate::eventsub::automod::message::AutomodMessageFragment;
+
+        self.fragments
+            .iter()
+            .map(|fragment| match fragment {
+                AutomodMessageFragment::Text { text } => Token::Text(text),
+                AutomodMessageFragment::Emote { text, emote } => Token::Emote {
+                    code: text,
+                    id: &emote.id,
+                    animated: false,
+                },
+                AutomodMessageFragment::Cheermote { text, cheermote } => Token::Cheermote {
+                    text,
+                    prefix: &cheermote.prefix,
+                    bits: cheermote.bits,
+                    tier: cheermote.tier,
+                },
+            })
+            .collect()
+    }
+}
+
+macro_rules! render_message {
+    ($($payload:ty),* $(,)?) => {
+        $(
+            #[cfg(feature = "eventsub")]
+            #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
+            impl RenderMessage for $payload {
+                fn tokens(&self) -> Vec<Token<'_>> { self.message.tokens() }
+            }
+        )*
+    };
+}
+
+render_message!(
+    crate::eventsub::channel::ChannelChatMessageV1Payload,
+    crate::eventsub::channel::ChannelChatNotificationV1Payload,
+    crate::eventsub::channel::ChannelChatUserMessageHoldV1Payload,
+    crate::eventsub::automod::message::AutomodMessageHoldV1Payload,
+    crate::eventsub::automod::message::AutomodMessageHoldV2Payload,
+);
+
+/// Background theme of images
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Theme {
+    /// Images for a dark background
+    #[default]
+    Dark,
+    /// Images for a light background
+    Light,
+}
+
+/// Size of images
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Scale {
+    /// Small, 28 × 28 for emotes
+    #[default]
+    Small,
+    /// Medium, 56 × 56 for emotes
+    Medium,
+    /// Large, 112 × 112 for emotes
+    Large,
+}
+
+/// Renders [tokens](Token) as HTML.
+///
+/// Text is escaped, emotes and cheermotes are rendered as `<img>` with the classes `emote` and `cheermote`,
+/// the amount of a cheer is rendered in a `<span class="cheer-amount">` with the color of the tier,
+/// and mentions are rendered in a `<span class="mention">`.
+///
+/// Cheermotes are only rendered as images if their [prefix](Token::Cheermote::prefix) is found in [`HtmlRenderer::cheermotes`].
+#[derive(Clone, Debug, Default)]
+pub struct HtmlRenderer<'a> {
+    theme: Theme,
+    scale: Scale,
+    animated: bool,
+    #[cfg(feature = "helix")]
+    cheermotes: &'a [crate::helix::bits::Cheermote],
+    #[cfg(not(feature = "helix"))]
+    _pd: std::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> HtmlRenderer<'a> {
+    /// Create a renderer for static images on a dark background in the smallest size
+    pub fn new() -> Self { Self::default() }
+
+    /// Set the background theme
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Set the size of images
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Use animated images where available
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    /// Use these cheermotes, as returned by [Get Cheermotes](crate::helix::bits::get_cheermotes), for the images of cheers
+    #[cfg(feature = "helix")]
+    #[cfg_attr(nightly, doc(cfg(feature = "helix")))]
+    pub fn cheermotes(mut self, cheermotes: &'a [crate::helix::bits::Cheermote]) -> Self {
+        self.cheermotes = cheermotes;
+        self
+    }
+
+    /// Render tokens as HTML
+    pub fn render(&self, tokens: &[Token<'_>]) -> String {
+        let mut html = String::new();
+        for token in tokens {
+            match token {
+                Token::Text(text) => escape(&mut html, text),
+                Token::Emote { code, id, animated } => {
+                    let url = types::EmoteUrlBuilder::new(id);
+                    let url = match self.scale {
+                        Scale::Small => url.size_1x(),
+                        Scale::Medium => url.size_2x(),
+                        Scale::Large => url.size_3x(),
+                    };
+                    let url = match self.theme {
+                        Theme::Dark => url.dark_mode(),
+                        Theme::Light => url.light_mode(),
+                    };
+                    let url = if self.animated && *animated {
+                        url.animation_animated()
+                    } else {
+                        url.animation_default()
+                    };
+                    image(&mut html, "emote", &url.render(), code);
+                }
+                Token::Cheermote {
+                    text, prefix, bits, ..
+                } => {
+                    if !self.cheermote(&mut html, text, prefix, *bits) {
+                        escape(&mut html, text);
+                    }
+                }
+                Token::Mention { text, .. } => {
+                    html.push_str(r#"<span class="mention">"#);
+                    escape(&mut html, text);
+                    html.push_str("</span>");
+                }
+            }
+        }
+        html
+    }
+
+    /// Render a cheermote, returns `false` if the cheermote is unknown
+    #[cfg(feature = "helix")]
+    fn cheermote(&self, html: &mut String, text: &str, prefix: &str, bits: i32) -> bool {
+        let Some(tier) = self
+            .cheermotes
+            .iter()
+            .find(|cheermote| cheermote.prefix.eq_ignore_ascii_case(prefix))
+            .and_then(|cheermote| cheermote.tier(bits.into()))
+        else {
+            return false;
+        };
+        image(
+            html,
+            "cheermote",
+            tier.image_url(self.theme, self.scale, self.animated),
+            text,
+        );
+        html.push_str(r#"<span class="cheer-amount" style="color: "#);
+        escape(html, &tier.color);
+        let _ = write!(html, r#"">{bits}</span>"#);
+        true
+    }
+
+    #[cfg(not(feature = "helix"))]
+    fn cheermote(&self, _: &mut String, _: &str, _: &str, _: i32) -> bool { false }
+}
+
+fn image(html: &mut String, class: &str, url: &str, alt: &str) {
+    let _ = write!(html, r#"<img class="{class}" src=""#);
+    escape(html, url);
+    html.push_str(r#"" alt=""#);
+    escape(html, alt);
+    html.push_str(r#"" title=""#);
+    escape(html, alt);
+    html.push_str(r#"">"#);
+}
+
+fn escape(html: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_message() {
+        let message: Message = serde_json::from_str(
+            r#"{"text":"@justintv cheer100 <b>","fragments":[{"type":"mention","text":"@justintv","mention":{"user_id":"1337","user_name":"JustinTV","user_login":"justintv"}},{"type":"text","text":" "},{"type":"cheermote","text":"cheer100","cheermote":{"prefix":"cheer","bits":100,"tier":100}},{"type":"text","text":" <b>"}]}"#,
+        )
+        .unwrap();
+        let tokens = message.tokens();
+        assert_eq!(tokens.len(), 4);
+        assert!(matches!(
+            tokens[0],
+            Token::Mention {
+                text: "@justintv",
+                ..
+            }
+        ));
+        assert!(matches!(tokens[2], Token::Cheermote { bits: 100, .. }));
+        assert_eq!(message.to_plain_text(), message.text);
+        assert_eq!(
+            message.to_html(&HtmlRenderer::new()),
+            r#"<span class="mention">@justintv</span> cheer100 &lt;b&gt;"#
+        );
+    }
+
+    #[cfg(feature = "helix")]
+    #[test]
+    fn render_cheermote() {
+        let cheermotes: Vec<crate::helix::bits::Cheermote> = serde_json::from_str(
+            r##"[{"prefix":"Cheer","tiers":[{"min_bits":1,"id":"1","color":"#979797","images":{"dark":{"animated":{"1":"dark/animated/1/1.gif","1.5":"dark/animated/1/1.5.gif","2":"dark/animated/1/2.gif","3":"dark/animated/1/3.gif","4":"dark/animated/1/4.gif"},"static":{"1":"dark/static/1/1.png","1.5":"dark/static/1/1.5.png","2":"dark/static/1/2.png","3":"dark/static/1/3.png","4":"dark/static/1/4.png"}},"light":{"animated":{"1":"light/animated/1/1.gif","1.5":"light/animated/1/1.5.gif","2":"light/animated/1/2.gif","3":"light/animated/1/3.gif","4":"light/animated/1/4.gif"},"static":{"1":"light/static/1/1.png","1.5":"light/static/1/1.5.png","2":"light/static/1/2.png","3":"light/static/1/3.png","4":"light/static/1/4.png"}}},"can_cheer":true,"show_in_bits_card":true},{"min_bits":100,"id":"100","color":"#9c3ee8","images":{"dark":{"animated":{"1":"dark/animated/100/1.gif","1.5":"dark/animated/100/1.5.gif","2":"dark/animated/100/2.gif","3":"dark/animated/100/3.gif","4":"dark/animated/100/4.gif"},"static":{"1":"dark/static/100/1.png","1.5":"dark/static/100/1.5.png","2":"dark/static/100/2.png","3":"dark/static/100/3.png","4":"dark/static/100/4.png"}},"light":{"animated":{"1":"light/animated/100/1.gif","1.5":"light/animated/100/1.5.gif","2":"light/animated/100/2.gif","3":"light/animated/100/3.gif","4":"light/animated/100/4.gif"},"static":{"1":"light/static/100/1.png","1.5":"light/static/100/1.5.png","2":"light/static/100/2.png","3":"light/static/100/3.png","4":"light/static/100/4.png"}}},"can_cheer":true,"show_in_bits_card":true}],"type":"global_first_party","order":1,"last_updated":"2018-05-22T00:06:04Z","is_charitable":false}]"##,
+        )
+        .unwrap();
+        let tokens = [Token::Cheermote {
+            text: "cheer150",
+            prefix: "cheer",
+            bits: 150,
+            tier: 100,
+        }];
+        assert_eq!(
+            HtmlRenderer::new()
+                .theme(Theme::Light)
+                .animated(true)
+                .cheermotes(&cheermotes)
+                .render(&tokens),
+            r#"<img class="cheermote" src="light/animated/100/1.gif" alt="cheer150" title="cheer150"><span class="cheer-amount" style="color: #9c3ee8">150</span>"#
+        );
+    }
+}
@@ -1,4 +1,15 @@
 //! Common types between Helix and EventSub.
 
+#[cfg(any(
+    feature = "helix",
+    all(
+        feature = "eventsub",
+        any(all(feature = "tower", feature = "hmac"), feature = "pubsub")
+    )
+))]
+pub(crate) mod calendar;
 pub mod chat;
 pub mod hypetrain;
+#[cfg(feature = "twitch_oauth2")]
+#[cfg_attr(nightly, doc(cfg(feature = "twitch_oauth2")))]
+pub mod scopes;
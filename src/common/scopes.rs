@@ -0,0 +1,214 @@
+//! Planning of the scopes needed for requests and subscriptions.
+//!
+//! Every [request](crate::helix::Request) and [subscription](crate::eventsub::EventSubscription) declares the scopes it needs as a [`Validator`].
+//! A [`ScopePlanner`] combines these into the smallest set of scopes to ask for when authorizing a user.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use twitch_api::{
+//!     common::scopes::ScopePlanner, eventsub, helix, twitch_oauth2::Scope,
+//! };
+//!
+//! let scopes = ScopePlanner::new()
+//!     .request::<helix::moderation::BanUserRequest>()
+//!     .request::<helix::chat::SendChatMessageRequest>()
+//!     .subscription::<eventsub::channel::ChannelChatMessageV1>()
+//!     .scopes();
+//! assert!(scopes.contains(&Scope::ModeratorManageBannedUsers));
+//! assert!(scopes.contains(&Scope::UserReadChat));
+//! ```
+//!
+//! The scopes can also be combined at compile time with [`validator!`](twitch_oauth2::validator), and planned with [`missing_scopes`]
+//!
+//! ```rust
+//! use twitch_api::{
+//!     common::scopes::missing_scopes, eventsub, helix, twitch_oauth2::{validator, Scope, Validator},
+//! };
+//!
+//! const SCOPES: Validator = validator![
+//!     <helix::moderation::BanUserRequest as helix::Request>::SCOPE,
+//!     <eventsub::channel::ChannelChatMessageV1 as eventsub::EventSubscription>::SCOPE,
+//! ];
+//! let missing = missing_scopes(&SCOPES, &[Scope::UserReadChat]);
+//! assert!(missing.contains(&Scope::ModeratorManageBannedUsers));
+//! assert!(!missing.contains(&Scope::UserReadChat));
+//! ```
+use twitch_oauth2::{Scope, Validator};
+
+/// Combine the scopes needed for requests and subscriptions into a minimal set of scopes.
+///
+/// See the [module level documentation](self)
+#[derive(Debug, Clone, Default)]
+pub struct ScopePlanner {
+    required: Vec<Validator>,
+    optional: Vec<Scope>,
+    include_optional: bool,
+}
+
+impl ScopePlanner {
+    /// Create a new, empty planner
+    pub fn new() -> Self { Self::default() }
+
+    /// Add the scopes needed for the request `R`
+    #[cfg(feature = "helix")]
+    #[cfg_attr(nightly, doc(cfg(feature = "helix")))]
+    pub fn request<R: crate::helix::Request>(self) -> Self {
+        self.validator(R::SCOPE).optional(R::OPT_SCOPE)
+    }
+
+    /// Add the scopes needed for the subscription `E`
+    #[cfg(feature = "eventsub")]
+    #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
+    pub fn subscription<E: crate::eventsub::EventSubscription>(self) -> Self {
+        self.validator(E::SCOPE).optional(E::OPT_SCOPE)
+    }
+
+    /// Add a [`Validator`] that needs to be satisfied
+    pub fn validator(mut self, validator: Validator) -> Self {
+        self.required.push(validator);
+        self
+    }
+
+    /// Add optional scopes, these are only included in [`ScopePlanner::scopes`] if [`include_optional`](ScopePlanner::include_optional) is set.
+    pub fn optional(mut self, scopes: &[Scope]) -> Self {
+        for scope in scopes {
+            push_unique(&mut self.optional, scope.clone());
+        }
+        self
+    }
+
+    /// Include optional scopes, which give access to more data on some endpoints.
+    pub fn include_optional(mut self, include_optional: bool) -> Self {
+        self.include_optional = include_optional;
+        self
+    }
+
+    /// Get the minimal set of scopes that satisfy all requests and subscriptions.
+    pub fn scopes(&self) -> Vec<Scope> { self.missing(&[]) }
+
+    /// Get the scopes that need to be added to `scopes` to satisfy all requests and subscriptions.
+    pub fn missing(&self, scopes: &[Scope]) -> Vec<Scope> {
+        let mut planned = scopes.to_vec();
+        // Resolve alternatives last, so that they can be satisfied by scopes that are needed anyway.
+        for validator in self
+            .required
+            .iter()
+            .filter(|v| !matches!(v, Validator::Any(_)))
+            .chain(
+                self.required
+                    .iter()
+                    .filter(|v| matches!(v, Validator::Any(_))),
+            )
+        {
+            plan(validator, &mut planned);
+        }
+        if self.include_optional {
+            for scope in &self.optional {
+                push_unique(&mut planned, scope.clone());
+            }
+        }
+        planned.retain(|scope| !scopes.contains(scope));
+        planned
+    }
+}
+
+/// Get the scopes that need to be added to `scopes` to satisfy `validator`.
+///
+/// Returns an empty list if the validator is satisfied, or can't be satisfied by adding scopes.
+pub fn missing_scopes(validator: &Validator, scopes: &[Scope]) -> Vec<Scope> {
+    let mut planned = scopes.to_vec();
+    plan(validator, &mut planned);
+    planned.retain(|scope| !scopes.contains(scope));
+    planned
+}
+
+/// Add the scopes needed to satisfy `validator` to `scopes`, preferring alternatives that add the fewest scopes.
+fn plan(validator: &Validator, scopes: &mut Vec<Scope>) {
+    match validator {
+        Validator::Scope(scope) => push_unique(scopes, scope.clone()),
+        Validator::All(all) => {
+            for validator in all.0.iter() {
+                plan(validator, scopes);
+            }
+        }
+        Validator::Any(_) if validator.matches(scopes) => {}
+        Validator::Any(any) => {
+            if let Some(best) = any
+                .0
+                .iter()
+                .map(|validator| {
+                    let mut planned = scopes.clone();
+                    plan(validator, &mut planned);
+                    planned
+                })
+                .min_by_key(Vec::len)
+            {
+                *scopes = best;
+            }
+        }
+        // Scopes can only be added, so negations are left as is
+        _ => {}
+    }
+}
+
+fn push_unique(scopes: &mut Vec<Scope>, scope: Scope) {
+    if !scopes.contains(&scope) {
+        scopes.push(scope);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimal_scopes() {
+        const VALIDATOR: Validator = twitch_oauth2::validator![all(
+            Scope::ModeratorReadChatters,
+            any(
+                Scope::ModeratorReadChatters,
+                Scope::ModeratorManageBannedUsers
+            )
+        )];
+        const ANY: Validator = twitch_oauth2::validator![any(
+            Scope::ModeratorManageBannedUsers,
+            Scope::ModeratorReadChatters
+        )];
+        let planner = ScopePlanner::new()
+            .validator(ANY)
+            .validator(VALIDATOR)
+            .optional(&[Scope::UserReadEmail]);
+        assert_eq!(planner.scopes(), vec![Scope::ModeratorReadChatters]);
+        assert_eq!(
+            planner.clone().include_optional(true).scopes(),
+            vec![Scope::ModeratorReadChatters, Scope::UserReadEmail]
+        );
+        assert!(planner.missing(&[Scope::ModeratorReadChatters]).is_empty());
+        assert_eq!(
+            missing_scopes(&VALIDATOR, &[Scope::ModeratorManageBannedUsers]),
+            vec![Scope::ModeratorReadChatters]
+        );
+    }
+
+    #[cfg(all(feature = "helix", feature = "client"))]
+    #[tokio::test]
+    async fn scope_check() {
+        use crate::helix::{moderation::BanUserRequest, ClientRequestError};
+
+        let client =
+            crate::tests::MockClient::helix(Vec::<(u16, String)>::new()).with_scope_check(true);
+        let request = BanUserRequest::new("1337", "1337");
+        let body = crate::helix::moderation::BanUserBody::new("1234", "no reason", None);
+        let error = client
+            .req_post(request, body, &crate::tests::token())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            &error,
+            ClientRequestError::MissingScopeError(e) if e.missing == [Scope::ModeratorManageBannedUsers]
+        ));
+        assert!(error.is_auth_error());
+        assert!(client.get_client().requests.lock().unwrap().is_empty());
+    }
+}
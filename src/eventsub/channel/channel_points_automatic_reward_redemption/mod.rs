@@ -10,6 +10,10 @@ pub mod add;
 pub use add::{
     ChannelPointsAutomaticRewardRedemptionAddV1, ChannelPointsAutomaticRewardRedemptionAddV1Payload,
 };
+#[doc(inline)]
+pub use add::{
+    ChannelPointsAutomaticRewardRedemptionAddV2, ChannelPointsAutomaticRewardRedemptionAddV2Payload,
+};
 
 /// Basic information about the automatic reward that was redeemed, at the time it was redeemed.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -72,3 +76,69 @@ pub struct RedemptionMessage {
     /// An array that includes the emote ID and start and end positions for where the emote appears in the text.
     pub emotes: Vec<types::EmoteOccurrence>,
 }
+
+/// Basic information about the automatic reward that was redeemed, at the time it was redeemed. Used in [`ChannelPointsAutomaticRewardRedemptionAddV2`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct AutomaticRewardV2 {
+    /// The type of reward.
+    #[serde(rename = "type")]
+    pub type_: AutomaticRewardType,
+    /// Number of channel points used.
+    pub channel_points: i64,
+    /// Emote associated with the reward.
+    pub emote: Option<UnlockedEmote>,
+}
+
+/// The user message from a reward redemption. Used in [`ChannelPointsAutomaticRewardRedemptionAddV2`].
+///
+/// This message is different to the one from [channel.chat.message](crate::eventsub::channel::chat::Message)
+/// in that it doesn't contain "mention" or "cheermote" fragments and that the "emote" fragment only contains the ID.
+// XXX: this struct can never be deny_unknown_fields
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RedemptionMessageV2 {
+    /// The text of the chat message.
+    pub text: String,
+    /// Ordered list of chat message fragments.
+    pub fragments: Vec<RedemptionMessageFragment>,
+}
+
+/// A fragment of a [redemption message](RedemptionMessageV2)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RedemptionMessageFragment {
+    /// An Emote.
+    Emote {
+        /// Message text in a fragment
+        text: String,
+        /// An Emote.
+        emote: RedemptionMessageEmote,
+    },
+    /// A text fragment, see [`RedemptionMessageFragment::text`].
+    Text {
+        /// Message text in a fragment
+        text: String,
+    },
+}
+
+impl RedemptionMessageFragment {
+    /// Get the text data
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Emote { text, .. } => text,
+            Self::Text { text } => text,
+        }
+    }
+}
+
+/// An emote in a [redemption message](RedemptionMessageV2)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct RedemptionMessageEmote {
+    /// An ID that uniquely identifies this emote.
+    pub id: types::EmoteId,
+}
@@ -0,0 +1,127 @@
+#![doc(alias = "channel.custom_power_up_redemption.add")]
+//! A viewer has redeemed a custom Power-up with Bits on the specified channel.
+
+use super::*;
+/// [`channel.custom_power_up_redemption.add`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelcustom_power_up_redemptionadd): a viewer has redeemed a custom Power-up with Bits on the specified channel.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelCustomPowerUpRedemptionAddV1 {
+    /// The broadcaster user ID for the channel you want to receive custom Power-up redemption notifications for.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    pub broadcaster_user_id: types::UserId,
+    /// Optional. Specify a custom Power-up id to only receive notifications for a specific custom Power-up.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    pub reward_id: Option<types::RewardId>,
+}
+
+impl ChannelCustomPowerUpRedemptionAddV1 {
+    /// The broadcaster user ID for the channel you want to receive custom Power-up redemption notifications for.
+    pub fn broadcaster_user_id(broadcaster_user_id: impl Into<types::UserId>) -> Self {
+        Self {
+            broadcaster_user_id: broadcaster_user_id.into(),
+            reward_id: None,
+        }
+    }
+
+    /// Specify a custom Power-up id to only receive notifications for a specific custom Power-up.
+    pub fn reward_id(mut self, reward_id: impl Into<types::RewardId>) -> Self {
+        self.reward_id = Some(reward_id.into());
+        self
+    }
+}
+
+impl EventSubscription for ChannelCustomPowerUpRedemptionAddV1 {
+    type Payload = ChannelCustomPowerUpRedemptionAddV1Payload;
+
+    const EVENT_TYPE: EventType = EventType::ChannelCustomPowerUpRedemptionAdd;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator =
+        twitch_oauth2::validator![twitch_oauth2::Scope::BitsRead];
+    const VERSION: &'static str = "1";
+}
+
+/// [`channel.custom_power_up_redemption.add`](ChannelCustomPowerUpRedemptionAddV1) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelCustomPowerUpRedemptionAddV1Payload {
+    /// The ID of the channel where the custom Power-up was redeemed.
+    pub broadcaster_user_id: types::UserId,
+    /// The login of the channel where the custom Power-up was redeemed.
+    pub broadcaster_user_login: types::UserName,
+    /// The display name of the channel where the custom Power-up was redeemed.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The ID of the redeeming user.
+    pub user_id: types::UserId,
+    /// The login of the redeeming user.
+    pub user_login: types::UserName,
+    /// The display name of the redeeming user.
+    pub user_name: types::DisplayName,
+    /// The ID of the redemption.
+    pub id: types::RedemptionId,
+    /// Basic information about the custom Power-up that was redeemed, at the time it was redeemed.
+    pub custom_power_up: CustomPowerUp,
+    /// The user input provided. Empty string if not provided.
+    pub user_input: String,
+    /// The UTC date and time (in RFC3339 format) of when the custom Power-up was redeemed.
+    pub redeemed_at: types::Timestamp,
+}
+
+#[cfg(test)]
+#[test]
+fn parse_payload() {
+    use crate::eventsub::{Event, Message};
+
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.custom_power_up_redemption.add",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337",
+                "reward_id": null
+            },
+            "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2025-06-12T10:11:12.634234626Z"
+        },
+        "event": {
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cool_user",
+            "broadcaster_user_name": "Cool_User",
+            "user_id": "9001",
+            "user_login": "cooler_user",
+            "user_name": "Cooler_User",
+            "id": "17fa2df1-ad76-4804-bfa5-a40ef63efe63",
+            "custom_power_up": {
+                "id": "92af127c-7326-4483-a52b-b0da0be61c01",
+                "title": "Hydrate!",
+                "prompt": "The streamer drinks a glass of water",
+                "bits": 100
+            },
+            "user_input": "",
+            "redeemed_at": "2025-06-12T10:12:12.634234626Z"
+        }
+    }
+    "##;
+
+    let val = Event::parse(payload).unwrap();
+    crate::tests::roundtrip(&val);
+
+    let Event::ChannelCustomPowerUpRedemptionAddV1(val) = val else {
+        panic!("invalid event type");
+    };
+    let Message::Notification(notif) = val.message else {
+        panic!("invalid message type");
+    };
+
+    assert_eq!(notif.custom_power_up.title, "Hydrate!");
+    assert_eq!(notif.custom_power_up.bits, 100);
+}
@@ -0,0 +1,25 @@
+#![doc(alias = "channel.custom_power_up_redemption")]
+//! A viewer has redeemed a custom Power-up with Bits in a specified channel.
+use super::{EventSubscription, EventType};
+use crate::types;
+use serde_derive::{Deserialize, Serialize};
+
+pub mod add;
+
+#[doc(inline)]
+pub use add::{ChannelCustomPowerUpRedemptionAddV1, ChannelCustomPowerUpRedemptionAddV1Payload};
+
+/// Basic information about the custom Power-up that was redeemed, at the time it was redeemed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct CustomPowerUp {
+    /// The ID of the custom Power-up.
+    pub id: types::RewardId,
+    /// The title of the custom Power-up.
+    pub title: String,
+    /// The prompt of the custom Power-up.
+    pub prompt: String,
+    /// The amount of Bits the custom Power-up costs.
+    pub bits: i64,
+}
@@ -15,6 +15,7 @@ pub mod charity_campaign;
 pub mod chat;
 pub mod chat_settings;
 pub mod cheer;
+pub mod custom_power_up_redemption;
 pub mod follow;
 pub mod goal;
 #[cfg(feature = "beta")]
@@ -52,6 +53,10 @@ pub use channel_points_automatic_reward_redemption::{
     ChannelPointsAutomaticRewardRedemptionAddV1, ChannelPointsAutomaticRewardRedemptionAddV1Payload,
 };
 #[doc(inline)]
+pub use channel_points_automatic_reward_redemption::{
+    ChannelPointsAutomaticRewardRedemptionAddV2, ChannelPointsAutomaticRewardRedemptionAddV2Payload,
+};
+#[doc(inline)]
 pub use channel_points_custom_reward::{
     ChannelPointsCustomRewardAddV1, ChannelPointsCustomRewardAddV1Payload,
 };
@@ -100,6 +105,10 @@ pub use chat_settings::{ChannelChatSettingsUpdateV1, ChannelChatSettingsUpdateV1
 #[doc(inline)]
 pub use cheer::{ChannelCheerV1, ChannelCheerV1Payload};
 #[doc(inline)]
+pub use custom_power_up_redemption::{
+    ChannelCustomPowerUpRedemptionAddV1, ChannelCustomPowerUpRedemptionAddV1Payload,
+};
+#[doc(inline)]
 pub use follow::{ChannelFollowV1, ChannelFollowV1Payload};
 #[doc(inline)]
 pub use follow::{ChannelFollowV2, ChannelFollowV2Payload};
@@ -0,0 +1,455 @@
+//! Orchestration of a [conduit](https://dev.twitch.tv/docs/eventsub/handling-conduit-events/) and its shards
+//!
+//! A [`ConduitManager`] owns a conduit, keeps track of which [transport](Transport) is attached to which shard
+//! and reassigns shards when Twitch disables them.
+//!
+//! # Examples
+//!
+//! ```rust, no_run
+//! use twitch_api::eventsub::{
+//!     self, conduit::manager::ConduitManager, Event, Message, Transport,
+//! };
+//! # use twitch_api::{client, helix};
+//! # fn main() {}
+//! # async fn run(event: Event) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+//! # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let mut manager = ConduitManager::get_or_create(&client, 2, &token).await?;
+//! manager
+//!     .attach_all(
+//!         [
+//!             Transport::webhook("https://example.com/eventsub/0", "secret0".to_owned()),
+//!             Transport::webhook("https://example.com/eventsub/1", "secret1".to_owned()),
+//!         ],
+//!         &token,
+//!     )
+//!     .await?;
+//! // used when a shard is disabled
+//! manager.add_spare(Transport::webhook(
+//!     "https://backup.example.com/eventsub",
+//!     "secret2".to_owned(),
+//! ));
+//!
+//! // when receiving a `conduit.shard.disabled` notification
+//! if let Event::ConduitShardDisabledV1(eventsub::Payload {
+//!     message: Message::Notification(disabled),
+//!     ..
+//! }) = event
+//! {
+//!     manager.handle_disabled(&disabled, &token).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+
+use twitch_oauth2::TwitchToken;
+
+use super::ConduitShardDisabledV1Payload;
+use crate::{
+    eventsub::{Conduit, Shard, ShardError, ShardResponse, Transport},
+    helix::{self, ClientRequestError, HelixClient},
+};
+
+/// Manages a [conduit](Conduit) and the transports attached to its shards, see the [module documentation](self)
+pub struct ConduitManager<'a, C: crate::HttpClient> {
+    helix: &'a HelixClient<'a, C>,
+    conduit: Conduit,
+    /// The transport attached to each shard, indexed by shard id
+    shards: Vec<Option<Transport>>,
+    /// Transports used to replace disabled shards
+    spares: VecDeque<Transport>,
+}
+
+impl<'a, C> ConduitManager<'a, C>
+where C: crate::HttpClient + Send + Sync + 'a
+{
+    /// Manage an existing conduit
+    ///
+    /// No shards are considered attached, use [`ConduitManager::attach`] or [`ConduitManager::attach_all`] to attach transports.
+    pub fn from_conduit(helix: &'a HelixClient<'a, C>, conduit: Conduit) -> Self {
+        Self {
+            helix,
+            shards: vec![None; conduit.shard_count],
+            conduit,
+            spares: VecDeque::new(),
+        }
+    }
+
+    /// Create a new conduit with `shard_count` shards
+    pub async fn create<T>(
+        helix: &'a HelixClient<'a, C>,
+        shard_count: usize,
+        token: &T,
+    ) -> Result<Self, ConduitError<C::Error>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let conduit = helix
+            .req_post(
+                helix::eventsub::CreateConduitRequest {},
+                helix::eventsub::CreateConduitBody::new(shard_count),
+                token,
+            )
+            .await?
+            .data;
+        Ok(Self::from_conduit(helix, conduit))
+    }
+
+    /// Reuse the first conduit of the application, resized to `shard_count` shards, or create a new one if there is none
+    pub async fn get_or_create<T>(
+        helix: &'a HelixClient<'a, C>,
+        shard_count: usize,
+        token: &T,
+    ) -> Result<Self, ConduitError<C::Error>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let existing = helix
+            .req_get(helix::eventsub::GetConduitsRequest {}, token)
+            .await?
+            .data
+            .into_iter()
+            .next();
+        match existing {
+            Some(conduit) => {
+                let mut manager = Self::from_conduit(helix, conduit);
+                manager.resize(shard_count, token).await?;
+                Ok(manager)
+            }
+            None => Self::create(helix, shard_count, token).await,
+        }
+    }
+
+    /// The managed conduit
+    pub const fn conduit(&self) -> &Conduit { &self.conduit }
+
+    /// The transport attached to a shard by this manager
+    pub fn transport(&self, shard_id: usize) -> Option<&Transport> {
+        self.shards.get(shard_id).and_then(Option::as_ref)
+    }
+
+    /// Ids of the shards that have no transport attached by this manager
+    pub fn unassigned_shards(&self) -> impl Iterator<Item = usize> + '_ {
+        self.shards
+            .iter()
+            .enumerate()
+            .filter(|(_, transport)| transport.is_none())
+            .map(|(id, _)| id)
+    }
+
+    /// Add a transport to use when a shard is [disabled](ConduitManager::handle_disabled)
+    pub fn add_spare(&mut self, transport: Transport) { self.spares.push_back(transport) }
+
+    /// Change the shard count of the conduit
+    ///
+    /// Does nothing if the conduit already has `shard_count` shards.
+    /// When shrinking, transports attached to removed shards are forgotten.
+    pub async fn resize<T>(
+        &mut self,
+        shard_count: usize,
+        token: &T,
+    ) -> Result<(), ConduitError<C::Error>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        if shard_count == self.conduit.shard_count {
+            return Ok(());
+        }
+        self.conduit = self
+            .helix
+            .req_patch(
+                helix::eventsub::UpdateConduitRequest::default(),
+                helix::eventsub::UpdateConduitBody::new(self.conduit.id.clone(), shard_count),
+                token,
+            )
+            .await?
+            .data;
+        self.shards.resize(self.conduit.shard_count, None);
+        Ok(())
+    }
+
+    /// Attach a transport to a shard
+    pub async fn attach<T>(
+        &mut self,
+        shard_id: usize,
+        transport: Transport,
+        token: &T,
+    ) -> Result<ShardResponse, ConduitError<C::Error>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        if shard_id >= self.shards.len() {
+            return Err(ConduitError::ShardOutOfRange {
+                shard_id,
+                shard_count: self.shards.len(),
+            });
+        }
+        let mut updated = self
+            .update_shards(vec![(shard_id, transport)], token)
+            .await?;
+        // twitch should always respond with either the shard or an error for it
+        updated.pop().ok_or(ConduitError::MissingShard { shard_id })
+    }
+
+    /// Attach transports to the unassigned shards, in order of their id
+    ///
+    /// The conduit is grown if there are more transports than unassigned shards.
+    pub async fn attach_all<T>(
+        &mut self,
+        transports: impl IntoIterator<Item = Transport>,
+        token: &T,
+    ) -> Result<Vec<ShardResponse>, ConduitError<C::Error>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let transports: Vec<_> = transports.into_iter().collect();
+        let unassigned = self.unassigned_shards().count();
+        if transports.len() > unassigned {
+            let shard_count = self.conduit.shard_count + transports.len() - unassigned;
+            self.resize(shard_count, token).await?;
+        }
+        let shards = self.unassigned_shards().zip(transports).collect();
+        self.update_shards(shards, token).await
+    }
+
+    /// Reassign a shard that was disabled by Twitch
+    ///
+    /// The shard is attached to the next [spare transport](ConduitManager::add_spare).
+    /// If there is none, a webhook shard is attached to the same webhook again, which makes Twitch verify the callback anew.
+    /// Websocket sessions can not be reused, so the shard is left unassigned and `None` is returned.
+    ///
+    /// Notifications for other conduits are ignored.
+    pub async fn handle_disabled<T>(
+        &mut self,
+        disabled: &ConduitShardDisabledV1Payload,
+        token: &T,
+    ) -> Result<Option<ShardResponse>, ConduitError<C::Error>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        if disabled.conduit_id != self.conduit.id.as_str() {
+            return Ok(None);
+        }
+        let Some(shard) = disabled
+            .shard_id
+            .parse::<usize>()
+            .ok()
+            .and_then(|id| self.shards.get_mut(id).map(|shard| (id, shard)))
+        else {
+            return Ok(None);
+        };
+        let (shard_id, previous) = (shard.0, shard.1.take());
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(shard_id, status = ?disabled.status, "conduit shard was disabled");
+
+        let replacement = match (self.spares.pop_front(), previous) {
+            (Some(spare), _) => spare,
+            (None, Some(webhook @ Transport::Webhook(_))) => webhook,
+            (None, _) => return Ok(None),
+        };
+        self.attach(shard_id, replacement, token).await.map(Some)
+    }
+
+    async fn update_shards<T>(
+        &mut self,
+        shards: Vec<(usize, Transport)>,
+        token: &T,
+    ) -> Result<Vec<ShardResponse>, ConduitError<C::Error>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let body: Vec<Shard> = shards
+            .iter()
+            .map(|(id, transport)| Shard::new(id.to_string(), transport.clone()))
+            .collect();
+        let response = self
+            .helix
+            .req_patch(
+                helix::eventsub::UpdateConduitShardsRequest::default(),
+                helix::eventsub::UpdateConduitShardsBody::new(self.conduit.id.clone(), body),
+                token,
+            )
+            .await?
+            .data;
+
+        for (id, transport) in shards {
+            if response
+                .shards
+                .iter()
+                .any(|s| s.id.as_str() == id.to_string())
+            {
+                self.shards[id] = Some(transport);
+            }
+        }
+        if response.errors.is_empty() {
+            Ok(response.shards)
+        } else {
+            Err(ConduitError::Shards {
+                updated: response.shards,
+                errors: response.errors,
+            })
+        }
+    }
+}
+
+impl<C: crate::HttpClient> std::fmt::Debug for ConduitManager<'_, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConduitManager")
+            .field("conduit", &self.conduit)
+            .field("shards", &self.shards)
+            .field("spares", &self.spares)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Errors from a [`ConduitManager`]
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[non_exhaustive]
+pub enum ConduitError<RE: std::error::Error + Send + Sync + 'static> {
+    /// request to helix failed
+    Request(#[from] ClientRequestError<RE>),
+    /// shards could not be updated: {errors:?}
+    Shards {
+        /// The shards that were updated
+        updated: Vec<ShardResponse>,
+        /// The shards that could not be updated
+        errors: Vec<ShardError>,
+    },
+    /// twitch responded with neither shard {shard_id} nor an error for it
+    MissingShard {
+        /// The requested shard
+        shard_id: usize,
+    },
+    /// shard {shard_id} is outside of the conduit's {shard_count} shards
+    ShardOutOfRange {
+        /// The requested shard
+        shard_id: usize,
+        /// The shard count of the conduit
+        shard_count: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{token, MockClient};
+
+    fn conduit(shard_count: usize) -> Conduit {
+        Conduit {
+            id: "bfcfc993-26b1-b876-44d9-afe75a379dac".into(),
+            shard_count,
+        }
+    }
+
+    fn disabled(shard_id: &str) -> ConduitShardDisabledV1Payload {
+        serde_json::from_str(&format!(
+            r#"{{"conduit_id":"bfcfc993-26b1-b876-44d9-afe75a379dac","shard_id":"{shard_id}","status":"websocket_disconnected","transport":{{"method":"websocket","session_id":"ad1c9fc3-0d99-4eb7-8a04-8608e8ff9ec9","connected_at":"2020-11-10T14:32:18.730260295Z","disconnected_at":"2020-11-11T14:32:18.730260295Z"}}}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn attach_all_grows_conduit() {
+        let helix = MockClient::helix([
+            (
+                200,
+                r#"{"data":[{"id":"bfcfc993-26b1-b876-44d9-afe75a379dac","shard_count":2}]}"#,
+            ),
+            (
+                200,
+                r#"{"data":[{"id":"0","status":"enabled","transport":{"method":"websocket","session_id":"a","connected_at":"2020-11-10T14:32:18.730260295Z"}},{"id":"1","status":"enabled","transport":{"method":"websocket","session_id":"b","connected_at":"2020-11-10T14:32:18.730260295Z"}}],"errors":[]}"#,
+            ),
+        ]);
+        let token = token();
+        let mut manager = ConduitManager::from_conduit(&helix, conduit(1));
+
+        let updated = futures::executor::block_on(manager.attach_all(
+            [Transport::websocket("a"), Transport::websocket("b")],
+            &token,
+        ))
+        .unwrap();
+        assert_eq!(updated.len(), 2);
+        assert_eq!(manager.conduit().shard_count, 2);
+        assert_eq!(manager.transport(1), Some(&Transport::websocket("b")));
+        assert_eq!(manager.unassigned_shards().count(), 0);
+
+        let requests = helix.get_client().requests.lock().unwrap();
+        assert_eq!(requests[0].1, "/helix/eventsub/conduits");
+        assert_eq!(requests[1].1, "/helix/eventsub/conduits/shards");
+    }
+
+    #[test]
+    fn shard_errors_are_surfaced() {
+        let helix = MockClient::helix([(
+            200,
+            r#"{"data":[],"errors":[{"id":"0","message":"The websocket session is not found","code":"invalid_parameter"}]}"#,
+        )]);
+        let token = token();
+        let mut manager = ConduitManager::from_conduit(&helix, conduit(1));
+
+        let error =
+            futures::executor::block_on(manager.attach(0, Transport::websocket("a"), &token))
+                .unwrap_err();
+        let ConduitError::Shards { updated, errors } = error else {
+            panic!("unexpected error: {:?}", error);
+        };
+        assert!(updated.is_empty());
+        assert_eq!(errors[0].code, "invalid_parameter");
+        assert_eq!(manager.transport(0), None);
+
+        assert!(matches!(
+            futures::executor::block_on(manager.attach(1, Transport::websocket("a"), &token)),
+            Err(ConduitError::ShardOutOfRange {
+                shard_id: 1,
+                shard_count: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn missing_shard_is_an_error() {
+        let helix = MockClient::helix([(200, r#"{"data":[],"errors":[]}"#)]);
+        let token = token();
+        let mut manager = ConduitManager::from_conduit(&helix, conduit(1));
+
+        assert!(matches!(
+            futures::executor::block_on(manager.attach(0, Transport::websocket("a"), &token)),
+            Err(ConduitError::MissingShard { shard_id: 0 })
+        ));
+        assert_eq!(manager.transport(0), None);
+    }
+
+    #[test]
+    fn disabled_shard_uses_spare() {
+        let helix = MockClient::helix([
+            (
+                200,
+                r#"{"data":[{"id":"0","status":"enabled","transport":{"method":"websocket","session_id":"a","connected_at":"2020-11-10T14:32:18.730260295Z"}}],"errors":[]}"#,
+            ),
+            (
+                200,
+                r#"{"data":[{"id":"0","status":"enabled","transport":{"method":"websocket","session_id":"b","connected_at":"2020-11-10T14:32:18.730260295Z"}}],"errors":[]}"#,
+            ),
+        ]);
+        let token = token();
+        let mut manager = ConduitManager::from_conduit(&helix, conduit(1));
+        futures::executor::block_on(manager.attach(0, Transport::websocket("a"), &token)).unwrap();
+
+        manager.add_spare(Transport::websocket("b"));
+        let reassigned =
+            futures::executor::block_on(manager.handle_disabled(&disabled("0"), &token)).unwrap();
+        assert!(reassigned.is_some());
+        assert_eq!(manager.transport(0), Some(&Transport::websocket("b")));
+
+        // no spare left, websocket sessions can't be reused
+        let reassigned =
+            futures::executor::block_on(manager.handle_disabled(&disabled("0"), &token)).unwrap();
+        assert!(reassigned.is_none());
+        assert_eq!(manager.transport(0), None);
+    }
+}
@@ -1,6 +1,9 @@
 //! Subscription types regarding conduits.
 use super::{EventSubscription, EventType};
 
+#[cfg(all(feature = "helix", feature = "client"))]
+#[cfg_attr(nightly, doc(cfg(all(feature = "helix", feature = "client"))))]
+pub mod manager;
 pub mod shard;
 
 #[doc(inline)]
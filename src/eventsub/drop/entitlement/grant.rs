@@ -0,0 +1,194 @@
+#![doc(alias = "drop.entitlement.grant")]
+//! An entitlement for a Drop is granted to a user.
+//!
+//! Notifications for this subscription are batched, a single notification contains a list of [events](DropEntitlementGrantV1Payload).
+//! This subscription type is only supported by webhooks and conduits.
+
+use super::*;
+
+/// [`drop.entitlement.grant`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#dropentitlementgrant): an entitlement for a Drop is granted to a user.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct DropEntitlementGrantV1 {
+    /// The organization ID of the organization that owns the game on the developer portal.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    pub organization_id: String,
+    /// Optional. The category (or game) ID of the game for which entitlement notifications will be received.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    pub category_id: Option<types::CategoryId>,
+    /// Optional. The campaign ID for a specific campaign for which entitlement notifications will be received.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    pub campaign_id: Option<String>,
+}
+
+impl DropEntitlementGrantV1 {
+    /// Get notifications for entitlements granted in all campaigns of this organization
+    pub fn organization_id(organization_id: impl Into<String>) -> Self {
+        Self {
+            organization_id: organization_id.into(),
+            category_id: None,
+            campaign_id: None,
+        }
+    }
+
+    /// Only get notifications for entitlements granted for this category (or game)
+    pub fn category_id(mut self, category_id: impl Into<types::CategoryId>) -> Self {
+        self.category_id = Some(category_id.into());
+        self
+    }
+
+    /// Only get notifications for entitlements granted in this campaign
+    pub fn campaign_id(mut self, campaign_id: impl Into<String>) -> Self {
+        self.campaign_id = Some(campaign_id.into());
+        self
+    }
+}
+
+impl EventSubscription for DropEntitlementGrantV1 {
+    /// The notification contains a batch of entitlement grants.
+    type Payload = Vec<DropEntitlementGrantV1Payload>;
+
+    const EVENT_TYPE: EventType = EventType::DropEntitlementGrant;
+    const IS_BATCHING_ENABLED: bool = true;
+    #[cfg(feature = "twitch_oauth2")]
+    /// App access token where the client ID is associated with a user on the Twitch developer console who owns the organization.
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+    const VERSION: &'static str = "1";
+}
+
+/// A single event in a [`drop.entitlement.grant`](DropEntitlementGrantV1) notification.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct DropEntitlementGrantV1Payload {
+    /// Individual event ID, as assigned by EventSub. Use this for de-duplicating messages.
+    pub id: String,
+    /// Entitlement object.
+    pub data: DropEntitlementGrantV1Data,
+}
+
+/// Entitlement granted in a [`drop.entitlement.grant`](DropEntitlementGrantV1) event.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct DropEntitlementGrantV1Data {
+    /// The ID of the organization that owns the game that has Drops enabled.
+    pub organization_id: String,
+    /// Twitch category ID of the game that was being played when this benefit was entitled.
+    pub category_id: types::CategoryId,
+    /// The category name.
+    pub category_name: String,
+    /// The campaign this entitlement is associated with.
+    pub campaign_id: String,
+    /// Twitch user ID of the user who was granted the entitlement.
+    pub user_id: types::UserId,
+    /// The user display name of the user who was granted the entitlement.
+    pub user_name: types::DisplayName,
+    /// The user login of the user who was granted the entitlement.
+    pub user_login: types::UserName,
+    /// Unique identifier of the entitlement. Use this to de-duplicate entitlements.
+    pub entitlement_id: String,
+    /// Identifier of the Benefit.
+    pub benefit_id: String,
+    /// UTC timestamp in ISO format when this entitlement was granted on Twitch.
+    pub created_at: types::Timestamp,
+}
+
+#[cfg(test)]
+#[test]
+fn parse_payload() {
+    use crate::eventsub::{Event, Message};
+    use http::header::{HeaderMap, HeaderName, HeaderValue};
+
+    let payload = r##"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "drop.entitlement.grant",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "organization_id": "9001",
+                "category_id": "9002",
+                "campaign_id": "9003"
+            },
+            "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.634234626Z"
+        },
+        "events": [
+            {
+                "id": "bf7c8577-e3e8-4a16-9d4a-b0c8f1d2a3e4",
+                "data": {
+                    "organization_id": "9001",
+                    "category_id": "9002",
+                    "category_name": "Fortnite",
+                    "campaign_id": "9003",
+                    "user_id": "1234",
+                    "user_name": "Cool_User",
+                    "user_login": "cool_user",
+                    "entitlement_id": "fb78259e-fb81-4d1b-8333-34a06ffc24c0",
+                    "benefit_id": "74c52265-e214-48a6-91b9-23b6014e8041",
+                    "created_at": "2019-01-28T04:17:53.325Z"
+                }
+            },
+            {
+                "id": "a1d2e3f4-1b2c-4d5e-8f90-123456789abc",
+                "data": {
+                    "organization_id": "9001",
+                    "category_id": "9002",
+                    "category_name": "Fortnite",
+                    "campaign_id": "9003",
+                    "user_id": "5678",
+                    "user_name": "Cooler_User",
+                    "user_login": "cooler_user",
+                    "entitlement_id": "862750a5-265e-4ab6-9f0a-c64df3d54dd0",
+                    "benefit_id": "74c52265-e214-48a6-91b9-23b6014e8041",
+                    "created_at": "2019-01-28T04:17:54.325Z"
+                }
+            }
+        ]
+    }
+    "##;
+
+    let val = Event::parse(payload).unwrap();
+    crate::tests::roundtrip(&val);
+
+    let Event::DropEntitlementGrantV1(val) = val else {
+        panic!("invalid event type");
+    };
+    let Message::Notification(events) = val.message else {
+        panic!("invalid message type");
+    };
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[1].data.user_login.as_str(), "cooler_user");
+
+    #[rustfmt::skip]
+    let headers: HeaderMap = vec![
+        ("Twitch-Eventsub-Message-Id", "e76c6bd4-55c9-4987-8304-da1588d8988b"),
+        ("Twitch-Eventsub-Message-Retry", "0"),
+        ("Twitch-Eventsub-Message-Type", "notification"),
+        ("Twitch-Eventsub-Message-Signature", "sha256=f56bf6ce06a1adf46fa27831d7d15d"),
+        ("Twitch-Eventsub-Message-Timestamp", "2019-11-16T10:11:12.123Z"),
+        ("Twitch-Eventsub-Subscription-Type", "drop.entitlement.grant"),
+        ("Twitch-Eventsub-Subscription-Version", "1"),
+        ].into_iter()
+    .map(|(h, v)| {
+        (
+            h.parse::<HeaderName>().unwrap(),
+            v.parse::<HeaderValue>().unwrap(),
+        )
+    })
+    .collect();
+
+    let mut request = http::Request::builder();
+    let _ = std::mem::replace(request.headers_mut().unwrap(), headers);
+    let request = request.body(payload.as_bytes().to_vec()).unwrap();
+    let val = Event::parse_http(&request).unwrap();
+    assert!(matches!(val, Event::DropEntitlementGrantV1(_)));
+}
@@ -0,0 +1,10 @@
+#![doc(alias = "drops")]
+//! Subscription types regarding Drops.
+use super::{EventSubscription, EventType};
+
+pub mod entitlement;
+
+#[doc(inline)]
+pub use entitlement::grant::{
+    DropEntitlementGrantV1, DropEntitlementGrantV1Data, DropEntitlementGrantV1Payload,
+};
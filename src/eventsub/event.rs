@@ -1,5 +1,6 @@
 //! EventSub events and their types
 #![allow(deprecated)]
+pub mod raw;
 pub mod websocket;
 
 use std::borrow::Cow;
@@ -33,6 +34,7 @@ macro_rules! fill_events {
             channel::ChannelChatUserMessageUpdateV1;
             channel::ChannelChatSettingsUpdateV1;
             channel::ChannelCheerV1;
+            channel::ChannelCustomPowerUpRedemptionAddV1;
             channel::ChannelFollowV1;
             channel::ChannelFollowV2;
             channel::ChannelGoalBeginV1;
@@ -57,6 +59,7 @@ macro_rules! fill_events {
             channel::ChannelModeratorAddV1;
             channel::ChannelModeratorRemoveV1;
             channel::ChannelPointsAutomaticRewardRedemptionAddV1;
+            channel::ChannelPointsAutomaticRewardRedemptionAddV2;
             channel::ChannelPointsCustomRewardAddV1;
             channel::ChannelPointsCustomRewardRedemptionAddV1;
             channel::ChannelPointsCustomRewardRedemptionUpdateV1;
@@ -93,6 +96,7 @@ macro_rules! fill_events {
             channel::ChannelWarningAcknowledgeV1;
             channel::ChannelWarningSendV1;
             conduit::ConduitShardDisabledV1;
+            drop::DropEntitlementGrantV1;
             extension::ExtensionBitsTransactionCreateV1;
             stream::StreamOfflineV1;
             stream::StreamOnlineV1;
@@ -218,6 +222,8 @@ make_event_type!("Event Types": pub enum EventType {
     ChannelSubscribe => "channel.subscribe",
     "a user cheers on the specified channel.":
     ChannelCheer => "channel.cheer",
+    "a viewer has redeemed a custom Power-up with Bits on the specified channel.":
+    ChannelCustomPowerUpRedemptionAdd => "channel.custom_power_up_redemption.add",
     "a viewer is banned from the specified channel.":
     ChannelBan => "channel.ban",
     "a viewer is unbanned from the specified channel.":
@@ -314,6 +320,8 @@ make_event_type!("Event Types": pub enum EventType {
     ChannelVipRemove => "channel.vip.remove",
     "sends a notification when eventsub disables a shard due to the status of the underlying transport changing.":
     ConduitShardDisabled => "conduit.shard.disabled",
+    "an entitlement for a Drop is granted to a user.":
+    DropEntitlementGrant => "drop.entitlement.grant",
     "an extension triggers a bits transaction":
     ExtensionBitsTransactionCreate => "extension.bits_transaction.create",
     "the specified broadcaster starts a stream.":
@@ -401,6 +409,8 @@ pub enum Event {
     ChannelSubscribeV1(Payload<channel::ChannelSubscribeV1>),
     /// Channel Cheer V1 Event
     ChannelCheerV1(Payload<channel::ChannelCheerV1>),
+    /// Channel Custom Power-up Redemption Add V1 Event
+    ChannelCustomPowerUpRedemptionAddV1(Payload<channel::ChannelCustomPowerUpRedemptionAddV1>),
     /// Channel Ban V1 Event
     ChannelBanV1(Payload<channel::ChannelBanV1>),
     /// Channel Unban V1 Event
@@ -421,6 +431,10 @@ pub enum Event {
     ChannelPointsAutomaticRewardRedemptionAddV1(
         Payload<channel::ChannelPointsAutomaticRewardRedemptionAddV1>,
     ),
+    /// Channel Points Automatic Reward Redemption Add V2 Event
+    ChannelPointsAutomaticRewardRedemptionAddV2(
+        Payload<channel::ChannelPointsAutomaticRewardRedemptionAddV2>,
+    ),
     /// Channel Points Custom Reward Add V1 Event
     ChannelPointsCustomRewardAddV1(Payload<channel::ChannelPointsCustomRewardAddV1>),
     /// Channel Points Custom Reward Update V1 Event
@@ -512,6 +526,8 @@ pub enum Event {
     ChannelModeratorRemoveV1(Payload<channel::ChannelModeratorRemoveV1>),
     /// Conduit Shard Disabled V1 Event
     ConduitShardDisabledV1(Payload<conduit::ConduitShardDisabledV1>),
+    /// Drop Entitlement Grant V1 Event
+    DropEntitlementGrantV1(Payload<drop::DropEntitlementGrantV1>),
     /// Extension Bits Transaction Create V1 Event
     ExtensionBitsTransactionCreateV1(Payload<extension::ExtensionBitsTransactionCreateV1>),
     /// StreamOnline V1 Event
@@ -601,6 +617,45 @@ impl Event {
         fill_events!(match_event())
     }
 
+    /// Get the [`Payload`] of this event if it is for the subscription type `E`, otherwise returns the event back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twitch_api::eventsub::{channel::ChannelFollowV2, Event, Message};
+    /// # let event: Event = Event::parse(r#"{"subscription":{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","type":"channel.follow","version":"2","status":"enabled","cost":0,"condition":{"broadcaster_user_id":"1337","moderator_user_id":"1337"},"transport":{"method":"webhook","callback":"https://example.com/webhooks/callback"},"created_at":"2019-11-16T10:11:12.634234626Z"},"event":{"user_id":"1234","user_login":"cool_user","user_name":"Cool_User","broadcaster_user_id":"1337","broadcaster_user_login":"cooler_user","broadcaster_user_name":"Cooler_User","followed_at":"2020-07-15T18:16:11.17106713Z"}}"#)?;
+    /// if let Ok(payload) = event.try_into_payload::<ChannelFollowV2>() {
+    ///     if let Message::Notification(follow) = payload.message {
+    ///         println!("{} followed", follow.user_name);
+    ///     }
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    // the event is given back as is, so it can be matched on again without unboxing
+    #[allow(clippy::result_large_err)]
+    pub fn try_into_payload<E: EventSubscription + 'static>(self) -> Result<Payload<E>, Self> {
+        use std::any::Any;
+
+        macro_rules! match_event {
+            ($($(#[$meta:meta])* $module:ident::$event:ident);* $(;)?) => {{
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Event::$event(payload) => {
+                            let mut payload = Some(payload);
+                            if let Some(payload) = (&mut payload as &mut dyn Any).downcast_mut::<Option<Payload<E>>>() {
+                                return Ok(payload.take().expect("payload is set"));
+                            }
+                            Err(Event::$event(payload.expect("payload is set")))
+                        },
+                    )*
+                }
+            }}
+        }
+
+        fill_events!(match_event())
+    }
+
     /// Verify that this event is authentic using `HMAC-SHA256`.
     ///
     /// HMAC key is `secret`, HMAC message is a concatenation of `Twitch-Eventsub-Message-Id` header, `Twitch-Eventsub-Message-Timestamp` header and the request body.
@@ -671,8 +726,10 @@ impl Event {
 fn get_version_event_type_and_message_type_from_text(
     source: &str,
 ) -> Result<(Cow<'_, str>, EventType, Cow<'_, [u8]>), PayloadParseError> {
+    use serde::de::IgnoredAny;
+
     #[derive(Deserialize)]
-    struct IEventSubscripionInformation {
+    struct IEventSubscripionInformation<'a> {
         // condition: serde_json::Value,
         // created_at: types::Timestamp,
         // status: Status,
@@ -681,13 +738,17 @@ fn get_version_event_type_and_message_type_from_text(
         // transport: TransportResponse,
         #[serde(rename = "type")]
         type_: EventType,
-        version: String,
+        #[serde(borrow)]
+        version: Cow<'a, str>,
     }
     #[derive(Deserialize)]
-    struct IEvent {
-        subscription: IEventSubscripionInformation,
-        challenge: Option<serde_json::Value>,
-        event: Option<serde_json::Value>,
+    struct IEvent<'a> {
+        #[serde(borrow)]
+        subscription: IEventSubscripionInformation<'a>,
+        challenge: Option<IgnoredAny>,
+        // batched notifications, e.g. `drop.entitlement.grant`, use `events`
+        #[serde(alias = "events")]
+        event: Option<IgnoredAny>,
     }
 
     let IEvent {
@@ -696,25 +757,18 @@ fn get_version_event_type_and_message_type_from_text(
         event,
     } = parse_json(source, false)?;
     // FIXME: A visitor is really what we want.
-    if event.is_some() {
-        Ok((
-            subscription.version.into(),
-            subscription.type_,
-            Cow::Borrowed(b"notification"),
-        ))
+    let message_type: &[u8] = if event.is_some() {
+        b"notification"
     } else if challenge.is_some() {
-        Ok((
-            subscription.version.into(),
-            subscription.type_,
-            Cow::Borrowed(b"webhook_callback_verification"),
-        ))
+        b"webhook_callback_verification"
     } else {
-        Ok((
-            subscription.version.into(),
-            subscription.type_,
-            Cow::Borrowed(b"revocation"),
-        ))
-    }
+        b"revocation"
+    };
+    Ok((
+        subscription.version,
+        subscription.type_,
+        message_type.into(),
+    ))
 }
 
 /// Helper function to get version and type of event from http.
@@ -0,0 +1,317 @@
+//! Header-only parsing of EventSub messages
+use std::borrow::Cow;
+
+use serde_derive::Deserialize;
+
+use super::{
+    get_version_event_type_and_message_type_from_http,
+    get_version_event_type_and_message_type_from_text, Event, EventType, EventsubWebsocketMetadata,
+    NotificationMetadata, RevocationMetadata,
+};
+use crate::{
+    eventsub::{EventSubscription, Payload, PayloadParseError},
+    parse_json,
+};
+
+/// An EventSub message where only the subscription type, version and message type have been parsed.
+///
+/// Parsing a [`RawEvent`] is cheap compared to [`Event::parse`], [`Event::parse_http`] and [`Event::parse_websocket`],
+/// as the event itself is not deserialized until requested with [`RawEvent::parse`] or [`RawEvent::into_event`].
+/// This is useful when most incoming messages are filtered out before they are handled.
+///
+/// # Examples
+///
+/// ```rust
+/// use twitch_api::eventsub::{channel::ChannelFollowV2, Message, RawEvent};
+/// # let frame = r#"{"metadata":{"message_id":"befa7b53-d79d-478f-86b9-120f112b044e","message_type":"notification","message_timestamp":"2019-11-16T10:11:12.123Z","subscription_type":"channel.follow","subscription_version":"2"},"payload":{"subscription":{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","status":"enabled","type":"channel.follow","version":"2","cost":0,"condition":{"broadcaster_user_id":"1337","moderator_user_id":"1337"},"transport":{"method":"websocket","session_id":"AQoQexAWVYKSTIu4ec_2VAxyuhAB"},"created_at":"2019-11-16T10:11:12.123Z"},"event":{"user_id":"1234","user_login":"cool_user","user_name":"Cool_User","broadcaster_user_id":"1337","broadcaster_user_login":"cooler_user","broadcaster_user_name":"Cooler_User","followed_at":"2020-07-15T18:16:11.17106713Z"}}}"#;
+/// if let Some(raw) = RawEvent::parse_websocket(frame)? {
+///     // Only follows are deserialized, everything else is skipped.
+///     if raw.is::<ChannelFollowV2>() {
+///         if let Message::Notification(follow) = raw.parse::<ChannelFollowV2>()?.message {
+///             println!("{} followed", follow.user_name);
+///         }
+///     }
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawEvent<'a> {
+    event_type: EventType,
+    version: Cow<'a, str>,
+    message_type: Cow<'a, [u8]>,
+    message_id: Option<Cow<'a, str>>,
+    json: Cow<'a, str>,
+}
+
+impl<'a> RawEvent<'a> {
+    /// Parse the type and version of a string slice containing a payload. Consider using [`RawEvent::parse_http`] instead.
+    ///
+    /// See [`Event::parse`]
+    pub fn parse_str(source: &'a str) -> Result<Self, PayloadParseError> {
+        let (version, event_type, message_type) =
+            get_version_event_type_and_message_type_from_text(source)?;
+        Ok(Self {
+            event_type,
+            version,
+            message_type,
+            message_id: None,
+            json: source.into(),
+        })
+    }
+
+    /// Parse the headers of a http request. The body is only checked to be valid UTF-8.
+    ///
+    /// See [`Event::parse_http`]
+    pub fn parse_http<B>(request: &'a http::Request<B>) -> Result<Self, PayloadParseError>
+    where B: AsRef<[u8]> {
+        let (version, event_type, message_type) =
+            get_version_event_type_and_message_type_from_http(request)?;
+        let message_id = request
+            .headers()
+            .get("Twitch-Eventsub-Message-Id")
+            .map(|v| std::str::from_utf8(v.as_bytes()))
+            .transpose()?
+            .map(Cow::Borrowed);
+        Ok(Self {
+            event_type,
+            version,
+            message_type,
+            message_id,
+            json: std::str::from_utf8(request.body().as_ref())?.into(),
+        })
+    }
+
+    /// Parse the metadata of a websocket frame.
+    ///
+    /// Returns `None` if the frame is a session message (welcome, keepalive or reconnect), use [`Event::parse_websocket`] for those.
+    ///
+    /// See [`Event::parse_websocket`]
+    pub fn parse_websocket(frame: &'a str) -> Result<Option<Self>, PayloadParseError> {
+        #[derive(Deserialize)]
+        #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+        struct EventsubWebsocketFrame<'a> {
+            #[serde(borrow)]
+            metadata: EventsubWebsocketMetadata<'a>,
+            #[serde(borrow)]
+            payload: &'a serde_json::value::RawValue,
+        }
+
+        let frame: EventsubWebsocketFrame = parse_json(frame, true)?;
+        let (metadata, message_type): (_, &[u8]) = match frame.metadata {
+            EventsubWebsocketMetadata::Notification(NotificationMetadata {
+                message_id,
+                subscription_type,
+                subscription_version,
+                ..
+            }) => (
+                (message_id, subscription_type, subscription_version),
+                b"notification",
+            ),
+            EventsubWebsocketMetadata::Revocation(RevocationMetadata {
+                message_id,
+                subscription_type,
+                subscription_version,
+                ..
+            }) => (
+                (message_id, subscription_type, subscription_version),
+                b"revocation",
+            ),
+            EventsubWebsocketMetadata::Welcome(_)
+            | EventsubWebsocketMetadata::Keepalive(_)
+            | EventsubWebsocketMetadata::Reconnect(_) => return Ok(None),
+        };
+        let (message_id, event_type, version) = metadata;
+        Ok(Some(Self {
+            event_type,
+            version,
+            message_type: message_type.into(),
+            message_id: Some(message_id),
+            json: frame.payload.get().into(),
+        }))
+    }
+
+    /// The subscription type of this message
+    pub const fn event_type(&self) -> EventType { self.event_type }
+
+    /// The subscription version of this message
+    pub fn version(&self) -> &str { &self.version }
+
+    /// The message id, if it was part of the message.
+    ///
+    /// This is `Twitch-Eventsub-Message-Id` for webhooks and `metadata.message_id` for websockets.
+    pub fn message_id(&self) -> Option<&str> { self.message_id.as_deref() }
+
+    /// The unparsed JSON of the payload, containing `subscription` and `event`, `challenge` or nothing
+    pub fn json(&self) -> &str { &self.json }
+
+    /// Returns `true` if the message is a [`Notification`](crate::eventsub::Message::Notification).
+    pub fn is_notification(&self) -> bool { self.message_type.as_ref() == b"notification" }
+
+    /// Returns `true` if the message is a [`Revocation`](crate::eventsub::Message::Revocation).
+    pub fn is_revocation(&self) -> bool { self.message_type.as_ref() == b"revocation" }
+
+    /// Returns `true` if the message is a [`VerificationRequest`](crate::eventsub::Message::VerificationRequest).
+    pub fn is_verification_request(&self) -> bool {
+        self.message_type.as_ref() == b"webhook_callback_verification"
+    }
+
+    /// Returns `true` if this message is for the subscription type and version of `E`.
+    pub fn is<E: EventSubscription>(&self) -> bool {
+        self.event_type == E::EVENT_TYPE && self.version == E::VERSION
+    }
+
+    /// Deserialize the payload as the subscription `E`.
+    ///
+    /// Returns [`PayloadParseError::MismatchedEvent`] if the message is not for `E`, see [`RawEvent::is`].
+    pub fn parse<E: EventSubscription + Clone>(&self) -> Result<Payload<E>, PayloadParseError> {
+        if !self.is::<E>() {
+            return Err(PayloadParseError::MismatchedEvent {
+                version: self.version.clone().into_owned(),
+                event_type: self.event_type,
+            });
+        }
+        Payload::parse_request_str(&self.message_type, &self.json)
+    }
+
+    /// Deserialize the payload as an [`Event`]
+    pub fn into_event(self) -> Result<Event, PayloadParseError> {
+        let RawEvent {
+            event_type,
+            version,
+            message_type,
+            json,
+            ..
+        } = self;
+        let source = match json {
+            Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
+            Cow::Owned(s) => Cow::Owned(s.into_bytes()),
+        };
+        Event::parse_request(version, &event_type, message_type, source)
+    }
+
+    /// Make this message owned, allowing it to outlive its source.
+    pub fn into_owned(self) -> RawEvent<'static> {
+        RawEvent {
+            event_type: self.event_type,
+            version: Cow::Owned(self.version.into_owned()),
+            message_type: Cow::Owned(self.message_type.into_owned()),
+            message_id: self.message_id.map(|id| Cow::Owned(id.into_owned())),
+            json: Cow::Owned(self.json.into_owned()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eventsub::{channel, Message};
+
+    #[test]
+    fn parse_websocket() {
+        let frame = r#"
+        {
+            "metadata": {
+                "message_id": "befa7b53-d79d-478f-86b9-120f112b044e",
+                "message_type": "notification",
+                "message_timestamp": "2019-11-16T10:11:12.123Z",
+                "subscription_type": "channel.follow",
+                "subscription_version": "2"
+            },
+            "payload": {
+                "subscription": {
+                    "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+                    "status": "enabled",
+                    "type": "channel.follow",
+                    "version": "2",
+                    "cost": 0,
+                    "condition": {
+                        "broadcaster_user_id": "1337",
+                        "moderator_user_id": "1337"
+                    },
+                    "transport": {
+                        "method": "websocket",
+                        "session_id": "AQoQexAWVYKSTIu4ec_2VAxyuhAB"
+                    },
+                    "created_at": "2019-11-16T10:11:12.123Z"
+                },
+                "event": {
+                    "user_id": "1234",
+                    "user_login": "cool_user",
+                    "user_name": "Cool_User",
+                    "broadcaster_user_id": "1337",
+                    "broadcaster_user_login": "cooler_user",
+                    "broadcaster_user_name": "Cooler_User",
+                    "followed_at": "2020-07-15T18:16:11.17106713Z"
+                }
+            }
+        }
+        "#;
+        let raw = RawEvent::parse_websocket(frame).unwrap().unwrap();
+        assert_eq!(raw.event_type(), EventType::ChannelFollow);
+        assert_eq!(raw.version(), "2");
+        assert_eq!(
+            raw.message_id(),
+            Some("befa7b53-d79d-478f-86b9-120f112b044e")
+        );
+        assert!(raw.is_notification());
+        assert!(raw.is::<channel::ChannelFollowV2>());
+        assert!(!raw.is::<channel::ChannelRaidV1>());
+
+        assert!(matches!(
+            raw.parse::<channel::ChannelRaidV1>(),
+            Err(PayloadParseError::MismatchedEvent { .. })
+        ));
+        let payload = raw.parse::<channel::ChannelFollowV2>().unwrap();
+        assert!(matches!(payload.message, Message::Notification(_)));
+        assert!(matches!(
+            raw.into_owned().into_event().unwrap(),
+            Event::ChannelFollowV2(_)
+        ));
+
+        let keepalive = r#"{"metadata":{"message_id":"8d8e0935-0e0c-479a-8fa2-ad4c02cef742","message_type":"session_keepalive","message_timestamp":"2022-11-04T13:11:49.770459792Z"},"payload":{}}"#;
+        assert!(RawEvent::parse_websocket(keepalive).unwrap().is_none());
+    }
+
+    #[test]
+    fn parse_http() {
+        let body = r#"{"subscription":{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","type":"channel.follow","version":"2","status":"webhook_callback_verification_pending","cost":0,"condition":{"broadcaster_user_id":"1337","moderator_user_id":"1337"},"transport":{"method":"webhook","callback":"https://example.com/webhooks/callback"},"created_at":"2019-11-16T10:11:12.634234626Z"},"challenge":"pogchamp-kappa-360noscope-vohiyo"}"#;
+        let request = http::Request::builder()
+            .header(
+                "Twitch-Eventsub-Message-Id",
+                "e76c6bd4-55c9-4987-8304-da1588d8988b",
+            )
+            .header(
+                "Twitch-Eventsub-Message-Type",
+                "webhook_callback_verification",
+            )
+            .header("Twitch-Eventsub-Subscription-Type", "channel.follow")
+            .header("Twitch-Eventsub-Subscription-Version", "2")
+            .body(body.as_bytes().to_vec())
+            .unwrap();
+        let raw = RawEvent::parse_http(&request).unwrap();
+        assert_eq!(
+            raw.message_id(),
+            Some("e76c6bd4-55c9-4987-8304-da1588d8988b")
+        );
+        assert!(raw.is_verification_request());
+        assert_eq!(raw.json(), body);
+        assert_eq!(
+            RawEvent {
+                message_id: None,
+                ..raw.clone()
+            },
+            RawEvent::parse_str(body).unwrap()
+        );
+        let payload = raw.parse::<channel::ChannelFollowV2>().unwrap();
+        assert!(matches!(payload.message, Message::VerificationRequest(_)));
+    }
+
+    #[test]
+    fn parse_batched() {
+        let body = r#"{"subscription":{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","type":"drop.entitlement.grant","version":"1","status":"enabled","cost":0,"condition":{"organization_id":"9001","category_id":"9002","campaign_id":"9003"},"transport":{"method":"webhook","callback":"https://example.com/webhooks/callback"},"created_at":"2019-11-16T10:11:12.634234626Z"},"events":[{"id":"bf7c8577-e3e8-4a16-9d4a-b0c8f1d2a3e4","data":{"organization_id":"9001","category_id":"9002","category_name":"Fortnite","campaign_id":"9003","user_id":"1234","user_name":"Cool_User","user_login":"cool_user","entitlement_id":"fb78259e-fb81-4d1b-8333-34a06ffc24c0","benefit_id":"74c52265-e214-48a6-91b9-23b6014e8041","created_at":"2019-01-28T04:17:53.325Z"}}]}"#;
+        let raw = RawEvent::parse_str(body).unwrap();
+        assert_eq!(raw.event_type(), EventType::DropEntitlementGrant);
+        assert!(raw.is_notification());
+        assert!(raw.is::<crate::eventsub::drop::DropEntitlementGrantV1>());
+    }
+}
@@ -92,7 +92,7 @@
 //!
 //! </details>
 //!
-//! <details><summary style="cursor: pointer"><code style="color: var(--link-color)">channel.*</code> 🟡 66/68</summary>
+//! <details><summary style="cursor: pointer"><code style="color: var(--link-color)">channel.*</code> 🟢 68/68</summary>
 //!
 //! | Name | Subscription<br>Payload |
 //! |---|:---|
@@ -100,7 +100,7 @@
 //! | [`channel.ban`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelban) (v1) | [ChannelBanV1](channel::ChannelBanV1)<br>[ChannelBanV1Payload](channel::ChannelBanV1Payload) |
 //! | [`channel.bits.use`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelbitsuse) (v1) | [ChannelBitsUseV1](channel::ChannelBitsUseV1)<br>[ChannelBitsUseV1Payload](channel::ChannelBitsUseV1Payload) |
 //! | [<span style="font-size: 0.9em">`channel.channel_points_automatic_reward_redemption.add`</span>](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchannel_points_automatic_reward_redemptionadd) (v1) | [ChannelPointsAutomaticRewardRedemptionAddV1](channel::ChannelPointsAutomaticRewardRedemptionAddV1)<br>[ChannelPointsAutomaticRewardRedemptionAddV1Payload](channel::ChannelPointsAutomaticRewardRedemptionAddV1Payload) |
-//! | [<span style="font-size: 0.9em">`channel.channel_points_automatic_reward_redemption.add`</span>](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchannel_points_automatic_reward_redemptionadd-v2) (v2) | -<br>- |
+//! | [<span style="font-size: 0.9em">`channel.channel_points_automatic_reward_redemption.add`</span>](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchannel_points_automatic_reward_redemptionadd-v2) (v2) | [ChannelPointsAutomaticRewardRedemptionAddV2](channel::ChannelPointsAutomaticRewardRedemptionAddV2)<br>[ChannelPointsAutomaticRewardRedemptionAddV2Payload](channel::ChannelPointsAutomaticRewardRedemptionAddV2Payload) |
 //! | [<span style="font-size: 0.9em">`channel.channel_points_custom_reward.add`</span>](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchannel_points_custom_rewardadd) (v1) | [ChannelPointsCustomRewardAddV1](channel::ChannelPointsCustomRewardAddV1)<br>[ChannelPointsCustomRewardAddV1Payload](channel::ChannelPointsCustomRewardAddV1Payload) |
 //! | [<span style="font-size: 0.9em">`channel.channel_points_custom_reward.remove`</span>](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchannel_points_custom_rewardremove) (v1) | [ChannelPointsCustomRewardRemoveV1](channel::ChannelPointsCustomRewardRemoveV1)<br>[ChannelPointsCustomRewardRemoveV1Payload](channel::ChannelPointsCustomRewardRemoveV1Payload) |
 //! | [<span style="font-size: 0.9em">`channel.channel_points_custom_reward.update`</span>](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchannel_points_custom_rewardupdate) (v1) | [ChannelPointsCustomRewardUpdateV1](channel::ChannelPointsCustomRewardUpdateV1)<br>[ChannelPointsCustomRewardUpdateV1Payload](channel::ChannelPointsCustomRewardUpdateV1Payload) |
@@ -119,7 +119,7 @@
 //! | [`channel.chat.user_message_update`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchatuser_message_update) (v1) | [ChannelChatUserMessageUpdateV1](channel::ChannelChatUserMessageUpdateV1)<br>[ChannelChatUserMessageUpdateV1Payload](channel::ChannelChatUserMessageUpdateV1Payload) |
 //! | [`channel.chat_settings.update`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelchat_settingsupdate) (v1) | [ChannelChatSettingsUpdateV1](channel::ChannelChatSettingsUpdateV1)<br>[ChannelChatSettingsUpdateV1Payload](channel::ChannelChatSettingsUpdateV1Payload) |
 //! | [`channel.cheer`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelcheer) (v1) | [ChannelCheerV1](channel::ChannelCheerV1)<br>[ChannelCheerV1Payload](channel::ChannelCheerV1Payload) |
-//! | [`channel.custom_power_up_redemption.add`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelcustom_power_up_redemptionadd) (v1) | -<br>- |
+//! | [`channel.custom_power_up_redemption.add`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelcustom_power_up_redemptionadd) (v1) | [ChannelCustomPowerUpRedemptionAddV1](channel::ChannelCustomPowerUpRedemptionAddV1)<br>[ChannelCustomPowerUpRedemptionAddV1Payload](channel::ChannelCustomPowerUpRedemptionAddV1Payload) |
 //! | [`channel.follow`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelfollow) (v2) | [ChannelFollowV2](channel::ChannelFollowV2)<br>[ChannelFollowV2Payload](channel::ChannelFollowV2Payload) |
 //! | [`channel.goal.begin`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelgoalbegin) (v1) | [ChannelGoalBeginV1](channel::ChannelGoalBeginV1)<br>[ChannelGoalBeginV1Payload](channel::ChannelGoalBeginV1Payload) |
 //! | [`channel.goal.end`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelgoalend) (v1) | [ChannelGoalEndV1](channel::ChannelGoalEndV1)<br>[ChannelGoalEndV1Payload](channel::ChannelGoalEndV1Payload) |
@@ -175,11 +175,11 @@
 //!
 //! </details>
 //!
-//! <details><summary style="cursor: pointer"><code style="color: var(--link-color)">drop.*</code> 🔴 0/1</summary>
+//! <details><summary style="cursor: pointer"><code style="color: var(--link-color)">drop.*</code> 🟢 1/1</summary>
 //!
 //! | Name | Subscription<br>Payload |
 //! |---|:---|
-//! | [`drop.entitlement.grant`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#dropentitlementgrant) (v1) | -<br>- |
+//! | [`drop.entitlement.grant`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#dropentitlementgrant) (v1) | [DropEntitlementGrantV1](drop::DropEntitlementGrantV1)<br>[DropEntitlementGrantV1Payload](drop::DropEntitlementGrantV1Payload) |
 //!
 //! </details>
 //!
@@ -224,13 +224,24 @@ use crate::parse_json;
 pub mod automod;
 pub mod channel;
 pub mod conduit;
+pub mod drop;
 pub mod event;
 pub mod extension;
+#[cfg(all(feature = "helix", feature = "client"))]
+#[cfg_attr(nightly, doc(cfg(all(feature = "helix", feature = "client"))))]
+pub mod reconcile;
+pub mod router;
 pub mod stream;
 pub mod user;
+#[cfg(all(feature = "tower", feature = "hmac"))]
+#[cfg_attr(nightly, doc(cfg(all(feature = "tower", feature = "hmac"))))]
+pub mod webhook;
+#[cfg(feature = "eventsub_websocket")]
+#[cfg_attr(nightly, doc(cfg(feature = "eventsub_websocket")))]
+pub mod websocket;
 
 #[doc(inline)]
-pub use event::{Event, EventType};
+pub use event::{raw::RawEvent, Event, EventType};
 
 pub use event::websocket::*;
 
@@ -249,6 +260,8 @@ pub trait EventSubscription: DeserializeOwned + serde::Serialize + PartialEq + C
     const VERSION: &'static str;
     /// Subscription type name.
     const EVENT_TYPE: EventType;
+    /// Notifications for this subscription are batched, and Twitch requires `is_batching_enabled` to be set when creating it.
+    const IS_BATCHING_ENABLED: bool = false;
 
     /// Creates the [`condition`](https://dev.twitch.tv/docs/eventsub/eventsub-reference#conditions) for this EventSub subscription
     fn condition(&self) -> Result<serde_json::Value, serde_json::Error> {
@@ -351,7 +364,8 @@ impl<E: EventSubscription> Payload<E> {
         struct Notification<E: EventSubscription> {
             #[serde(bound = "E: EventSubscription")]
             pub subscription: EventSubscriptionInformation<E>,
-            #[serde(bound = "E: EventSubscription")]
+            // batched notifications, e.g. `drop.entitlement.grant`, use `events`
+            #[serde(bound = "E: EventSubscription", alias = "events")]
             pub event: <E as EventSubscription>::Payload,
         }
 
@@ -541,6 +555,13 @@ pub enum PayloadParseError {
         /// Event type
         event_type: EventType,
     },
+    /// event is of type `{event_type}` version `{version}`, which is not the requested subscription
+    MismatchedEvent {
+        /// Version
+        version: String,
+        /// Event type
+        event_type: EventType,
+    },
 }
 
 /// Notification received
@@ -1056,7 +1077,8 @@ pub enum ShardStatus {
 }
 
 /// A structured error that occurred with a shard
-#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug, thiserror::Error)]
+#[error("shard {id} could not be updated: {message} ({code})")]
 #[non_exhaustive]
 pub struct ShardError {
     /// Shard ID.
@@ -0,0 +1,466 @@
+//! Declarative management of EventSub subscriptions
+//!
+//! Declare which subscriptions should exist with a [`Reconciler`], and [`Reconciler::reconcile`] makes it so:
+//!
+//! * subscriptions that are declared but don't exist are created,
+//! * subscriptions on a declared transport that are not declared are deleted,
+//! * subscriptions on a declared transport that can no longer deliver events, e.g. because the authorization was [revoked](Status::AuthorizationRevoked), are deleted and created again if they are declared.
+//!
+//! Subscriptions are not created once the [maximum total cost](ReconcileReport::max_total_cost) has been reached, these are reported as [failed](ReconcileFailure::MaxTotalCost).
+//! Subscriptions are matched on their type, version, condition and transport.
+//!
+//! # Examples
+//!
+//! ```rust, no_run
+//! use twitch_api::eventsub::{reconcile::Reconciler, stream::StreamOnlineV1, Transport};
+//! # use twitch_api::{client, helix};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+//! # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let transport = Transport::webhook("https://example.com/eventsub", "secret".to_owned());
+//! let report = Reconciler::new(&client)
+//!     .with_subscription(StreamOnlineV1::broadcaster_user_id("1234"), transport.clone())
+//!     .with_subscription(StreamOnlineV1::broadcaster_user_id("5678"), transport)
+//!     .reconcile(&token)
+//!     .await?;
+//! println!(
+//!     "created {}, deleted {}, cost {}/{}",
+//!     report.created.len(),
+//!     report.deleted.len(),
+//!     report.total_cost,
+//!     report.max_total_cost
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use twitch_oauth2::TwitchToken;
+
+use super::{
+    EventSubSubscription, EventSubscription, EventType, Status, Transport, TransportResponse,
+};
+use crate::helix::{self, ClientRequestError, HelixClient};
+
+/// Declares which subscriptions should exist, see the [module documentation](self)
+pub struct Reconciler<'a, C, T>
+where
+    C: crate::HttpClient,
+    T: TwitchToken + Send + Sync + ?Sized, {
+    helix: &'a HelixClient<'a, C>,
+    subscriptions: Vec<Declared<'a, C, T>>,
+}
+
+struct Declared<'a, C: crate::HttpClient, T: TwitchToken + Send + Sync + ?Sized> {
+    subscription: Box<dyn Create<C, T> + 'a>,
+    condition: serde_json::Value,
+    transport: Transport,
+}
+
+impl<'a, C, T> Reconciler<'a, C, T>
+where
+    C: crate::HttpClient + Send + Sync + 'a,
+    T: TwitchToken + Send + Sync + ?Sized,
+{
+    /// Create a reconciler without any subscriptions
+    pub fn new(helix: &'a HelixClient<'a, C>) -> Self {
+        Self {
+            helix,
+            subscriptions: vec![],
+        }
+    }
+
+    /// Declare that `subscription` should exist on `transport`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the condition of the subscription can't be serialized, which is a bug in the [`EventSubscription`] implementation.
+    pub fn with_subscription<E>(mut self, subscription: E, transport: Transport) -> Self
+    where E: EventSubscription + Send + Sync + 'a {
+        let condition = subscription
+            .condition()
+            .expect("condition should always be serializable");
+        self.subscriptions.push(Declared {
+            subscription: Box::new(subscription),
+            condition,
+            transport,
+        });
+        self
+    }
+
+    /// Create and delete subscriptions so that only the declared ones exist on the declared transports
+    ///
+    /// Failing to create or delete a subscription does not stop the reconciliation, the failures are part of the [report](ReconcileReport::failed).
+    /// An error is only returned if the existing subscriptions could not be retrieved.
+    pub async fn reconcile(
+        &self,
+        token: &T,
+    ) -> Result<ReconcileReport<C::Error>, ClientRequestError<C::Error>> {
+        let mut report = ReconcileReport::default();
+        let mut existing = vec![];
+        let mut page = Some(
+            self.helix
+                .req_get(
+                    helix::eventsub::GetEventSubSubscriptionsRequest::default(),
+                    token,
+                )
+                .await?,
+        );
+        while let Some(response) = page {
+            report.total_cost = response.data.total_cost;
+            report.max_total_cost = response.data.max_total_cost;
+            existing.extend(response.data.subscriptions.iter().cloned());
+            page = response.get_next(self.helix, token).await?;
+        }
+
+        // decide what to keep before deleting, so freed up cost can be used for new subscriptions
+        let mut missing: Vec<&Declared<'a, C, T>> = self.subscriptions.iter().collect();
+        let mut stale = vec![];
+        for subscription in existing {
+            if is_alive(&subscription.status) {
+                if let Some(declared) = missing.iter().position(|d| d.matches(&subscription)) {
+                    missing.swap_remove(declared);
+                    report.unchanged.push(subscription);
+                    continue;
+                }
+            }
+            // subscriptions on other transports may belong to someone else using the same client id
+            if self
+                .subscriptions
+                .iter()
+                .any(|d| same_transport(&d.transport, &subscription.transport))
+            {
+                stale.push(subscription);
+            }
+        }
+
+        for subscription in stale {
+            match self
+                .helix
+                .req_delete(
+                    helix::eventsub::DeleteEventSubSubscriptionRequest::id(subscription.id.clone()),
+                    token,
+                )
+                .await
+            {
+                Ok(_) => {
+                    report.total_cost = report.total_cost.saturating_sub(subscription.cost);
+                    report.deleted.push(subscription);
+                }
+                Err(error) => report.failed.push(ReconcileFailure::Delete {
+                    subscription,
+                    error,
+                }),
+            }
+        }
+
+        for declared in missing {
+            let event_type = declared.subscription.event_type();
+            // a subscription costs at most 1
+            if report.is_at_max_cost() {
+                report
+                    .failed
+                    .push(ReconcileFailure::MaxTotalCost { event_type });
+                continue;
+            }
+            match declared
+                .subscription
+                .create(self.helix, declared.transport.clone(), token)
+                .await
+            {
+                Ok(created) => {
+                    report.total_cost = created.total_cost;
+                    report.max_total_cost = created.max_total_cost;
+                    report.created.push(created.subscription);
+                }
+                Err(ClientRequestError::HelixRequestPostError(
+                    helix::HelixRequestPostError::Error { status, .. },
+                )) if status == http::StatusCode::CONFLICT => {
+                    report.already_exists.push(event_type);
+                }
+                Err(error) => report
+                    .failed
+                    .push(ReconcileFailure::Create { event_type, error }),
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+impl<C, T> Declared<'_, C, T>
+where
+    C: crate::HttpClient,
+    T: TwitchToken + Send + Sync + ?Sized,
+{
+    fn matches(&self, subscription: &EventSubSubscription) -> bool {
+        self.subscription.event_type() == subscription.type_
+            && self.subscription.version() == subscription.version
+            && same_transport(&self.transport, &subscription.transport)
+            && normalize(&self.condition) == normalize(&subscription.condition)
+    }
+}
+
+/// What changed when [reconciling](Reconciler::reconcile)
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ReconcileReport<RE: std::error::Error + Send + Sync + 'static> {
+    /// Subscriptions that were created
+    pub created: Vec<EventSubSubscription>,
+    /// Subscriptions that were deleted, either because they were not declared or because they can no longer deliver events
+    pub deleted: Vec<EventSubSubscription>,
+    /// Declared subscriptions that already existed
+    pub unchanged: Vec<EventSubSubscription>,
+    /// Declared subscriptions that Twitch reported as already existing when creating them, e.g. because they were created concurrently
+    pub already_exists: Vec<EventType>,
+    /// Subscriptions that could not be created or deleted, or were not created because the maximum total cost was reached
+    pub failed: Vec<ReconcileFailure<RE>>,
+    /// Total cost of all subscriptions of the client, as last reported by Twitch
+    pub total_cost: usize,
+    /// The maximum total cost allowed for all subscriptions of the client
+    pub max_total_cost: usize,
+}
+
+impl<RE: std::error::Error + Send + Sync + 'static> ReconcileReport<RE> {
+    /// Returns `true` if nothing was created or deleted and nothing failed
+    pub fn is_unchanged(&self) -> bool {
+        self.created.is_empty() && self.deleted.is_empty() && self.failed.is_empty()
+    }
+
+    /// Returns `true` if no more subscriptions with a cost can be created
+    pub const fn is_at_max_cost(&self) -> bool { self.total_cost >= self.max_total_cost }
+}
+
+impl<RE: std::error::Error + Send + Sync + 'static> Default for ReconcileReport<RE> {
+    fn default() -> Self {
+        Self {
+            created: vec![],
+            deleted: vec![],
+            unchanged: vec![],
+            already_exists: vec![],
+            failed: vec![],
+            total_cost: 0,
+            max_total_cost: 0,
+        }
+    }
+}
+
+/// A subscription that could not be created or deleted
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[non_exhaustive]
+pub enum ReconcileFailure<RE: std::error::Error + Send + Sync + 'static> {
+    /// could not create subscription for `{event_type}`
+    Create {
+        /// The subscription type that failed
+        event_type: EventType,
+        /// The error from helix
+        #[source]
+        error: ClientRequestError<RE>,
+    },
+    /// could not create subscription for `{event_type}`, the maximum total cost has been reached
+    MaxTotalCost {
+        /// The subscription type that was not created
+        event_type: EventType,
+    },
+    /// could not delete subscription
+    Delete {
+        /// The subscription that could not be deleted
+        subscription: EventSubSubscription,
+        /// The error from helix
+        #[source]
+        error: ClientRequestError<RE>,
+    },
+}
+
+/// A subscription is alive if it can deliver events, now or after the callback was verified
+const fn is_alive(status: &Status) -> bool {
+    matches!(
+        status,
+        Status::Enabled | Status::WebhookCallbackVerificationPending
+    )
+}
+
+fn same_transport(transport: &Transport, response: &TransportResponse) -> bool {
+    match (transport, response) {
+        (Transport::Webhook(t), TransportResponse::Webhook(r)) => t.callback == r.callback,
+        (Transport::Websocket(t), TransportResponse::Websocket(r)) => t.session_id == r.session_id,
+        (Transport::Conduit(t), TransportResponse::Conduit(r)) => t.conduit_id == r.conduit_id,
+        _ => false,
+    }
+}
+
+/// Twitch returns conditions with unset fields as empty strings, while they may be omitted or `null` when serialized
+fn normalize(condition: &serde_json::Value) -> serde_json::Value {
+    match condition {
+        serde_json::Value::Object(map) => map
+            .iter()
+            .filter(|(_, v)| !v.is_null() && v.as_str() != Some(""))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect(),
+        other => other.clone(),
+    }
+}
+
+/// The parts of a created subscription the reconciler needs
+struct Created {
+    subscription: EventSubSubscription,
+    total_cost: usize,
+    max_total_cost: usize,
+}
+
+#[async_trait::async_trait]
+trait Create<C: crate::HttpClient, T: TwitchToken + Send + Sync + ?Sized>: Send + Sync {
+    async fn create(
+        &self,
+        helix: &HelixClient<'_, C>,
+        transport: Transport,
+        token: &T,
+    ) -> Result<Created, ClientRequestError<C::Error>>;
+
+    fn event_type(&self) -> EventType;
+
+    fn version(&self) -> &'static str;
+}
+
+#[async_trait::async_trait]
+impl<C, T, E> Create<C, T> for E
+where
+    C: crate::HttpClient + Sync,
+    T: TwitchToken + Send + Sync + ?Sized,
+    E: EventSubscription + Send + Sync,
+{
+    async fn create(
+        &self,
+        helix: &HelixClient<'_, C>,
+        transport: Transport,
+        token: &T,
+    ) -> Result<Created, ClientRequestError<C::Error>> {
+        let created = helix
+            .create_eventsub_subscription(self.clone(), transport, token)
+            .await?;
+        Ok(Created {
+            subscription: EventSubSubscription {
+                cost: created.cost,
+                condition: created.condition.condition().unwrap_or_default(),
+                created_at: created.created_at,
+                id: created.id,
+                status: created.status,
+                transport: created.transport,
+                type_: created.type_,
+                version: created.version,
+            },
+            total_cost: created.total_cost,
+            max_total_cost: created.max_total_cost,
+        })
+    }
+
+    fn event_type(&self) -> EventType { E::EVENT_TYPE }
+
+    fn version(&self) -> &'static str { E::VERSION }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        eventsub::stream::StreamOnlineV1,
+        tests::{token, MockClient},
+    };
+
+    fn subscription(id: &str, broadcaster: &str, status: &str, callback: &str) -> String {
+        format!(
+            r#"{{"id":"{id}","status":"{status}","type":"stream.online","version":"1","cost":1,"condition":{{"broadcaster_user_id":"{broadcaster}"}},"created_at":"2020-11-10T20:08:33.12345678Z","transport":{{"method":"webhook","callback":"{callback}"}}}}"#
+        )
+    }
+
+    #[test]
+    fn reconcile() {
+        let list = format!(
+            r#"{{"total":5,"data":[{},{},{},{},{}],"total_cost":5,"max_total_cost":10,"pagination":{{}}}}"#,
+            // declared, kept
+            subscription("1", "1234", "enabled", "https://example.com/eventsub"),
+            // not declared, deleted
+            subscription("2", "9999", "enabled", "https://example.com/eventsub"),
+            // declared but revoked, deleted and created again
+            subscription(
+                "3",
+                "5678",
+                "authorization_revoked",
+                "https://example.com/eventsub"
+            ),
+            // other transport, kept
+            subscription("4", "9999", "enabled", "https://other.example.com/eventsub"),
+            // other transport and revoked, kept
+            subscription(
+                "5",
+                "1234",
+                "authorization_revoked",
+                "https://other.example.com/eventsub"
+            ),
+        );
+        let helix = MockClient::helix([
+            (200, list),
+            (204, String::new()),
+            (204, String::new()),
+            (
+                409,
+                r#"{"error":"Conflict","status":409,"message":"subscription already exists"}"#
+                    .to_owned(),
+            ),
+        ]);
+        let token = token();
+        let transport = Transport::webhook("https://example.com/eventsub", "secret".to_owned());
+
+        let report = futures::executor::block_on(
+            Reconciler::new(&helix)
+                .with_subscription(
+                    StreamOnlineV1::broadcaster_user_id("1234"),
+                    transport.clone(),
+                )
+                .with_subscription(StreamOnlineV1::broadcaster_user_id("5678"), transport)
+                .reconcile(&token),
+        )
+        .unwrap();
+
+        assert_eq!(report.unchanged.len(), 1);
+        assert_eq!(report.unchanged[0].id.as_str(), "1");
+        let deleted: Vec<_> = report.deleted.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(deleted, ["2", "3"]);
+        assert!(report.created.is_empty());
+        assert_eq!(report.already_exists, [EventType::StreamOnline]);
+        assert!(report.failed.is_empty());
+        assert_eq!(report.total_cost, 3);
+        assert_eq!(report.max_total_cost, 10);
+
+        let requests = helix.get_client().requests.lock().unwrap();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[3].0, http::Method::POST);
+        assert!(requests[3].2.contains(r#""broadcaster_user_id":"5678""#));
+    }
+
+    #[test]
+    fn stops_at_max_total_cost() {
+        let list = r#"{"total":0,"data":[],"total_cost":10,"max_total_cost":10,"pagination":{}}"#;
+        let helix = MockClient::helix([(200, list)]);
+        let token = token();
+        let transport = Transport::webhook("https://example.com/eventsub", "secret".to_owned());
+
+        let report = futures::executor::block_on(
+            Reconciler::new(&helix)
+                .with_subscription(StreamOnlineV1::broadcaster_user_id("1234"), transport)
+                .reconcile(&token),
+        )
+        .unwrap();
+
+        assert!(report.created.is_empty());
+        assert!(matches!(
+            report.failed[..],
+            [ReconcileFailure::MaxTotalCost {
+                event_type: EventType::StreamOnline
+            }]
+        ));
+        assert_eq!(helix.get_client().requests.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,421 @@
+//! Route [`Event`]s to handlers for their subscription type
+//!
+//! Instead of matching on [`Event`], register an async handler per [`EventSubscription`] with [`EventRouter::on`].
+//! Handlers receive the typed notification and the [subscription metadata](EventSubscriptionInformation).
+//! Revocations, events without a handler and events of types this library doesn't know go to the
+//! [revocation](EventRouter::on_revocation) and [fallthrough](EventRouter::fallthrough) handlers.
+//!
+//! Events can be routed from webhooks with [`EventRouter::route_http`], from websockets with [`EventRouter::route_websocket`],
+//! or directly with [`EventRouter::dispatch`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use twitch_api::eventsub::{
+//!     channel::{ChannelChatMessageV1, ChannelFollowV2},
+//!     router::{EventRouter, Unhandled},
+//! };
+//!
+//! let router = EventRouter::new()
+//!     .on::<ChannelChatMessageV1>(|message, meta| async move {
+//!         println!(
+//!             "{}: {} (subscription {})",
+//!             message.chatter_user_name, message.message.text, meta.id
+//!         );
+//!     })
+//!     .on::<ChannelFollowV2>(|follow, _| async move {
+//!         println!("{} followed", follow.user_name);
+//!     })
+//!     .on_revocation(|subscription| async move {
+//!         println!(
+//!             "{} was revoked: {:?}",
+//!             subscription.type_, subscription.status
+//!         );
+//!     })
+//!     .fallthrough(|unhandled| async move {
+//!         if let Unhandled::Unknown(unknown) = unhandled {
+//!             println!(
+//!                 "unknown event {} v{}",
+//!                 unknown.event_type, unknown.version
+//!             );
+//!         }
+//!     });
+//! # let _ = router;
+//! ```
+//!
+//! Route websocket messages, handling the session messages yourself
+//!
+//! ```rust, no_run
+//! # use twitch_api::eventsub::{router::EventRouter, EventsubWebsocketData};
+//! # async fn run(router: EventRouter<'_>, frame: &str) -> Result<(), Box<dyn std::error::Error>> {
+//! if let Some(EventsubWebsocketData::Welcome { payload, .. }) = router.route_websocket(frame).await? {
+//!     println!("session {} started", payload.session.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{future::Future, pin::Pin};
+
+use serde_derive::Deserialize;
+
+use super::{
+    Event, EventSubSubscription, EventSubscription, EventSubscriptionInformation, EventType,
+    EventsubWebsocketData, Message, Payload, PayloadParseError,
+};
+
+type BoxFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+type Handler<'a, T> = Box<dyn Fn(T) -> BoxFuture<'a> + Send + Sync + 'a>;
+
+/// Routes events to handlers, see the [module documentation](self)
+#[derive(Default)]
+pub struct EventRouter<'a> {
+    routes: Vec<Box<dyn Route<'a> + 'a>>,
+    revocation: Option<Handler<'a, EventSubSubscription>>,
+    fallthrough: Option<Handler<'a, Unhandled>>,
+}
+
+impl<'a> EventRouter<'a> {
+    /// Create a router without any handlers
+    pub fn new() -> Self { Self::default() }
+
+    /// Handle notifications of the subscription type `E`
+    ///
+    /// Replaces the handler previously registered for `E`.
+    pub fn on<E>(
+        mut self,
+        handler: impl EventHandler<E, Future = impl Future<Output = ()> + Send + 'a> + 'a,
+    ) -> Self
+    where
+        E: EventSubscription + Send + 'static,
+        E::Payload: Send,
+    {
+        self.routes
+            .retain(|r| r.subscription_type() != (E::EVENT_TYPE, E::VERSION));
+        self.routes
+            .push(Box::new(Typed(handler, std::marker::PhantomData::<fn(E)>)));
+        self
+    }
+
+    /// Handle revocations of any subscription
+    ///
+    /// Without this handler, revocations go to the [fallthrough handler](EventRouter::fallthrough).
+    pub fn on_revocation<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(EventSubSubscription) -> Fut + Send + Sync + 'a,
+        Fut: Future<Output = ()> + Send + 'a, {
+        self.revocation = Some(Box::new(move |s| Box::pin(handler(s))));
+        self
+    }
+
+    /// Handle everything that no other handler handled
+    pub fn fallthrough<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Unhandled) -> Fut + Send + Sync + 'a,
+        Fut: Future<Output = ()> + Send + 'a, {
+        self.fallthrough = Some(Box::new(move |u| Box::pin(handler(u))));
+        self
+    }
+
+    /// Route an event to its handler
+    pub async fn dispatch(&self, event: Event) {
+        if event.is_revocation() {
+            if let (Some(revocation), Ok(subscription)) = (&self.revocation, event.subscription()) {
+                return revocation(subscription).await;
+            }
+        } else if event.is_notification() {
+            let mut event = event;
+            for route in &self.routes {
+                match route.call(event) {
+                    Ok(handling) => return handling.await,
+                    Err(unhandled) => event = unhandled,
+                }
+            }
+            return self.unhandled(Unhandled::Event(event)).await;
+        }
+        self.unhandled(Unhandled::Event(event)).await
+    }
+
+    /// Parse and route a webhook request
+    ///
+    /// Events of types this library doesn't know go to the [fallthrough handler](EventRouter::fallthrough).
+    /// This does not verify the request, see [`Event::verify_payload`].
+    pub async fn route_http<B>(&self, request: &http::Request<B>) -> Result<(), PayloadParseError>
+    where B: AsRef<[u8]> {
+        match Event::parse_http(request) {
+            Ok(event) => {
+                self.dispatch(event).await;
+                Ok(())
+            }
+            Err(
+                error @ (PayloadParseError::UnknownEventType(_)
+                | PayloadParseError::UnimplementedEvent { .. }),
+            ) => {
+                let header = |name: &str| {
+                    request
+                        .headers()
+                        .get(name)
+                        .and_then(|v| v.to_str().ok())
+                        .map(str::to_owned)
+                };
+                let (Some(event_type), Some(version)) = (
+                    header("Twitch-Eventsub-Subscription-Type"),
+                    header("Twitch-Eventsub-Subscription-Version"),
+                ) else {
+                    return Err(error);
+                };
+                let body = std::str::from_utf8(request.body().as_ref())?.to_owned();
+                self.unhandled(Unhandled::Unknown(UnknownEvent {
+                    event_type,
+                    version,
+                    body,
+                }))
+                .await;
+                Ok(())
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Parse and route a websocket message
+    ///
+    /// Notifications and revocations are routed, other messages, like [welcome](EventsubWebsocketData::Welcome) and
+    /// [reconnect](EventsubWebsocketData::Reconnect) messages, are returned to be handled by the caller.
+    /// Events of types this library doesn't know go to the [fallthrough handler](EventRouter::fallthrough).
+    pub async fn route_websocket<'f>(
+        &self,
+        frame: &'f str,
+    ) -> Result<Option<EventsubWebsocketData<'f>>, PayloadParseError> {
+        match Event::parse_websocket(frame) {
+            Ok(
+                EventsubWebsocketData::Notification { payload, .. }
+                | EventsubWebsocketData::Revocation { payload, .. },
+            ) => {
+                self.dispatch(payload).await;
+                Ok(None)
+            }
+            Ok(data) => Ok(Some(data)),
+            Err(error) => {
+                let Some(unknown) = UnknownEvent::from_websocket(frame, &error) else {
+                    return Err(error);
+                };
+                self.unhandled(Unhandled::Unknown(unknown)).await;
+                Ok(None)
+            }
+        }
+    }
+
+    async fn unhandled(&self, unhandled: Unhandled) {
+        if let Some(fallthrough) = &self.fallthrough {
+            fallthrough(unhandled).await;
+        }
+    }
+}
+
+impl std::fmt::Debug for EventRouter<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventRouter")
+            .field(
+                "routes",
+                &self
+                    .routes
+                    .iter()
+                    .map(|r| r.subscription_type())
+                    .collect::<Vec<_>>(),
+            )
+            .field("revocation", &self.revocation.is_some())
+            .field("fallthrough", &self.fallthrough.is_some())
+            .finish()
+    }
+}
+
+/// Something that was not handled by a typed or revocation handler
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+#[non_exhaustive]
+pub enum Unhandled {
+    /// An event without a handler, a revocation without a [revocation handler](EventRouter::on_revocation), or a webhook verification request
+    Event(Event),
+    /// An event of a type or version this library doesn't know
+    Unknown(UnknownEvent),
+}
+
+/// An event of a type or version this library doesn't know
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct UnknownEvent {
+    /// The subscription type, e.g. `channel.follow`
+    pub event_type: String,
+    /// The subscription version
+    pub version: String,
+    /// The raw webhook body or websocket message
+    pub body: String,
+}
+
+impl UnknownEvent {
+    fn from_websocket(frame: &str, error: &PayloadParseError) -> Option<Self> {
+        #[derive(Deserialize)]
+        struct Frame {
+            metadata: Metadata,
+        }
+        #[derive(Deserialize)]
+        struct Metadata {
+            subscription_type: String,
+            subscription_version: String,
+        }
+
+        let Frame { metadata } = serde_json::from_str(frame).ok()?;
+        let known = metadata.subscription_type.parse::<EventType>().is_ok();
+        if known && !matches!(error, PayloadParseError::UnimplementedEvent { .. }) {
+            return None;
+        }
+        Some(Self {
+            event_type: metadata.subscription_type,
+            version: metadata.subscription_version,
+            body: frame.to_owned(),
+        })
+    }
+}
+
+/// An async handler for notifications of the subscription type `E`
+///
+/// Implemented for closures taking the [payload](EventSubscription::Payload) and [subscription metadata](EventSubscriptionInformation)
+/// and returning a future.
+pub trait EventHandler<E: EventSubscription>:
+    Fn(E::Payload, EventSubscriptionInformation<E>) -> <Self as EventHandler<E>>::Future + Send + Sync
+{
+    /// The future returned by the handler
+    type Future: Future<Output = ()> + Send;
+}
+
+impl<E, F, Fut> EventHandler<E> for F
+where
+    E: EventSubscription,
+    F: Fn(E::Payload, EventSubscriptionInformation<E>) -> Fut + Send + Sync,
+    Fut: Future<Output = ()> + Send,
+{
+    type Future = Fut;
+}
+
+trait Route<'a>: Send + Sync {
+    /// Handle the event, or give it back if it's not for this route
+    // the event is passed on to the next route, boxing it would allocate for every route tried
+    #[allow(clippy::result_large_err)]
+    fn call(&self, event: Event) -> Result<BoxFuture<'a>, Event>;
+
+    fn subscription_type(&self) -> (EventType, &'static str);
+}
+
+struct Typed<E, H>(H, std::marker::PhantomData<fn(E)>);
+
+impl<'a, E, H> Route<'a> for Typed<E, H>
+where
+    E: EventSubscription + Send + 'static,
+    E::Payload: Send,
+    H: EventHandler<E> + 'a,
+    H::Future: 'a,
+{
+    fn call(&self, event: Event) -> Result<BoxFuture<'a>, Event> {
+        let Payload {
+            subscription,
+            message,
+        } = event.try_into_payload::<E>()?;
+        match message {
+            Message::Notification(notification) => {
+                Ok(Box::pin((self.0)(notification, subscription)))
+            }
+            // only notifications are routed
+            _ => Ok(Box::pin(async {})),
+        }
+    }
+
+    fn subscription_type(&self) -> (EventType, &'static str) { (E::EVENT_TYPE, E::VERSION) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::eventsub::channel::{ChannelBanV1, ChannelFollowV2};
+
+    fn websocket(message_type: &str, event_type: &str, version: &str, event: &str) -> String {
+        // `channel.follow` v1 has no moderator in its condition
+        let condition = if version == "1" {
+            r#"{"broadcaster_user_id":"12826"}"#
+        } else {
+            r#"{"broadcaster_user_id":"12826","moderator_user_id":"12826"}"#
+        };
+        format!(
+            r#"{{"metadata":{{"message_id":"befa7b53-d79d-478f-86b9-120f112b044e","message_type":"{message_type}","message_timestamp":"2019-11-16T10:11:12.123Z","subscription_type":"{event_type}","subscription_version":"{version}"}},"payload":{{"subscription":{{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","status":"enabled","type":"{event_type}","version":"{version}","cost":0,"condition":{condition},"transport":{{"method":"websocket","session_id":"AQoQexAWVYKSTIu4ec_2VAxyuhAB"}},"created_at":"2019-11-16T10:11:12.123Z"}}{event}}}}}"#
+        )
+    }
+
+    const FOLLOW: &str = r#","event":{"user_id":"1337","user_login":"awesome_user","user_name":"Awesome_User","broadcaster_user_id":"12826","broadcaster_user_login":"twitch","broadcaster_user_name":"Twitch","followed_at":"2020-07-15T18:16:11.17106713Z"}"#;
+
+    #[test]
+    fn route() {
+        let log = Arc::new(Mutex::new(vec![]));
+        let router = EventRouter::new()
+            .on::<ChannelBanV1>({
+                let log = log.clone();
+                move |ban, _| {
+                    log.lock().unwrap().push(format!("ban {}", ban.user_login));
+                    async {}
+                }
+            })
+            .on::<ChannelFollowV2>({
+                let log = log.clone();
+                move |follow, meta| {
+                    log.lock()
+                        .unwrap()
+                        .push(format!("follow {} {}", follow.user_login, meta.id));
+                    async {}
+                }
+            })
+            .on_revocation({
+                let log = log.clone();
+                move |subscription| {
+                    log.lock()
+                        .unwrap()
+                        .push(format!("revoked {}", subscription.type_));
+                    async {}
+                }
+            })
+            .fallthrough({
+                let log = log.clone();
+                move |unhandled| {
+                    let entry = match unhandled {
+                        Unhandled::Event(_) => "event".to_owned(),
+                        Unhandled::Unknown(u) => format!("unknown {} {}", u.event_type, u.version),
+                    };
+                    log.lock().unwrap().push(entry);
+                    async {}
+                }
+            });
+
+        let frames = [
+            websocket("notification", "channel.follow", "2", FOLLOW),
+            websocket("revocation", "channel.follow", "2", ""),
+            websocket("notification", "channel.follow", "1", FOLLOW),
+            websocket("notification", "channel.unknown", "1", FOLLOW),
+            websocket("notification", "channel.follow", "99", FOLLOW),
+        ];
+        for frame in &frames {
+            assert!(futures::executor::block_on(router.route_websocket(frame))
+                .unwrap()
+                .is_none());
+        }
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "follow awesome_user f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+                "revoked channel.follow",
+                "event",
+                "unknown channel.unknown 1",
+                "unknown channel.follow 99",
+            ]
+        );
+    }
+}
@@ -0,0 +1,485 @@
+//! A [tower](tower_service::Service) service for receiving EventSub notifications over webhooks.
+//!
+//! [`WebhookService`] takes care of everything Twitch expects from a webhook callback:
+//!
+//! * verifying the `Twitch-Eventsub-Message-Signature` HMAC with your secret,
+//! * rejecting messages with a `Twitch-Eventsub-Message-Timestamp` older than 10 minutes,
+//! * answering the `webhook_callback_verification` challenge,
+//! * dropping messages with an already seen `Twitch-Eventsub-Message-Id`,
+//!
+//! and forwards every verified notification and revocation to an [`EventHandler`],
+//! which can be a closure or a [channel](futures::channel::mpsc::UnboundedSender).
+//!
+//! The service itself accepts `http::Request<Bytes>`,
+//! use [`WebhookService::collect_body`] to accept any [`Body`](hyper::body::Body), e.g. when mounting it in [`axum`](https://docs.rs/axum) or [`hyper`].
+//!
+//! # Examples
+//!
+//! ```rust, no_run
+//! # async fn run() {
+//! use twitch_api::eventsub::{webhook::WebhookService, Event};
+//!
+//! let (sender, mut receiver) = futures::channel::mpsc::unbounded::<Event>();
+//! let service = WebhookService::new("my secret", sender).collect_body(64 * 1024);
+//! // mount the service, e.g. `axum::Router::new().route_service("/twitch/eventsub", service)`
+//! # let _ = service;
+//! use futures::StreamExt;
+//! while let Some(event) = receiver.next().await {
+//!     println!("{event:?}");
+//! }
+//! # }
+//! ```
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, SystemTime},
+};
+
+use http_body_util::{BodyExt, Full, Limited};
+use tower_service::Service;
+
+use crate::{
+    client::{BoxedFuture, Bytes},
+    common::calendar,
+};
+
+use super::Event;
+
+/// How old a message is allowed to be before it is rejected.
+pub const DEFAULT_MAX_MESSAGE_AGE: Duration = Duration::from_secs(10 * 60);
+
+/// How far ahead of the local clock a message timestamp is allowed to be before it is rejected.
+pub const MAX_CLOCK_SKEW: Duration = Duration::from_secs(60);
+
+/// Receiver of verified [`Event`]s from a [`WebhookService`]
+///
+/// Implemented for closures taking an [`Event`] and for [`futures::channel::mpsc::UnboundedSender<Event>`].
+pub trait EventHandler: Send + Sync + 'static {
+    /// Handle a verified notification or revocation.
+    ///
+    /// Returning an error makes the service respond with `503 Service Unavailable`, so that Twitch retries the message.
+    fn handle(&self, event: Event) -> Result<(), DeliveryError>;
+}
+
+impl<F> EventHandler for F
+where F: Fn(Event) + Send + Sync + 'static
+{
+    fn handle(&self, event: Event) -> Result<(), DeliveryError> {
+        self(event);
+        Ok(())
+    }
+}
+
+impl EventHandler for futures::channel::mpsc::UnboundedSender<Event> {
+    fn handle(&self, event: Event) -> Result<(), DeliveryError> {
+        self.unbounded_send(event).map_err(|_| DeliveryError)
+    }
+}
+
+/// The event could not be delivered to the handler
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+pub struct DeliveryError;
+
+/// A [`Service`] receiving EventSub webhook messages
+///
+/// See the [module documentation](self) for more information.
+pub struct WebhookService<H> {
+    secret: Arc<[u8]>,
+    handler: Arc<H>,
+    max_age: Duration,
+    seen: Arc<Mutex<SeenMessages>>,
+    now: fn() -> SystemTime,
+}
+
+impl<H: EventHandler> WebhookService<H> {
+    /// Create a new service verifying messages with `secret` and forwarding events to `handler`
+    pub fn new(secret: impl AsRef<[u8]>, handler: H) -> Self {
+        Self {
+            secret: secret.as_ref().into(),
+            handler: Arc::new(handler),
+            max_age: DEFAULT_MAX_MESSAGE_AGE,
+            seen: Arc::default(),
+            now: SystemTime::now,
+        }
+    }
+
+    /// Set how old a message is allowed to be, defaults to [10 minutes](DEFAULT_MAX_MESSAGE_AGE)
+    ///
+    /// Message ids are remembered for this long to detect duplicates.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Accept requests with any [`Body`](hyper::body::Body), collecting at most `limit` bytes
+    pub fn collect_body(self, limit: usize) -> CollectBody<H> { CollectBody { inner: self, limit } }
+
+    fn respond(&self, request: http::Request<Bytes>) -> http::Response<Full<Bytes>> {
+        if !Event::verify_payload(&request, &self.secret) {
+            return response(http::StatusCode::FORBIDDEN, "invalid signature");
+        }
+
+        let now = (self.now)();
+        let timestamp = request
+            .headers()
+            .get("Twitch-Eventsub-Message-Timestamp")
+            .and_then(|t| t.to_str().ok())
+            .and_then(parse_rfc3339);
+        match timestamp {
+            Some(timestamp) if is_stale(timestamp, now, self.max_age) => {
+                return response(http::StatusCode::FORBIDDEN, "message is too old")
+            }
+            Some(timestamp) if is_ahead(timestamp, now) => {
+                return response(http::StatusCode::FORBIDDEN, "message is from the future")
+            }
+            Some(_) => {}
+            None => return response(http::StatusCode::BAD_REQUEST, "invalid timestamp"),
+        }
+
+        let event = match Event::parse_http(&request) {
+            Ok(event) => event,
+            Err(_e) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %_e, "could not parse eventsub message");
+                return response(http::StatusCode::BAD_REQUEST, "invalid payload");
+            }
+        };
+
+        if let Some(verification) = event.get_verification_request() {
+            let mut response = response(http::StatusCode::OK, verification.challenge.clone());
+            response.headers_mut().insert(
+                http::header::CONTENT_TYPE,
+                http::HeaderValue::from_static("text/plain"),
+            );
+            return response;
+        }
+
+        // verify_payload ensures the header is present
+        let Some(id) = request
+            .headers()
+            .get("Twitch-Eventsub-Message-Id")
+            .and_then(|id| id.to_str().ok())
+        else {
+            return response(http::StatusCode::BAD_REQUEST, "invalid message id");
+        };
+
+        if !self.seen.lock().unwrap().insert(id, now, self.max_age) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(message_id = id, "dropping duplicate eventsub message");
+            return response(http::StatusCode::NO_CONTENT, "");
+        }
+
+        match self.handler.handle(event) {
+            Ok(()) => response(http::StatusCode::NO_CONTENT, ""),
+            Err(_) => {
+                // allow the retry from twitch to be delivered
+                self.seen.lock().unwrap().remove(id);
+                response(http::StatusCode::SERVICE_UNAVAILABLE, "")
+            }
+        }
+    }
+}
+
+impl<H> Clone for WebhookService<H> {
+    fn clone(&self) -> Self {
+        Self {
+            secret: self.secret.clone(),
+            handler: self.handler.clone(),
+            max_age: self.max_age,
+            seen: self.seen.clone(),
+            now: self.now,
+        }
+    }
+}
+
+impl<H> std::fmt::Debug for WebhookService<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookService")
+            .field("max_age", &self.max_age)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<H: EventHandler> Service<http::Request<Bytes>> for WebhookService<H> {
+    type Error = std::convert::Infallible;
+    type Future = std::future::Ready<Result<Self::Response, Self::Error>>;
+    type Response = http::Response<Full<Bytes>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<Bytes>) -> Self::Future {
+        std::future::ready(Ok(self.respond(request)))
+    }
+}
+
+/// A [`WebhookService`] accepting any request [`Body`](hyper::body::Body)
+///
+/// Created with [`WebhookService::collect_body`]. Bodies larger than the limit are rejected with `413 Payload Too Large`.
+pub struct CollectBody<H> {
+    inner: WebhookService<H>,
+    limit: usize,
+}
+
+impl<H> Clone for CollectBody<H> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            limit: self.limit,
+        }
+    }
+}
+
+impl<H> std::fmt::Debug for CollectBody<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CollectBody")
+            .field("inner", &self.inner)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<H, B> Service<http::Request<B>> for CollectBody<H>
+where
+    H: EventHandler,
+    B: hyper::body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Error = std::convert::Infallible;
+    type Future = BoxedFuture<'static, Result<Self::Response, Self::Error>>;
+    type Response = http::Response<Full<Bytes>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let inner = self.inner.clone();
+        let limit = self.limit;
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let Ok(body) = Limited::new(body, limit).collect().await else {
+                return Ok(response(
+                    http::StatusCode::PAYLOAD_TOO_LARGE,
+                    "could not read body",
+                ));
+            };
+            Ok(inner.respond(http::Request::from_parts(parts, body.to_bytes())))
+        })
+    }
+}
+
+/// Message ids seen within the max age, in order of arrival.
+#[derive(Default)]
+struct SeenMessages {
+    ids: HashSet<String>,
+    order: VecDeque<(SystemTime, String)>,
+}
+
+impl SeenMessages {
+    /// Returns `false` if the id was already seen
+    fn insert(&mut self, id: &str, now: SystemTime, max_age: Duration) -> bool {
+        while let Some((seen_at, _)) = self.order.front() {
+            if !is_stale(*seen_at, now, max_age) {
+                break;
+            }
+            if let Some((_, id)) = self.order.pop_front() {
+                self.ids.remove(&id);
+            }
+        }
+        if !self.ids.insert(id.to_owned()) {
+            return false;
+        }
+        self.order.push_back((now, id.to_owned()));
+        true
+    }
+
+    fn remove(&mut self, id: &str) {
+        self.ids.remove(id);
+        self.order.retain(|(_, seen)| seen != id);
+    }
+}
+
+fn is_stale(time: SystemTime, now: SystemTime, max_age: Duration) -> bool {
+    now.duration_since(time)
+        .map(|age| age > max_age)
+        .unwrap_or(false)
+}
+
+fn is_ahead(time: SystemTime, now: SystemTime) -> bool {
+    time.duration_since(now)
+        .is_ok_and(|ahead| ahead > MAX_CLOCK_SKEW)
+}
+
+fn response(status: http::StatusCode, body: impl Into<Bytes>) -> http::Response<Full<Bytes>> {
+    let mut response = http::Response::new(Full::new(body.into()));
+    *response.status_mut() = status;
+    response
+}
+
+/// Parse a RFC3339 timestamp, e.g. `2021-02-19T23:47:00.8091512Z`
+fn parse_rfc3339(s: &str) -> Option<SystemTime> {
+    let (secs, nanos) = calendar::parse_rfc3339(s)?;
+    let since_epoch = Duration::new(secs.unsigned_abs(), nanos);
+    if secs >= 0 {
+        SystemTime::UNIX_EPOCH.checked_add(since_epoch)
+    } else {
+        SystemTime::UNIX_EPOCH.checked_sub(since_epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(headers: &[(&str, &str)], body: &str) -> http::Request<Bytes> {
+        let mut request = http::Request::builder().method(http::Method::POST);
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        request.body(Bytes::from(body.to_owned())).unwrap()
+    }
+
+    fn notification() -> http::Request<Bytes> {
+        #[rustfmt::skip]
+        let headers = [
+            ("Twitch-Eventsub-Message-Id", "ae2ff348-e102-16be-a3eb-6830c1bf38d2"),
+            ("Twitch-Eventsub-Message-Retry", "0"),
+            ("Twitch-Eventsub-Message-Signature", "sha256=815a2f4694712e66bdf01e427ca5a5e46deb0699647be836825c0b481e533d45"),
+            ("Twitch-Eventsub-Message-Timestamp", "2021-02-19T23:47:00.8091512Z"),
+            ("Twitch-Eventsub-Message-Type", "notification"),
+            ("Twitch-Eventsub-Subscription-Type", "channel.follow"),
+            ("Twitch-Eventsub-Subscription-Version", "1"),
+        ];
+        let body = r#"{"subscription":{"id":"ae2ff348-e102-16be-a3eb-6830c1bf38d2","status":"enabled","type":"channel.follow","version":"1","cost":0,"condition":{"broadcaster_user_id":"44429626"},"transport":{"method":"webhook","callback":"null"},"created_at":"2021-02-19T23:47:00.7621315Z"},"event":{"user_id":"28408015","user_login":"testFromUser","user_name":"testFromUser","broadcaster_user_id":"44429626","broadcaster_user_login":"44429626","broadcaster_user_name":"testBroadcaster","followed_at":"2021-02-19T23:47:00.7621315Z"}}"#;
+        request(&headers, body)
+    }
+
+    fn call<H: EventHandler>(
+        service: &mut WebhookService<H>,
+        request: http::Request<Bytes>,
+    ) -> http::Response<Full<Bytes>> {
+        futures::executor::block_on(service.call(request)).unwrap()
+    }
+
+    fn body(response: http::Response<Full<Bytes>>) -> Bytes {
+        futures::executor::block_on(response.into_body().collect())
+            .unwrap()
+            .to_bytes()
+    }
+
+    fn shortly_after() -> SystemTime { parse_rfc3339("2021-02-19T23:50:00Z").unwrap() }
+
+    #[test]
+    #[allow(deprecated)]
+    fn forwards_notification_once() {
+        let (sender, mut receiver) = futures::channel::mpsc::unbounded();
+        let mut service = WebhookService::new("secretabcd", sender);
+        service.now = shortly_after;
+
+        let response = call(&mut service, notification());
+        assert_eq!(response.status(), http::StatusCode::NO_CONTENT);
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(Event::ChannelFollowV1(_)))
+        ));
+
+        let response = call(&mut service, notification());
+        assert_eq!(response.status(), http::StatusCode::NO_CONTENT);
+        assert!(receiver.try_next().is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_signature() {
+        let mut service = WebhookService::new("wrong secret", |_: Event| {
+            panic!("event should not be forwarded")
+        });
+        service.now = shortly_after;
+
+        let response = call(&mut service, notification());
+        assert_eq!(response.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn rejects_stale_message() {
+        let mut service = WebhookService::new("secretabcd", |_: Event| {
+            panic!("event should not be forwarded")
+        });
+        service.now = || parse_rfc3339("2021-02-19T23:57:01Z").unwrap();
+
+        let response = call(&mut service, notification());
+        assert_eq!(response.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn rejects_message_from_the_future() {
+        let mut service = WebhookService::new("secretabcd", |_: Event| {
+            panic!("event should not be forwarded")
+        });
+        service.now = || parse_rfc3339("2021-02-19T23:45:00Z").unwrap();
+
+        let response = call(&mut service, notification());
+        assert_eq!(response.status(), http::StatusCode::FORBIDDEN);
+        assert_eq!(body(response), "message is from the future");
+    }
+
+    #[test]
+    fn closed_channel_allows_retry() {
+        let (sender, receiver) = futures::channel::mpsc::unbounded();
+        drop(receiver);
+        let mut service = WebhookService::new("secretabcd", sender);
+        service.now = shortly_after;
+
+        let response = call(&mut service, notification());
+        assert_eq!(response.status(), http::StatusCode::SERVICE_UNAVAILABLE);
+        assert!(service.seen.lock().unwrap().insert(
+            "ae2ff348-e102-16be-a3eb-6830c1bf38d2",
+            (service.now)(),
+            service.max_age
+        ));
+    }
+
+    #[test]
+    fn answers_challenge() {
+        #[rustfmt::skip]
+        let headers = [
+            ("Twitch-Eventsub-Message-Id", "8d8fa82b-9792-79da-4e11-a6fa58a7a582"),
+            ("Twitch-Eventsub-Message-Retry", "0"),
+            ("Twitch-Eventsub-Message-Signature", "sha256=091f6a5c74fba820f2d50e9d0c5e7650556ee009375af2cc662e610e670bc412"),
+            ("Twitch-Eventsub-Message-Timestamp", "2022-02-06T04:03:24.2726598Z"),
+            ("Twitch-Eventsub-Message-Type", "webhook_callback_verification"),
+            ("Twitch-Eventsub-Subscription-Type", "channel.subscribe"),
+            ("Twitch-Eventsub-Subscription-Version", "1"),
+        ];
+        let payload = r#"{"challenge":"11535768-497e-14ec-8197-ba2cb5341a01","subscription":{"id":"8d8fa82b-9792-79da-4e11-a6fa58a7a582","status":"webhook_callback_verification_pending","type":"channel.subscribe","version":"1","condition":{"broadcaster_user_id":"88525095"},"transport":{"method":"webhook","callback":"http://localhost:80/twitch/eventsub"},"created_at":"2022-02-06T04:03:24.2706497Z","cost":0}}"#;
+
+        let mut service = WebhookService::new("HELLOabc2321", |_: Event| {
+            panic!("challenge should not be forwarded")
+        });
+        service.now = || parse_rfc3339("2022-02-06T04:03:30+00:00").unwrap();
+
+        let response = call(&mut service, request(&headers, payload));
+        assert_eq!(response.status(), http::StatusCode::OK);
+        assert_eq!(response.headers()[http::header::CONTENT_TYPE], "text/plain");
+        assert_eq!(body(response), "11535768-497e-14ec-8197-ba2cb5341a01");
+    }
+
+    #[test]
+    fn parse_timestamps() {
+        let epoch = |s| parse_rfc3339(s).map(|t| t.duration_since(SystemTime::UNIX_EPOCH).unwrap());
+        assert_eq!(epoch("1970-01-01T00:00:00Z"), Some(Duration::ZERO));
+        assert_eq!(
+            epoch("2021-02-19T23:47:00.8091512Z"),
+            Some(Duration::new(1613778420, 809_151_200))
+        );
+        assert_eq!(
+            epoch("2021-02-20T01:47:00+02:00"),
+            Some(Duration::new(1613778420, 0))
+        );
+        assert_eq!(epoch("2021-02-19 23:47:00Z"), None);
+        assert_eq!(epoch("2021-13-19T23:47:00Z"), None);
+    }
+}
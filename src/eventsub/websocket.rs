@@ -0,0 +1,478 @@
+//! Client for [EventSub WebSockets](https://dev.twitch.tv/docs/eventsub/handling-websocket-events/)
+//!
+//! The [`Client`] connects to EventSub, creates the subscriptions you've given it when a session is established and keeps the connection alive:
+//!
+//! * On a [reconnect message](https://dev.twitch.tv/docs/eventsub/handling-websocket-events/#reconnect-message),
+//!   the client connects to the new URL and only closes the old connection once the new one is welcomed, so no events are lost.
+//! * If no message is received within [`keepalive_timeout_seconds`](crate::eventsub::SessionData::keepalive_timeout_seconds), the connection is assumed lost.
+//!   A new session is opened and the subscriptions are created again.
+//!
+//! # Notes
+//!
+//! This does not enable a TLS backend for [`tokio-tungstenite`](https://docs.rs/tokio-tungstenite), to connect to Twitch you need to enable one of its TLS features yourself,
+//! e.g `native-tls` or `rustls-tls-webpki-roots`.
+//!
+//! # Examples
+//!
+//! ```rust, no_run
+//! use futures::StreamExt;
+//! use twitch_api::eventsub::{self, channel::ChannelBanV1, websocket, Event, Message};
+//! # use twitch_api::{client, helix};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let mut events = websocket::Client::new(&client, &token)
+//!     .with_subscription(ChannelBanV1::broadcaster_user_id("1234"))
+//!     .into_stream();
+//!
+//! while let Some(event) = events.next().await {
+//!     if let Event::ChannelBanV1(eventsub::Payload {
+//!         message: Message::Notification(ban),
+//!         ..
+//!     }) = event
+//!     {
+//!         println!("{} was banned", ban.user_login);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{convert::TryFrom, time::Duration};
+
+use futures::StreamExt;
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite;
+use twitch_oauth2::TwitchToken;
+
+use super::{
+    Event, EventSubscription, EventType, EventsubWebsocketData, PayloadParseError, Transport,
+};
+use crate::helix::{ClientRequestError, HelixClient};
+
+type Socket =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// Used when Twitch doesn't specify a keepalive timeout
+const DEFAULT_KEEPALIVE: Duration = Duration::from_secs(10);
+/// How long to wait for the welcome message after connecting
+const WELCOME_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long the old connection is kept alive while reconnecting
+const RECONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A client for EventSub WebSockets, see the [module documentation](self)
+pub struct Client<'a, C, T>
+where
+    C: crate::HttpClient,
+    T: TwitchToken + Send + Sync + ?Sized, {
+    helix: &'a HelixClient<'a, C>,
+    token: &'a T,
+    url: url::Url,
+    subscriptions: Vec<Box<dyn Subscribe<C, T> + 'a>>,
+    /// The connection events are received on
+    connection: Option<Connection>,
+    /// The connection to the `reconnect_url`, until it has been welcomed
+    successor: Option<Connection>,
+}
+
+impl<'a, C, T> Client<'a, C, T>
+where
+    C: crate::HttpClient + Sync + 'a,
+    T: TwitchToken + Send + Sync + ?Sized,
+{
+    /// Create a new client that connects to [`TWITCH_EVENTSUB_WEBSOCKET_URL`](crate::TWITCH_EVENTSUB_WEBSOCKET_URL).
+    ///
+    /// Nothing happens until events are requested with [`Client::next_event`] or [`Client::into_stream`].
+    pub fn new(helix: &'a HelixClient<'a, C>, token: &'a T) -> Self {
+        Self {
+            helix,
+            token,
+            url: crate::TWITCH_EVENTSUB_WEBSOCKET_URL.clone(),
+            subscriptions: vec![],
+            connection: None,
+            successor: None,
+        }
+    }
+
+    /// Connect to another location than [`TWITCH_EVENTSUB_WEBSOCKET_URL`](crate::TWITCH_EVENTSUB_WEBSOCKET_URL)
+    pub fn with_url(mut self, url: url::Url) -> Self {
+        self.url = url;
+        self
+    }
+
+    /// Create this subscription with [`HelixClient::create_eventsub_subscription`] whenever a new session is established
+    pub fn with_subscription<E>(mut self, subscription: E) -> Self
+    where E: EventSubscription + Send + Sync + 'a {
+        self.subscriptions.push(Box::new(subscription));
+        self
+    }
+
+    /// Wait for the next notification or revocation.
+    ///
+    /// Connects and creates the subscriptions if there is no connection yet.
+    /// If the connection is lost, a new one is opened on the next call.
+    pub async fn next_event(&mut self) -> Result<Event, WebsocketError<C::Error>> {
+        loop {
+            if self.connection.is_none() {
+                self.successor = None;
+                let connection =
+                    Self::open(&self.url, self.helix, self.token, &self.subscriptions).await?;
+                self.connection = Some(connection);
+            }
+            let connection = self
+                .connection
+                .as_mut()
+                .expect("connection should have been opened");
+            let deadline = connection.deadline;
+            let successor = &mut self.successor;
+
+            let (frame, from_successor) = tokio::select! {
+                // drain the current connection first, so nothing is lost when the successor takes over
+                biased;
+                frame = connection.recv::<C::Error>() => (frame, false),
+                frame = async {
+                    match successor {
+                        Some(successor) => successor.recv::<C::Error>().await,
+                        None => std::future::pending().await,
+                    }
+                } => (frame, true),
+                _ = tokio::time::sleep_until(deadline) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!("missed keepalive, opening a new session");
+                    self.connection = None;
+                    continue;
+                }
+            };
+
+            let frame = match frame {
+                Ok(frame) => frame,
+                // the old connection may be closed by twitch before the successor is welcomed
+                Err(_) if !from_successor && self.successor.is_some() => {
+                    self.connection = self.successor.take();
+                    continue;
+                }
+                Err(_error) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(error = %_error, from_successor, "websocket connection lost");
+                    if from_successor {
+                        self.successor = None;
+                    } else {
+                        self.connection = None;
+                    }
+                    continue;
+                }
+            };
+
+            let (current, other) = if from_successor {
+                (&mut self.successor, &mut self.connection)
+            } else {
+                (&mut self.connection, &mut self.successor)
+            };
+            let Some(current) = current.as_mut() else {
+                continue;
+            };
+            current.deadline = Instant::now() + current.keepalive;
+
+            match Event::parse_websocket(&frame)? {
+                EventsubWebsocketData::Notification { payload, .. }
+                | EventsubWebsocketData::Revocation { payload, .. } => return Ok(payload),
+                EventsubWebsocketData::Welcome { payload, .. } => {
+                    current.set_keepalive(payload.session.keepalive_timeout_seconds);
+                    if from_successor {
+                        // the successor took over the session, subscriptions are kept
+                        if let Some(mut old) =
+                            std::mem::replace(&mut self.connection, self.successor.take())
+                        {
+                            let _ = old.socket.close(None).await;
+                        }
+                    }
+                }
+                EventsubWebsocketData::Reconnect { payload, .. } => {
+                    let url = payload
+                        .session
+                        .reconnect_url
+                        .ok_or(WebsocketError::<C::Error>::MissingReconnectUrl)?;
+                    current.deadline = Instant::now() + RECONNECT_TIMEOUT;
+                    let successor = Connection::connect::<C::Error>(&url).await?;
+                    *other = Some(successor);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Get all events as a stream.
+    ///
+    /// Messages that can't be parsed are skipped. The stream ends on errors that can't be recovered from,
+    /// e.g. when connecting fails or a subscription can't be created. Use [`Client::next_event`] to handle these errors yourself.
+    pub fn into_stream(self) -> impl futures::Stream<Item = Event> + Send + Unpin + 'a
+    where T: 'a {
+        futures::stream::unfold(self, |mut client| async move {
+            loop {
+                match client.next_event().await {
+                    Ok(event) => return Some((event, client)),
+                    Err(WebsocketError::Parse(_error)) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(error = %_error, "could not parse eventsub message");
+                    }
+                    Err(_error) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::error!(error = %_error, "eventsub websocket failed");
+                        return None;
+                    }
+                }
+            }
+        })
+        .boxed()
+    }
+
+    /// Open a new session and create all subscriptions on it
+    async fn open(
+        url: &url::Url,
+        helix: &'a HelixClient<'a, C>,
+        token: &'a T,
+        subscriptions: &[Box<dyn Subscribe<C, T> + 'a>],
+    ) -> Result<Connection, WebsocketError<C::Error>> {
+        let mut connection = Connection::connect::<C::Error>(url.as_str()).await?;
+        let frame = tokio::time::timeout(WELCOME_TIMEOUT, connection.recv::<C::Error>())
+            .await
+            .map_err(|_| WebsocketError::<C::Error>::MissingWelcome)??;
+        let EventsubWebsocketData::Welcome { payload, .. } = Event::parse_websocket(&frame)? else {
+            return Err(WebsocketError::MissingWelcome);
+        };
+        connection.set_keepalive(payload.session.keepalive_timeout_seconds);
+
+        let session_id = payload.session.id.as_ref();
+        futures::future::try_join_all(subscriptions.iter().map(|subscription| async move {
+            subscription
+                .subscribe(helix, Transport::websocket(session_id), token)
+                .await
+                .map_err(|source| WebsocketError::Subscribe {
+                    event_type: subscription.event_type(),
+                    source,
+                })
+        }))
+        .await?;
+
+        connection.deadline = Instant::now() + connection.keepalive;
+        Ok(connection)
+    }
+}
+
+/// Errors from the EventSub WebSocket [`Client`]
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[non_exhaustive]
+pub enum WebsocketError<RE: std::error::Error + Send + Sync + 'static> {
+    /// could not connect to {0}
+    Connect(String, #[source] Box<tungstenite::Error>),
+    /// websocket connection failed
+    Connection(#[source] Box<tungstenite::Error>),
+    /// websocket connection was closed
+    Closed,
+    /// twitch did not welcome the connection
+    MissingWelcome,
+    /// twitch asked to reconnect without giving a url to reconnect to
+    MissingReconnectUrl,
+    /// could not parse message
+    Parse(#[from] PayloadParseError),
+    /// could not create subscription for `{event_type}`
+    Subscribe {
+        /// The subscription that failed
+        event_type: EventType,
+        /// The error from helix
+        #[source]
+        source: ClientRequestError<RE>,
+    },
+}
+
+struct Connection {
+    socket: Socket,
+    keepalive: Duration,
+    /// When the connection is considered lost if no message was received
+    deadline: Instant,
+}
+
+impl Connection {
+    async fn connect<RE: std::error::Error + Send + Sync + 'static>(
+        url: &str,
+    ) -> Result<Self, WebsocketError<RE>> {
+        let (socket, _) = tokio_tungstenite::connect_async(url)
+            .await
+            .map_err(|e| WebsocketError::Connect(url.to_owned(), Box::new(e)))?;
+        Ok(Self {
+            socket,
+            keepalive: DEFAULT_KEEPALIVE,
+            deadline: Instant::now() + WELCOME_TIMEOUT,
+        })
+    }
+
+    fn set_keepalive(&mut self, seconds: Option<i64>) {
+        self.keepalive = seconds
+            .and_then(|s| u64::try_from(s).ok())
+            .map_or(DEFAULT_KEEPALIVE, Duration::from_secs);
+    }
+
+    /// Receive the next text frame. Pings are answered by tungstenite.
+    async fn recv<RE: std::error::Error + Send + Sync + 'static>(
+        &mut self,
+    ) -> Result<String, WebsocketError<RE>> {
+        loop {
+            match self.socket.next().await {
+                Some(Ok(tungstenite::Message::Text(text))) => return Ok(text.to_string()),
+                Some(Ok(tungstenite::Message::Close(_))) | None => {
+                    return Err(WebsocketError::Closed)
+                }
+                Some(Ok(_)) => (),
+                Some(Err(e)) => return Err(WebsocketError::Connection(Box::new(e))),
+            }
+        }
+    }
+}
+
+/// Type erased [`EventSubscription`]
+#[async_trait::async_trait]
+trait Subscribe<C: crate::HttpClient, T: TwitchToken + Send + Sync + ?Sized>: Send + Sync {
+    async fn subscribe(
+        &self,
+        helix: &HelixClient<'_, C>,
+        transport: Transport,
+        token: &T,
+    ) -> Result<(), ClientRequestError<C::Error>>;
+
+    fn event_type(&self) -> EventType;
+}
+
+#[async_trait::async_trait]
+impl<C, T, E> Subscribe<C, T> for E
+where
+    C: crate::HttpClient + Sync,
+    T: TwitchToken + Send + Sync + ?Sized,
+    E: EventSubscription + Send + Sync,
+{
+    async fn subscribe(
+        &self,
+        helix: &HelixClient<'_, C>,
+        transport: Transport,
+        token: &T,
+    ) -> Result<(), ClientRequestError<C::Error>> {
+        helix
+            .create_eventsub_subscription(self.clone(), transport, token)
+            .await
+            .map(|_| ())
+    }
+
+    fn event_type(&self) -> EventType { E::EVENT_TYPE }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::token;
+    use futures::SinkExt;
+    use tokio::net::TcpListener;
+    use tungstenite::Message as WsMessage;
+
+    fn welcome(keepalive: u64) -> WsMessage {
+        WsMessage::text(format!(
+            r#"{{"metadata":{{"message_id":"96a3f3b5-5dec-4eed-908e-e11ee657416c","message_type":"session_welcome","message_timestamp":"2022-10-19T14:56:51.634234626Z"}},"payload":{{"session":{{"id":"AQoQILE98gtqShGmLD7AM6yJThAB","status":"connected","connected_at":"2022-10-19T14:56:51.616329898Z","keepalive_timeout_seconds":{keepalive},"reconnect_url":null,"recovery_url":null}}}}}}"#
+        ))
+    }
+
+    fn reconnect(url: &str) -> WsMessage {
+        WsMessage::text(format!(
+            r#"{{"metadata":{{"message_id":"84c1e79a-2a4b-4c13-ba0b-4312293e9308","message_type":"session_reconnect","message_timestamp":"2019-11-18T09:10:11.234Z"}},"payload":{{"session":{{"id":"AQoQILE98gtqShGmLD7AM6yJThAB","status":"reconnecting","keepalive_timeout_seconds":null,"reconnect_url":"{url}","connected_at":"2019-11-16T10:11:12.123Z"}}}}}}"#
+        ))
+    }
+
+    fn follow(user_login: &str) -> WsMessage {
+        WsMessage::text(format!(
+            r#"{{"metadata":{{"message_id":"befa7b53-d79d-478f-86b9-120f112b044e","message_type":"notification","message_timestamp":"2019-11-16T10:11:12.123Z","subscription_type":"channel.follow","subscription_version":"2"}},"payload":{{"subscription":{{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","status":"enabled","type":"channel.follow","version":"2","cost":0,"condition":{{"broadcaster_user_id":"12826","moderator_user_id":"12826"}},"transport":{{"method":"websocket","session_id":"AQoQILE98gtqShGmLD7AM6yJThAB"}},"created_at":"2019-11-16T10:11:12.123Z"}},"event":{{"user_id":"1337","user_login":"{user_login}","user_name":"Awesome_User","broadcaster_user_id":"12826","broadcaster_user_login":"twitch","broadcaster_user_name":"Twitch","followed_at":"2020-07-15T18:16:11.17106713Z"}}}}}}"#
+        ))
+    }
+
+    fn follower(event: Event) -> String {
+        let Event::ChannelFollowV2(super::super::Payload {
+            message: super::super::Message::Notification(notification),
+            ..
+        }) = event
+        else {
+            panic!("unexpected event: {:?}", event);
+        };
+        notification.user_login.to_string()
+    }
+
+    async fn listen() -> (TcpListener, url::Url) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        (listener, url)
+    }
+
+    async fn accept(listener: &TcpListener) -> Socket {
+        let (stream, _) = listener.accept().await.unwrap();
+        tokio_tungstenite::accept_async(tokio_tungstenite::MaybeTlsStream::Plain(stream))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn reconnect_before_closing() {
+        let (listener, url) = listen().await;
+        let (successor_listener, successor_url) = listen().await;
+
+        let server = tokio::spawn(async move {
+            let mut old = accept(&listener).await;
+            old.send(welcome(10)).await.unwrap();
+            old.send(follow("first")).await.unwrap();
+            old.send(reconnect(successor_url.as_str())).await.unwrap();
+
+            let mut new = accept(&successor_listener).await;
+            // events are still delivered on the old connection until the new one is welcomed
+            old.send(follow("second")).await.unwrap();
+            new.send(welcome(10)).await.unwrap();
+            // the client closes the old connection
+            while let Some(Ok(message)) = old.next().await {
+                if message.is_close() {
+                    break;
+                }
+            }
+            new.send(follow("third")).await.unwrap();
+            new
+        });
+
+        let helix: HelixClient<'static, crate::DummyHttpClient> = HelixClient::default();
+        let token = token();
+        let mut client = Client::new(&helix, &token).with_url(url);
+        assert_eq!(follower(client.next_event().await.unwrap()), "first");
+        assert_eq!(follower(client.next_event().await.unwrap()), "second");
+        assert_eq!(follower(client.next_event().await.unwrap()), "third");
+        drop(server.await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn missed_keepalive() {
+        let (listener, url) = listen().await;
+
+        let server = tokio::spawn(async move {
+            let mut silent = accept(&listener).await;
+            silent.send(welcome(1)).await.unwrap();
+
+            let mut new = accept(&listener).await;
+            new.send(welcome(10)).await.unwrap();
+            new.send(follow("after_timeout")).await.unwrap();
+            (silent, new)
+        });
+
+        let helix: HelixClient<'static, crate::DummyHttpClient> = HelixClient::default();
+        let token = token();
+        let mut client = Client::new(&helix, &token).with_url(url);
+        assert_eq!(
+            follower(client.next_event().await.unwrap()),
+            "after_timeout"
+        );
+        drop(server.await.unwrap());
+    }
+}
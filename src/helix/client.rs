@@ -1,14 +1,27 @@
 //! Client for Helix endpoints
-use crate::client::ResponseExt;
-
 use super::*;
 
+pub mod batch;
+pub mod cache;
 pub(crate) mod client_ext;
 #[cfg(feature = "unsupported")]
 mod custom;
+pub mod ratelimit;
+pub mod refresh;
+pub mod retry;
 
+#[doc(inline)]
+pub use batch::BatchLoader;
+#[doc(inline)]
+pub use cache::ResponseCache;
 #[doc(inline)]
 pub use client_ext::ClientExtError;
+#[doc(inline)]
+pub use ratelimit::RateLimiter;
+#[doc(inline)]
+pub use refresh::TokenRefresher;
+#[doc(inline)]
+pub use retry::RetryPolicy;
 
 #[cfg(feature = "client")]
 impl<C: crate::HttpClient + crate::client::ClientDefault<'static>> Default
@@ -71,7 +84,14 @@ impl<C: crate::HttpClient + crate::client::ClientDefault<'static>> Default
 #[cfg(feature = "helix")] // this is needed due to a bug?
 pub struct HelixClient<'a, C: 'a> {
     pub(crate) client: C,
-    pub(crate) _pd: std::marker::PhantomData<&'a ()>, // TODO: Implement rate limiter...
+    pub(crate) ratelimiter: Option<std::sync::Arc<RateLimiter>>,
+    pub(crate) retry_policy: Option<std::sync::Arc<RetryPolicy>>,
+    pub(crate) token_refresher: Option<std::sync::Arc<dyn refresh::Refresh<C> + 'a>>,
+    pub(crate) scope_check: bool,
+    pub(crate) cache: Option<std::sync::Arc<ResponseCache>>,
+    pub(crate) batch_loader: Option<std::sync::Arc<BatchLoader>>,
+    pub(crate) base_url: Option<std::sync::Arc<url::Url>>,
+    pub(crate) _pd: std::marker::PhantomData<&'a ()>,
 }
 
 #[cfg(feature = "helix")]
@@ -81,6 +101,13 @@ where C: crate::HttpClient + Clone + 'a
     fn clone(&self) -> Self {
         Self {
             client: self.client.clone(),
+            ratelimiter: self.ratelimiter.clone(),
+            retry_policy: self.retry_policy.clone(),
+            token_refresher: self.token_refresher.clone(),
+            scope_check: self.scope_check,
+            cache: self.cache.clone(),
+            batch_loader: self.batch_loader.clone(),
+            base_url: self.base_url.clone(),
             _pd: self._pd,
         }
     }
@@ -91,10 +118,187 @@ impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
     pub const fn with_client(client: C) -> Self {
         HelixClient {
             client,
+            ratelimiter: None,
+            retry_policy: None,
+            token_refresher: None,
+            scope_check: false,
+            cache: None,
+            batch_loader: None,
+            base_url: None,
             _pd: std::marker::PhantomData,
         }
     }
 
+    /// Use a [`RateLimiter`] to delay requests when the rate limit of a token has been exhausted.
+    ///
+    /// The rate limiter is shared between all clones of this client.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::{HelixClient, RateLimiter};
+    /// # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+    /// let client: HelixClient<reqwest::Client> =
+    ///     HelixClient::new().with_rate_limiter(RateLimiter::new());
+    /// ```
+    pub fn with_rate_limiter(
+        mut self,
+        ratelimiter: impl Into<std::sync::Arc<RateLimiter>>,
+    ) -> Self {
+        self.ratelimiter = Some(ratelimiter.into());
+        self
+    }
+
+    /// Retrieve the [`RateLimiter`] used by this client, if any.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> { self.ratelimiter.as_deref() }
+
+    /// Use a [`RetryPolicy`] to retry requests that failed with a transient error.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::{HelixClient, RetryPolicy};
+    /// # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+    /// let client: HelixClient<reqwest::Client> =
+    ///     HelixClient::new().with_retry_policy(RetryPolicy::new().max_attempts(5));
+    /// ```
+    pub fn with_retry_policy(
+        mut self,
+        retry_policy: impl Into<std::sync::Arc<RetryPolicy>>,
+    ) -> Self {
+        self.retry_policy = Some(retry_policy.into());
+        self
+    }
+
+    /// Retrieve the [`RetryPolicy`] used by this client, if any.
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> { self.retry_policy.as_deref() }
+
+    /// Use a [`TokenRefresher`] to refresh its token and retry requests that fail with `401 Unauthorized` because the token expired.
+    ///
+    /// Only requests made with the current token of the refresher are retried, see [`TokenRefresher::token`].
+    ///
+    /// ```rust
+    /// use twitch_api::helix::{HelixClient, TokenRefresher};
+    /// # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+    /// # let token = twitch_oauth2::UserToken::from_existing_unchecked(
+    /// #     twitch_oauth2::AccessToken::new("totallyvalidtoken".to_string()), None,
+    /// #     twitch_oauth2::ClientId::new("validclientid".to_string()), None, "justintv".into(), "1337".into(), None, None);
+    /// let refresher = std::sync::Arc::new(TokenRefresher::new(token));
+    /// let client: HelixClient<reqwest::Client> =
+    ///     HelixClient::new().with_token_refresher(refresher.clone());
+    /// ```
+    pub fn with_token_refresher<T>(
+        mut self,
+        token_refresher: impl Into<std::sync::Arc<TokenRefresher<T>>>,
+    ) -> Self
+    where
+        C: Sync + 'static,
+        T: TwitchToken + Clone + Send + Sync + 'static,
+    {
+        let token_refresher: std::sync::Arc<TokenRefresher<T>> = token_refresher.into();
+        self.token_refresher = Some(token_refresher);
+        self
+    }
+
+    /// Use a [`ResponseCache`] to serve responses of `GET` requests to endpoints that rarely change without sending a request.
+    ///
+    /// The cache is shared between all clones of this client.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::{HelixClient, ResponseCache};
+    /// # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+    /// let client: HelixClient<reqwest::Client> =
+    ///     HelixClient::new().with_cache(ResponseCache::with_defaults());
+    /// ```
+    pub fn with_cache(mut self, cache: impl Into<std::sync::Arc<ResponseCache>>) -> Self {
+        self.cache = Some(cache.into());
+        self
+    }
+
+    /// Retrieve the [`ResponseCache`] used by this client, if any.
+    pub fn cache(&self) -> Option<&ResponseCache> { self.cache.as_deref() }
+
+    /// Use a [`BatchLoader`] to merge concurrent lookups by id, like [`get_user_from_id`](HelixClient::get_user_from_id), into fewer requests.
+    ///
+    /// The loader is shared between all clones of this client.
+    ///
+    /// ```rust
+    /// use twitch_api::helix::{BatchLoader, HelixClient};
+    /// # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+    /// let client: HelixClient<reqwest::Client> =
+    ///     HelixClient::new().with_batch_loader(BatchLoader::new());
+    /// ```
+    pub fn with_batch_loader(
+        mut self,
+        batch_loader: impl Into<std::sync::Arc<BatchLoader>>,
+    ) -> Self {
+        self.batch_loader = Some(batch_loader.into());
+        self
+    }
+
+    /// Retrieve the [`BatchLoader`] used by this client, if any.
+    pub fn batch_loader(&self) -> Option<&BatchLoader> { self.batch_loader.as_deref() }
+
+    /// Check that the token has the [scopes](Request::SCOPE) needed for a request before sending it.
+    ///
+    /// If the token is missing scopes, the request fails with [`ClientRequestError::MissingScopeError`] without being sent.
+    /// Only enable this if the scopes of your tokens are known, e.g. when the token has been validated.
+    ///
+    /// ```rust
+    /// use twitch_api::HelixClient;
+    /// # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+    /// let client: HelixClient<reqwest::Client> = HelixClient::new().with_scope_check(true);
+    /// ```
+    pub fn with_scope_check(mut self, scope_check: bool) -> Self {
+        self.scope_check = scope_check;
+        self
+    }
+
+    /// Fail with [`MissingScopeError`] if [scope checking](Self::with_scope_check) is enabled and `token` does not satisfy [`R::SCOPE`](Request::SCOPE).
+    fn check_scope<R, T>(&self, token: &T) -> Result<(), MissingScopeError>
+    where
+        R: Request,
+        T: TwitchToken + ?Sized, {
+        if self.scope_check && !R::SCOPE.matches(token.scopes()) {
+            return Err(MissingScopeError {
+                path: R::PATH,
+                missing: crate::common::scopes::missing_scopes(&R::SCOPE, token.scopes()),
+            });
+        }
+        Ok(())
+    }
+
+    /// Send requests to another location than [`TWITCH_HELIX_URL`](crate::TWITCH_HELIX_URL).
+    ///
+    /// This is useful for sending requests through a proxy, or to a [mock server](https://github.com/twitchdev/twitch-cli/blob/main/docs/mock-api.md).
+    /// The url should end with a `/`.
+    ///
+    /// ```rust
+    /// use twitch_api::HelixClient;
+    /// # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+    /// let client: HelixClient<reqwest::Client> = HelixClient::new()
+    ///     .with_base_url(url::Url::parse("http://localhost:8080/mock/").unwrap());
+    /// ```
+    pub fn with_base_url(mut self, base_url: url::Url) -> Self {
+        self.base_url = Some(std::sync::Arc::new(base_url));
+        self
+    }
+
+    /// Retrieve the location requests are sent to.
+    ///
+    /// Defaults to [`TWITCH_HELIX_URL`](crate::TWITCH_HELIX_URL)
+    pub fn base_url(&self) -> &url::Url {
+        self.base_url.as_deref().unwrap_or(&crate::TWITCH_HELIX_URL)
+    }
+
+    /// Point a request created with [`TWITCH_HELIX_URL`](crate::TWITCH_HELIX_URL) to the [base url](Self::base_url) of this client.
+    pub(crate) fn rebase<R: Request>(
+        &self,
+        request: &R,
+        req: &mut http::Request<hyper::body::Bytes>,
+    ) -> Result<(), CreateRequestError> {
+        if let Some(base_url) = &self.base_url {
+            *req.uri_mut() = request.get_uri_with_base(base_url)?;
+        }
+        Ok(())
+    }
+
     /// Create a new [`HelixClient`] with a default [`HttpClient`][crate::HttpClient]
     pub fn new() -> Self
     where C: crate::client::ClientDefault<'a> {
@@ -140,15 +344,14 @@ impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
         T: TwitchToken + ?Sized,
         C: Send,
     {
-        let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        self.check_scope::<R, T>(token)?;
+        let mut req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        self.rebase(&request, &mut req)?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?
-            .into_response_vec();
-        <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
+        let (response, attempts) = self.send(req, R::PATH, token).await?;
+        let mut response = <R>::parse_response(Some(request), &uri, response)?;
+        response.attempts = attempts;
+        Ok(response)
     }
 
     /// Request on a valid [`RequestPost`] endpoint
@@ -164,16 +367,15 @@ impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
         D: serde::de::DeserializeOwned + PartialEq,
         T: TwitchToken + ?Sized,
     {
-        let req =
+        self.check_scope::<R, T>(token)?;
+        let mut req =
             request.create_request(body, token.token().secret(), token.client_id().as_str())?;
+        self.rebase(&request, &mut req)?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?
-            .into_response_vec();
-        <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
+        let (response, attempts) = self.send(req, R::PATH, token).await?;
+        let mut response = <R>::parse_response(Some(request), &uri, response)?;
+        response.attempts = attempts;
+        Ok(response)
     }
 
     /// Request on a valid [`RequestPatch`] endpoint
@@ -189,16 +391,15 @@ impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
         D: serde::de::DeserializeOwned + PartialEq,
         T: TwitchToken + ?Sized,
     {
-        let req =
+        self.check_scope::<R, T>(token)?;
+        let mut req =
             request.create_request(body, token.token().secret(), token.client_id().as_str())?;
+        self.rebase(&request, &mut req)?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?
-            .into_response_vec();
-        <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
+        let (response, attempts) = self.send(req, R::PATH, token).await?;
+        let mut response = <R>::parse_response(Some(request), &uri, response)?;
+        response.attempts = attempts;
+        Ok(response)
     }
 
     /// Request on a valid [`RequestDelete`] endpoint
@@ -212,15 +413,14 @@ impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
         D: serde::de::DeserializeOwned + PartialEq,
         T: TwitchToken + ?Sized,
     {
-        let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        self.check_scope::<R, T>(token)?;
+        let mut req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        self.rebase(&request, &mut req)?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?
-            .into_response_vec();
-        <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
+        let (response, attempts) = self.send(req, R::PATH, token).await?;
+        let mut response = <R>::parse_response(Some(request), &uri, response)?;
+        response.attempts = attempts;
+        Ok(response)
     }
 
     /// Request on a valid [`RequestPut`] endpoint
@@ -236,15 +436,112 @@ impl<'a, C: crate::HttpClient + 'a> HelixClient<'a, C> {
         D: serde::de::DeserializeOwned + PartialEq,
         T: TwitchToken + ?Sized,
     {
-        let req =
+        self.check_scope::<R, T>(token)?;
+        let mut req =
             request.create_request(body, token.token().secret(), token.client_id().as_str())?;
+        self.rebase(&request, &mut req)?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?
-            .into_response_vec();
-        <R>::parse_response(Some(request), &uri, response).map_err(Into::into)
+        let (response, attempts) = self.send(req, R::PATH, token).await?;
+        let mut response = <R>::parse_response(Some(request), &uri, response)?;
+        response.attempts = attempts;
+        Ok(response)
+    }
+
+    /// Send a request with the [`HttpClient`][crate::HttpClient], respecting the [`ResponseCache`], [`RateLimiter`] and [`RetryPolicy`] if set.
+    ///
+    /// `path` is the [path](Request::PATH) of the endpoint. Returns the response and the amount of attempts made, `0` if the response was cached.
+    pub(crate) async fn send<T>(
+        &self,
+        mut req: http::Request<hyper::body::Bytes>,
+        path: &str,
+        token: &T,
+    ) -> Result<(crate::client::Response, usize), ClientRequestError<<C as crate::HttpClient>::Error>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let cache = self
+            .cache
+            .as_deref()
+            .filter(|_| req.method() == http::Method::GET)
+            .and_then(|cache| Some((cache, cache.ttl(path)?)));
+        let Some((cache, ttl)) = cache else {
+            return self.send_uncached(req, path, token).await;
+        };
+        let key = cache::key(&req, token);
+        let stale = match cache.lookup(&key, &mut req).await {
+            Ok(response) => return Ok((response, 0)),
+            Err(stale) => stale,
+        };
+        let (response, attempts) = self.send_uncached(req, path, token).await?;
+        Ok((cache.store(&key, ttl, response, stale).await, attempts))
+    }
+
+    /// Send a request with the [`HttpClient`][crate::HttpClient], respecting the [`RateLimiter`] and [`RetryPolicy`] if set.
+    async fn send_uncached<T>(
+        &self,
+        req: http::Request<hyper::body::Bytes>,
+        path: &str,
+        token: &T,
+    ) -> Result<(crate::client::Response, usize), ClientRequestError<<C as crate::HttpClient>::Error>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let bucket = self
+            .ratelimiter
+            .as_deref()
+            .map(|limiter| (limiter, ratelimit::BucketKey::from_token(token)));
+        let retry_policy = self
+            .retry_policy
+            .as_deref()
+            .filter(|policy| policy.is_retryable_request(req.method(), path));
+        let (mut parts, body) = req.into_parts();
+        let mut attempts = 0;
+        let mut refreshed = false;
+        loop {
+            attempts += 1;
+            if let Some((limiter, key)) = &bucket {
+                limiter.acquire(key).await;
+            }
+            let result = self
+                .client
+                .req(http::Request::from_parts(parts.clone(), body.clone()))
+                .await;
+            if let (Ok(response), Some((limiter, key))) = (&result, &bucket) {
+                limiter.update(key, response.headers());
+            }
+            if let Some(delay) = retry_policy.and_then(|p| p.retry_after(attempts, &result)) {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(attempts, ?delay, "retrying request");
+                futures_timer::Delay::new(delay).await;
+                continue;
+            }
+            if let (Ok(response), Some(refresher), false) =
+                (&result, &self.token_refresher, refreshed)
+            {
+                if refresh::is_refreshable(response) {
+                    let stale = token.token().clone();
+                    if let Some(new) = refresher
+                        .refresh_stale(self, &stale)
+                        .await
+                        .map_err(ClientRequestError::TokenRefreshError)?
+                    {
+                        let mut bearer =
+                            http::HeaderValue::from_str(&format!("Bearer {}", new.secret()))
+                                .map_err(|_| {
+                                    CreateRequestError::Custom(
+                                        "Could not make token into headervalue".into(),
+                                    )
+                                })?;
+                        bearer.set_sensitive(true);
+                        parts.headers.insert(http::header::AUTHORIZATION, bearer);
+                        refreshed = true;
+                        continue;
+                    }
+                }
+            }
+            return result
+                .map(|response| (response, attempts))
+                .map_err(ClientRequestError::RequestError);
+        }
     }
 }
@@ -0,0 +1,505 @@
+//! Batching of lookups by id for [`HelixClient`]
+//!
+//! Endpoints like [Get Users](crate::helix::users::GetUsersRequest) accept up to 100 ids in one request,
+//! but code handling many users concurrently usually looks them up one at a time.
+//! A [`BatchLoader`] collects the ids looked up within a short [window](BatchLoader::window),
+//! sends them together and hands every caller its own result.
+//!
+//! Enable it with [`HelixClient::with_batch_loader`]. The lookups by id of the client,
+//! [`get_user_from_id`](HelixClient::get_user_from_id), [`get_users_from_ids`](HelixClient::get_users_from_ids),
+//! [`get_channel_from_id`](HelixClient::get_channel_from_id), [`get_channels_from_ids`](HelixClient::get_channels_from_ids)
+//! and [`get_games_by_id`](HelixClient::get_games_by_id), then go through the loader.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+//! use twitch_api::helix::{BatchLoader, HelixClient};
+//! let client: HelixClient<reqwest::Client> =
+//!     HelixClient::new().with_batch_loader(BatchLoader::new());
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//!
+//! // Sent as one request
+//! let (justintv, twitch) = futures::try_join!(
+//!     client.get_user_from_id("1337", &token),
+//!     client.get_user_from_id("12826", &token),
+//! )?;
+//! # Ok(()) }
+//! ```
+use std::{
+    collections::HashMap,
+    future::Future,
+    hash::Hash,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use futures::channel::oneshot;
+use twitch_oauth2::TwitchToken;
+
+use super::ratelimit::BucketKey;
+use crate::{
+    helix::{self, ClientRequestError, HelixClient},
+    types,
+};
+
+type ClientError<C> = ClientRequestError<<C as crate::HttpClient>::Error>;
+type BatchError = Arc<dyn std::error::Error + Send + Sync>;
+type Reply<I> = oneshot::Sender<Result<Option<I>, BatchError>>;
+
+/// Maximum amount of lookups sent in one request
+const MAX_IDS: usize = 100;
+
+/// Merges concurrent lookups by id into as few requests as possible.
+///
+/// A batch is sent when the [window](BatchLoader::window) after its first lookup has passed, with the ids looked up in the meantime.
+/// A batch is sent early when it reaches 100 lookups.
+/// Every lookup in a batch can send it, so a batch is still sent if the lookup that started it is dropped.
+///
+/// Only lookups made with tokens of the same client and user are merged, so every batch is sent with a token its callers supplied.
+///
+/// If a request fails, every lookup in it fails with [`ClientRequestError::BatchedRequestError`].
+///
+/// See the [module level documentation](self)
+pub struct BatchLoader {
+    window: Duration,
+    users: Mutex<Queue<types::UserId, helix::users::User>>,
+    channels: Mutex<Queue<types::UserId, helix::channels::ChannelInformation>>,
+    games: Mutex<Queue<types::CategoryId, helix::games::Game>>,
+}
+
+/// Lookups waiting to be sent, batched per token
+struct Queue<Id, I> {
+    /// Id of the last batch that was started
+    last_batch: u64,
+    batches: HashMap<BucketKey, Batch<Id, I>>,
+}
+
+struct Batch<Id, I> {
+    id: u64,
+    /// When the lookups should be sent
+    deadline: Instant,
+    lookups: Vec<(Id, Reply<I>)>,
+}
+
+impl<Id, I> Default for Queue<Id, I> {
+    fn default() -> Self {
+        Self {
+            last_batch: 0,
+            batches: HashMap::new(),
+        }
+    }
+}
+
+impl<Id, I> Queue<Id, I> {
+    /// Add a lookup made with the token identified by `key`, returning the batch it's part of, when that batch should be sent and if it's full.
+    fn push(
+        &mut self,
+        key: &BucketKey,
+        id: Id,
+        reply: Reply<I>,
+        window: Duration,
+    ) -> (u64, Instant, bool) {
+        let last_batch = &mut self.last_batch;
+        let batch = self.batches.entry(key.clone()).or_insert_with(|| {
+            *last_batch += 1;
+            Batch {
+                id: *last_batch,
+                deadline: Instant::now() + window,
+                lookups: vec![],
+            }
+        });
+        batch.lookups.push((id, reply));
+        (batch.id, batch.deadline, batch.lookups.len() >= MAX_IDS)
+    }
+
+    /// Take the lookups of `batch`, unless it has already been taken
+    fn take(&mut self, key: &BucketKey, batch: u64) -> Option<Vec<(Id, Reply<I>)>> {
+        if self.batches.get(key)?.id != batch {
+            return None;
+        }
+        self.batches.remove(key).map(|batch| batch.lookups)
+    }
+}
+
+impl std::fmt::Debug for BatchLoader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchLoader")
+            .field("window", &self.window)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for BatchLoader {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_millis(10),
+            users: Mutex::default(),
+            channels: Mutex::default(),
+            games: Mutex::default(),
+        }
+    }
+}
+
+impl BatchLoader {
+    /// Create a new loader
+    pub fn new() -> Self { Self::default() }
+
+    /// Set how long to wait for more lookups before sending a batch. Defaults to 10 milliseconds.
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Get [User](helix::users::User) from user id
+    pub(crate) async fn get_user_from_id<'c, C, T>(
+        &self,
+        client: &'c HelixClient<'c, C>,
+        id: impl Into<&types::UserIdRef> + Send,
+        token: &T,
+    ) -> Result<Option<helix::users::User>, ClientError<C>>
+    where
+        C: crate::HttpClient + Send + Sync + 'c,
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let ids = vec![id.into().to_owned()];
+        let mut users = self
+            .load(
+                &self.users,
+                &BucketKey::from_token(token),
+                ids,
+                |user| &user.id,
+                |ids| fetch_users(client, token, ids),
+            )
+            .await?;
+        Ok(users.pop().flatten())
+    }
+
+    /// Get multiple [User](helix::users::User)s from user ids, skipping ids that don't belong to a user.
+    pub(crate) async fn get_users_from_ids<'c, C, T>(
+        &self,
+        client: &'c HelixClient<'c, C>,
+        ids: &types::Collection<'_, types::UserId>,
+        token: &T,
+    ) -> Result<Vec<helix::users::User>, ClientError<C>>
+    where
+        C: crate::HttpClient + Send + Sync + 'c,
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let ids = ids.iter().map(ToOwned::to_owned).collect();
+        let users = self
+            .load(
+                &self.users,
+                &BucketKey::from_token(token),
+                ids,
+                |user| &user.id,
+                |ids| fetch_users(client, token, ids),
+            )
+            .await?;
+        Ok(users.into_iter().flatten().collect())
+    }
+
+    /// Get [ChannelInformation](helix::channels::ChannelInformation) from a broadcasters id
+    pub(crate) async fn get_channel_from_id<'c, C, T>(
+        &self,
+        client: &'c HelixClient<'c, C>,
+        id: impl Into<&types::UserIdRef> + Send,
+        token: &T,
+    ) -> Result<Option<helix::channels::ChannelInformation>, ClientError<C>>
+    where
+        C: crate::HttpClient + Send + Sync + 'c,
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let ids = vec![id.into().to_owned()];
+        let mut channels = self
+            .load(
+                &self.channels,
+                &BucketKey::from_token(token),
+                ids,
+                |channel| &channel.broadcaster_id,
+                |ids| fetch_channels(client, token, ids),
+            )
+            .await?;
+        Ok(channels.pop().flatten())
+    }
+
+    /// Get multiple [ChannelInformation](helix::channels::ChannelInformation) from broadcasters ids, skipping ids that don't belong to a channel.
+    pub(crate) async fn get_channels_from_ids<'c, C, T>(
+        &self,
+        client: &'c HelixClient<'c, C>,
+        ids: &types::Collection<'_, types::UserId>,
+        token: &T,
+    ) -> Result<Vec<helix::channels::ChannelInformation>, ClientError<C>>
+    where
+        C: crate::HttpClient + Send + Sync + 'c,
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let ids = ids.iter().map(ToOwned::to_owned).collect();
+        let channels = self
+            .load(
+                &self.channels,
+                &BucketKey::from_token(token),
+                ids,
+                |channel| &channel.broadcaster_id,
+                |ids| fetch_channels(client, token, ids),
+            )
+            .await?;
+        Ok(channels.into_iter().flatten().collect())
+    }
+
+    /// Get multiple [Game](helix::games::Game)s by id, skipping ids that don't belong to a game.
+    pub(crate) async fn get_games_by_id<'c, C, T>(
+        &self,
+        client: &'c HelixClient<'c, C>,
+        ids: &types::Collection<'_, types::CategoryId>,
+        token: &T,
+    ) -> Result<Vec<helix::games::Game>, ClientError<C>>
+    where
+        C: crate::HttpClient + Send + Sync + 'c,
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let ids = ids.iter().map(ToOwned::to_owned).collect();
+        let games = self
+            .load(
+                &self.games,
+                &BucketKey::from_token(token),
+                ids,
+                |game| &game.id,
+                |ids| fetch_games(client, token, ids),
+            )
+            .await?;
+        Ok(games.into_iter().flatten().collect())
+    }
+
+    /// Look up `ids` in the next batches of `queue` for the token identified by `token`, returning the item for every id in order.
+    async fn load<Id, I, E, Fut>(
+        &self,
+        queue: &Mutex<Queue<Id, I>>,
+        token: &BucketKey,
+        ids: Vec<Id>,
+        key: fn(&I) -> &Id,
+        fetch: impl Fn(Vec<Id>) -> Fut,
+    ) -> Result<Vec<Option<I>>, ClientRequestError<E>>
+    where
+        Id: Clone + Eq + Hash,
+        I: Clone,
+        E: std::error::Error + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<I>, ClientRequestError<E>>>,
+    {
+        let mut results: Vec<Option<Option<I>>> = vec![None; ids.len()];
+        loop {
+            let mut receivers = vec![];
+            let mut full = vec![];
+            let mut joined = None;
+            {
+                let mut queue = queue.lock().expect("poisoned");
+                for (i, id) in ids.iter().enumerate() {
+                    if results[i].is_some() {
+                        continue;
+                    }
+                    let (tx, rx) = oneshot::channel();
+                    let (batch, deadline, is_full) = queue.push(token, id.clone(), tx, self.window);
+                    receivers.push((i, rx));
+                    if is_full {
+                        full.extend(queue.take(token, batch));
+                        joined = None;
+                    } else {
+                        joined = Some((batch, deadline));
+                    }
+                }
+            }
+            for pending in full {
+                flush(pending, key, &fetch).await;
+            }
+            if let Some((batch, deadline)) = joined {
+                // Every lookup waits for the batch it joined and sends it if no one else has.
+                futures_timer::Delay::new(deadline.saturating_duration_since(Instant::now())).await;
+                let pending = queue.lock().expect("poisoned").take(token, batch);
+                if let Some(pending) = pending {
+                    flush(pending, key, &fetch).await;
+                }
+            }
+            for (i, rx) in receivers {
+                match rx.await {
+                    Ok(Ok(item)) => results[i] = Some(item),
+                    Ok(Err(error)) => return Err(ClientRequestError::BatchedRequestError(error)),
+                    // The lookup sending the batch was dropped, look up the id again
+                    Err(oneshot::Canceled) => {}
+                }
+            }
+            if results.iter().all(Option::is_some) {
+                return Ok(results.into_iter().flatten().collect());
+            }
+        }
+    }
+}
+
+/// Send one request for the ids in `pending` and reply to every lookup
+async fn flush<Id, I, E, Fut>(
+    pending: Vec<(Id, Reply<I>)>,
+    key: fn(&I) -> &Id,
+    fetch: &impl Fn(Vec<Id>) -> Fut,
+) where
+    Id: Clone + Eq + Hash,
+    I: Clone,
+    E: std::error::Error + Send + Sync + 'static,
+    Fut: Future<Output = Result<Vec<I>, ClientRequestError<E>>>,
+{
+    let mut ids: Vec<Id> = Vec::with_capacity(pending.len());
+    for (id, _) in &pending {
+        if !ids.contains(id) {
+            ids.push(id.clone());
+        }
+    }
+    #[cfg(feature = "tracing")]
+    tracing::trace!(ids = ids.len(), lookups = pending.len(), "sending batch");
+    match fetch(ids).await {
+        Ok(items) => {
+            let items: HashMap<&Id, &I> = items.iter().map(|item| (key(item), item)).collect();
+            for (id, reply) in pending {
+                let _ = reply.send(Ok(items.get(&id).map(|item| (*item).clone())));
+            }
+        }
+        Err(error) => {
+            let error: BatchError = Arc::new(error);
+            for (_, reply) in pending {
+                let _ = reply.send(Err(error.clone()));
+            }
+        }
+    }
+}
+
+async fn fetch_users<'c, C, T>(
+    client: &'c HelixClient<'c, C>,
+    token: &T,
+    ids: Vec<types::UserId>,
+) -> Result<Vec<helix::users::User>, ClientError<C>>
+where
+    C: crate::HttpClient + Send + Sync + 'c,
+    T: TwitchToken + Send + Sync + ?Sized,
+{
+    let ids: Vec<&types::UserIdRef> = ids.iter().map(|id| &**id).collect();
+    let req = helix::users::GetUsersRequest::ids(&ids[..]);
+    Ok(client.req_get(req, token).await?.data)
+}
+
+async fn fetch_channels<'c, C, T>(
+    client: &'c HelixClient<'c, C>,
+    token: &T,
+    ids: Vec<types::UserId>,
+) -> Result<Vec<helix::channels::ChannelInformation>, ClientError<C>>
+where
+    C: crate::HttpClient + Send + Sync + 'c,
+    T: TwitchToken + Send + Sync + ?Sized,
+{
+    let ids: Vec<&types::UserIdRef> = ids.iter().map(|id| &**id).collect();
+    let req = helix::channels::GetChannelInformationRequest::broadcaster_ids(&ids[..]);
+    Ok(client.req_get(req, token).await?.data)
+}
+
+async fn fetch_games<'c, C, T>(
+    client: &'c HelixClient<'c, C>,
+    token: &T,
+    ids: Vec<types::CategoryId>,
+) -> Result<Vec<helix::games::Game>, ClientError<C>>
+where
+    C: crate::HttpClient + Send + Sync + 'c,
+    T: TwitchToken + Send + Sync + ?Sized,
+{
+    let ids: Vec<&types::CategoryIdRef> = ids.iter().map(|id| &**id).collect();
+    let req = helix::games::GetGamesRequest::ids(&ids[..]);
+    Ok(client.req_get(req, token).await?.data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::MockClient;
+
+    const USERS: &str = r#"{"data":[{"id":"1337","login":"justintv","display_name":"justintv","type":"","broadcaster_type":"","description":"","profile_image_url":"","offline_image_url":"","view_count":0,"created_at":"2007-05-22T10:37:47Z"},{"id":"12826","login":"twitch","display_name":"Twitch","type":"","broadcaster_type":"partner","description":"","profile_image_url":"","offline_image_url":"","view_count":0,"created_at":"2007-05-22T10:39:54Z"}]}"#;
+
+    #[tokio::test]
+    async fn merges_lookups() {
+        use futures::TryStreamExt;
+
+        let client = MockClient::helix([(200, USERS)]).with_batch_loader(BatchLoader::new());
+        let token = crate::tests::token();
+        let ids: types::Collection<types::UserId> = ["1337", "0"][..].into();
+        let (justintv, twitch, unknown) = futures::try_join!(
+            client.get_user_from_id("1337", &token),
+            client.get_user_from_id("12826", &token),
+            client
+                .get_users_from_ids(&ids, &token)
+                .try_collect::<Vec<_>>(),
+        )
+        .unwrap();
+        assert_eq!(justintv.unwrap().login.as_str(), "justintv");
+        assert_eq!(twitch.unwrap().login.as_str(), "twitch");
+        assert_eq!(unknown.len(), 1);
+        assert_eq!(client.get_client().requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn shares_errors() {
+        let client = MockClient::helix([(
+            400,
+            r#"{"error":"Bad Request","status":400,"message":"Invalid user id"}"#,
+        )])
+        .with_batch_loader(BatchLoader::new());
+        let token = crate::tests::token();
+        let (justintv, twitch) = futures::join!(
+            client.get_user_from_id("1337", &token),
+            client.get_user_from_id("12826", &token),
+        );
+        assert_eq!(
+            justintv.unwrap_err().kind(),
+            Some(helix::HelixErrorKind::BadRequest)
+        );
+        assert_eq!(
+            twitch.unwrap_err().kind(),
+            Some(helix::HelixErrorKind::BadRequest)
+        );
+        assert_eq!(client.get_client().requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn batches_per_token() {
+        let client =
+            MockClient::helix([(200, USERS), (200, USERS)]).with_batch_loader(BatchLoader::new());
+        let token = crate::tests::token();
+        let other = twitch_oauth2::UserToken::from_existing_unchecked(
+            twitch_oauth2::AccessToken::new("othertoken".to_string()),
+            None,
+            twitch_oauth2::ClientId::new("validclientid".to_string()),
+            None,
+            "twitch".into(),
+            "12826".into(),
+            None,
+            None,
+        );
+        let (justintv, twitch) = futures::try_join!(
+            client.get_user_from_id("1337", &token),
+            client.get_user_from_id("12826", &other),
+        )
+        .unwrap();
+        assert_eq!(justintv.unwrap().login.as_str(), "justintv");
+        assert_eq!(twitch.unwrap().login.as_str(), "twitch");
+        assert_eq!(client.get_client().requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn dropped_lookup_still_sends_batch() {
+        let client = MockClient::helix([(200, USERS)]).with_batch_loader(BatchLoader::new());
+        let token = crate::tests::token();
+        let mut first = Box::pin(client.get_user_from_id("1337", &token));
+        // start the batch, then give up on the lookup
+        assert!(futures::poll!(first.as_mut()).is_pending());
+        drop(first);
+        let twitch = client.get_user_from_id("12826", &token).await.unwrap();
+        assert_eq!(twitch.unwrap().login.as_str(), "twitch");
+        assert_eq!(client.get_client().requests.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,356 @@
+//! Caching of responses for [`HelixClient`](super::HelixClient)
+//!
+//! Some endpoints, like [Get Global Emotes](crate::helix::chat::GetGlobalEmotesRequest) or [Get Cheermotes](crate::helix::bits::GetCheermotesRequest),
+//! return data that rarely changes. A [`ResponseCache`] keeps successful `GET` responses for these endpoints for a configured time,
+//! and serves them without sending a request.
+//!
+//! Only endpoints that have been given a time to live with [`ResponseCache::cache`] are cached.
+//! Responses are keyed by the request URI and the token used, responses for user tokens are only shared between requests made for the same user.
+//! When Helix returns an `ETag` header, expired responses are revalidated with `If-None-Match`.
+//!
+//! Responses are stored in a [`CacheStore`], by default a [`MemoryStore`]. Implement [`CacheStore`] to use another store, like Redis.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use twitch_api::helix::{
+//!     cache::{MemoryStore, ResponseCache},
+//!     chat::GetGlobalEmotesRequest,
+//!     HelixClient,
+//! };
+//! # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+//! let cache = ResponseCache::new(MemoryStore::new())
+//!     .cache::<GetGlobalEmotesRequest>(Duration::from_secs(60 * 60));
+//! let client: HelixClient<reqwest::Client> = HelixClient::new().with_cache(cache);
+//! ```
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, SystemTime},
+};
+
+use twitch_oauth2::TwitchToken;
+
+use crate::client::{BoxedFuture, Bytes};
+
+/// A response stored in a [`CacheStore`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CachedResponse {
+    /// Status code of the response
+    pub status: u16,
+    /// Body of the response
+    pub body: Bytes,
+    /// `ETag` header of the response, if any
+    pub etag: Option<String>,
+    /// Time when the response should be revalidated
+    pub expires_at: SystemTime,
+}
+
+impl CachedResponse {
+    /// Create a new cached response
+    pub fn new(status: u16, body: Bytes, etag: Option<String>, expires_at: SystemTime) -> Self {
+        Self {
+            status,
+            body,
+            etag,
+            expires_at,
+        }
+    }
+
+    /// Returns `true` if the response can be used without revalidating it
+    pub fn is_fresh(&self, now: SystemTime) -> bool { self.expires_at > now }
+
+    fn to_response(&self) -> crate::client::Response {
+        let mut response = http::Response::new(self.body.clone());
+        *response.status_mut() =
+            http::StatusCode::from_u16(self.status).unwrap_or(http::StatusCode::OK);
+        if let Some(etag) = self
+            .etag
+            .as_deref()
+            .and_then(|e| http::HeaderValue::from_str(e).ok())
+        {
+            response.headers_mut().insert(http::header::ETAG, etag);
+        }
+        response
+    }
+}
+
+/// A store for cached responses.
+///
+/// Errors in the store should be handled by the store, a failed lookup is treated as a cache miss.
+pub trait CacheStore: Send + Sync {
+    /// Get the response stored for `key`
+    fn get<'a>(&'a self, key: &'a str) -> BoxedFuture<'a, Option<CachedResponse>>;
+    /// Store `response` for `key`
+    fn put<'a>(&'a self, key: &'a str, response: CachedResponse) -> BoxedFuture<'a, ()>;
+}
+
+/// A [`CacheStore`] keeping responses in memory.
+///
+/// Expired responses are kept for revalidation if they have an `ETag`, other expired responses are removed when a new response is stored.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    responses: Mutex<HashMap<String, CachedResponse>>,
+}
+
+impl MemoryStore {
+    /// Create a new, empty store
+    pub fn new() -> Self { Self::default() }
+}
+
+impl CacheStore for MemoryStore {
+    fn get<'a>(&'a self, key: &'a str) -> BoxedFuture<'a, Option<CachedResponse>> {
+        let response = self.responses.lock().expect("poisoned").get(key).cloned();
+        Box::pin(async move { response })
+    }
+
+    fn put<'a>(&'a self, key: &'a str, response: CachedResponse) -> BoxedFuture<'a, ()> {
+        let now = SystemTime::now();
+        let mut responses = self.responses.lock().expect("poisoned");
+        responses.retain(|_, r| r.is_fresh(now) || r.etag.is_some());
+        responses.insert(key.to_owned(), response);
+        Box::pin(async {})
+    }
+}
+
+/// Counters for a [`ResponseCache`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CacheStats {
+    /// Requests answered from the cache without contacting Helix
+    pub hits: u64,
+    /// Requests that were sent to Helix
+    pub misses: u64,
+    /// Requests answered from the cache after Helix responded with `304 Not Modified`
+    pub revalidated: u64,
+}
+
+/// A cache for responses of `GET` requests.
+///
+/// See the [module level documentation](self)
+pub struct ResponseCache {
+    store: Box<dyn CacheStore>,
+    ttls: HashMap<&'static str, Duration>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    revalidated: AtomicU64,
+}
+
+impl std::fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("ttls", &self.ttls)
+            .field("stats", &self.stats())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self { Self::new(MemoryStore::new()) }
+}
+
+impl ResponseCache {
+    /// Create a new cache using `store`, no endpoints are cached until configured with [`ResponseCache::cache`].
+    pub fn new(store: impl CacheStore + 'static) -> Self {
+        Self {
+            store: Box::new(store),
+            ttls: HashMap::new(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            revalidated: AtomicU64::new(0),
+        }
+    }
+
+    /// Create a new cache in memory, caching endpoints that rarely change.
+    ///
+    /// | Endpoint | Time to live |
+    /// | -------- | ------------ |
+    /// | [Get Global Emotes](crate::helix::chat::GetGlobalEmotesRequest) | 1 hour |
+    /// | [Get Channel Emotes](crate::helix::chat::GetChannelEmotesRequest) | 10 minutes |
+    /// | [Get Global Chat Badges](crate::helix::chat::GetGlobalChatBadgesRequest) | 1 hour |
+    /// | [Get Channel Chat Badges](crate::helix::chat::GetChannelChatBadgesRequest) | 10 minutes |
+    /// | [Get Cheermotes](crate::helix::bits::GetCheermotesRequest) | 1 hour |
+    /// | [Get Content Classification Labels](crate::helix::ccls::GetContentClassificationLabelsRequest) | 1 day |
+    /// | [Get Games](crate::helix::games::GetGamesRequest) | 1 hour |
+    pub fn with_defaults() -> Self {
+        use crate::helix::{bits, ccls, chat, games};
+
+        const HOUR: Duration = Duration::from_secs(60 * 60);
+        Self::default()
+            .cache::<chat::GetGlobalEmotesRequest>(HOUR)
+            .cache::<chat::GetChannelEmotesRequest>(HOUR / 6)
+            .cache::<chat::GetGlobalChatBadgesRequest>(HOUR)
+            .cache::<chat::GetChannelChatBadgesRequest>(HOUR / 6)
+            .cache::<bits::GetCheermotesRequest>(HOUR)
+            .cache::<ccls::GetContentClassificationLabelsRequest>(HOUR * 24)
+            .cache::<games::GetGamesRequest>(HOUR)
+    }
+
+    /// Cache responses of the endpoint `R` for `ttl`.
+    pub fn cache<R: crate::helix::Request + crate::helix::RequestGet>(
+        mut self,
+        ttl: Duration,
+    ) -> Self {
+        self.ttls.insert(R::PATH, ttl);
+        self
+    }
+
+    /// Get the time to live for the endpoint with the given [path](crate::helix::Request::PATH), if it's cached.
+    pub fn ttl(&self, path: &str) -> Option<Duration> { self.ttls.get(path).copied() }
+
+    /// Get the hit and miss counters of this cache
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            revalidated: self.revalidated.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Look up a response, returning it if it's fresh.
+    ///
+    /// If a stale response with an `ETag` is found, `If-None-Match` is added to `req` and the response is returned for [`ResponseCache::store`].
+    pub(crate) async fn lookup(
+        &self,
+        key: &str,
+        req: &mut http::Request<Bytes>,
+    ) -> Result<crate::client::Response, Option<CachedResponse>> {
+        let Some(cached) = self.store.get(key).await else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return Err(None);
+        };
+        if cached.is_fresh(SystemTime::now()) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(cached.to_response());
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        match cached
+            .etag
+            .as_deref()
+            .and_then(|e| http::HeaderValue::from_str(e).ok())
+        {
+            Some(etag) => {
+                req.headers_mut().insert(http::header::IF_NONE_MATCH, etag);
+                Err(Some(cached))
+            }
+            None => Err(None),
+        }
+    }
+
+    /// Store a response, returning the response to use.
+    ///
+    /// `stale` is the response returned from [`ResponseCache::lookup`], used when Helix responded with `304 Not Modified`.
+    pub(crate) async fn store(
+        &self,
+        key: &str,
+        ttl: Duration,
+        response: crate::client::Response,
+        stale: Option<CachedResponse>,
+    ) -> crate::client::Response {
+        let expires_at = SystemTime::now() + ttl;
+        match (response.status(), stale) {
+            (http::StatusCode::NOT_MODIFIED, Some(mut stale)) => {
+                self.revalidated.fetch_add(1, Ordering::Relaxed);
+                stale.expires_at = expires_at;
+                let response = stale.to_response();
+                self.store.put(key, stale).await;
+                response
+            }
+            (status, _) if status.is_success() => {
+                let etag = response
+                    .headers()
+                    .get(http::header::ETAG)
+                    .and_then(|e| e.to_str().ok())
+                    .map(ToOwned::to_owned);
+                let cached =
+                    CachedResponse::new(status.as_u16(), response.body().clone(), etag, expires_at);
+                self.store.put(key, cached).await;
+                response
+            }
+            _ => response,
+        }
+    }
+}
+
+/// Get the key for a request made with `token`
+pub(crate) fn key<T: TwitchToken + ?Sized>(req: &http::Request<Bytes>, token: &T) -> String {
+    match token.user_id() {
+        Some(user_id) => format!("user:{}:{}", user_id, req.uri()),
+        None => format!("app:{}:{}", token.client_id().as_str(), req.uri()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::MockClient;
+
+    #[tokio::test]
+    async fn caches_responses() {
+        let client = MockClient::helix([(
+            200,
+            r#"{"data":[{"id":"DrugsIntoxication","description":"Excessive tobacco glorification or promotion, any marijuana consumption/use, legal drug and alcohol induced intoxication, discussions of illegal drugs.","name":"Drugs, Intoxication, or Excessive Tobacco Use"}]}"#,
+        )])
+        .with_cache(ResponseCache::with_defaults());
+        let token = crate::tests::token();
+
+        for _ in 0..2 {
+            let response = client
+                .req_get(
+                    crate::helix::ccls::GetContentClassificationLabelsRequest::new(),
+                    &token,
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.data.len(), 1);
+        }
+        assert_eq!(client.get_client().requests.lock().unwrap().len(), 1);
+        let stats = client.cache().unwrap().stats();
+        assert_eq!((stats.hits, stats.misses), (1, 1));
+    }
+
+    #[tokio::test]
+    async fn revalidates_with_etag() {
+        let cache = ResponseCache::default();
+        let mut req = http::Request::new(Bytes::new());
+        cache
+            .store(
+                "key",
+                Duration::ZERO,
+                http::Response::builder()
+                    .header("ETag", "\"abc\"")
+                    .body(Bytes::from_static(b"{}"))
+                    .unwrap(),
+                None,
+            )
+            .await;
+        let stale = cache.lookup("key", &mut req).await.unwrap_err();
+        assert_eq!(req.headers()["If-None-Match"], "\"abc\"");
+        let response = cache
+            .store(
+                "key",
+                Duration::from_secs(60),
+                http::Response::builder()
+                    .status(304)
+                    .body(Bytes::new())
+                    .unwrap(),
+                stale,
+            )
+            .await;
+        assert_eq!(response.body().as_ref(), b"{}");
+        assert!(cache.lookup("key", &mut req).await.is_ok());
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 1,
+                revalidated: 1
+            }
+        );
+    }
+}
@@ -30,6 +30,8 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     }
 
     /// Get [User](helix::users::User) from user id
+    ///
+    /// Concurrent lookups are merged into fewer requests if a [`BatchLoader`](helix::BatchLoader) is [used](HelixClient::with_batch_loader).
     pub async fn get_user_from_id<T>(
         &'client self,
         id: impl Into<&types::UserIdRef> + Send,
@@ -38,6 +40,9 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
+        if let Some(loader) = self.batch_loader.as_deref() {
+            return loader.get_user_from_id(self, id, token).await;
+        }
         self.req_get(helix::users::GetUsersRequest::ids(&[id.into()][..]), token)
             .await
             .map(|response| response.first())
@@ -60,6 +65,8 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     ///     .get_users_from_ids(&["1234", "4321"][..].into(), &token).try_collect().await?;
     /// # Ok(()) }
     /// ```
+    ///
+    /// Concurrent lookups are merged into fewer requests if a [`BatchLoader`](helix::BatchLoader) is [used](HelixClient::with_batch_loader).
     pub fn get_users_from_ids<T>(
         &'client self,
         ids: &'client types::Collection<'client, types::UserId>,
@@ -68,6 +75,12 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
+        if let Some(loader) = self.batch_loader.as_deref() {
+            return futures::stream::once(loader.get_users_from_ids(self, ids, token))
+                .map_ok(|users| futures::stream::iter(users.into_iter().map(Ok)))
+                .try_flatten()
+                .boxed();
+        }
         futures::stream::iter(ids.chunks(100))
             .map(move |c| {
                 let req = helix::users::GetUsersRequest::ids(c);
@@ -76,6 +89,7 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
             .flatten_unordered(None)
             .map_ok(|resp| futures::stream::iter(resp.data.into_iter().map(Ok)))
             .try_flatten_unordered(None)
+            .boxed()
     }
 
     /// Get multiple [User](helix::users::User)s from user logins/nicknames.
@@ -130,6 +144,8 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     }
 
     /// Get [ChannelInformation](helix::channels::ChannelInformation) from a broadcasters id
+    ///
+    /// Concurrent lookups are merged into fewer requests if a [`BatchLoader`](helix::BatchLoader) is [used](HelixClient::with_batch_loader).
     pub async fn get_channel_from_id<T>(
         &'client self,
         id: impl Into<&types::UserIdRef> + Send,
@@ -138,6 +154,9 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
+        if let Some(loader) = self.batch_loader.as_deref() {
+            return loader.get_channel_from_id(self, id, token).await;
+        }
         let ids: &[_] = &[id.into()];
         self.req_get(
             helix::channels::GetChannelInformationRequest::broadcaster_ids(ids),
@@ -164,6 +183,8 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     ///     .get_channels_from_ids(&["1234", "4321"][..].into(), &token).try_collect().await?;
     /// # Ok(()) }
     /// ```
+    ///
+    /// Concurrent lookups are merged into fewer requests if a [`BatchLoader`](helix::BatchLoader) is [used](HelixClient::with_batch_loader).
     pub fn get_channels_from_ids<T>(
         &'client self,
         ids: &'client types::Collection<'client, types::UserId>,
@@ -175,6 +196,12 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
+        if let Some(loader) = self.batch_loader.as_deref() {
+            return futures::stream::once(loader.get_channels_from_ids(self, ids, token))
+                .map_ok(|channels| futures::stream::iter(channels.into_iter().map(Ok)))
+                .try_flatten()
+                .boxed();
+        }
         futures::stream::iter(ids.chunks(100))
             .map(move |c| {
                 let req = helix::channels::GetChannelInformationRequest::broadcaster_ids(c);
@@ -183,6 +210,7 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
             .flatten_unordered(None)
             .map_ok(|resp| futures::stream::iter(resp.data.into_iter().map(Ok)))
             .try_flatten_unordered(None)
+            .boxed()
     }
 
     /// Get multiple [Stream](helix::streams::Stream)s from user ids.
@@ -865,6 +893,8 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     ///     .get_games_by_id(&["509658", "32982", "27471"][..].into(), &token).try_collect().await?;
     /// # Ok(()) }
     /// ```
+    ///
+    /// Concurrent lookups are merged into fewer requests if a [`BatchLoader`](helix::BatchLoader) is [used](HelixClient::with_batch_loader).
     pub fn get_games_by_id<T>(
         &'client self,
         ids: &'client types::Collection<'client, types::CategoryId>,
@@ -873,6 +903,12 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
     where
         T: TwitchToken + Send + Sync + ?Sized,
     {
+        if let Some(loader) = self.batch_loader.as_deref() {
+            return futures::stream::once(loader.get_games_by_id(self, ids, token))
+                .map_ok(|games| futures::stream::iter(games.into_iter().map(Ok)))
+                .try_flatten()
+                .boxed();
+        }
         futures::stream::iter(ids.chunks(100))
             .map(move |c| {
                 let req = helix::games::GetGamesRequest::ids(c);
@@ -881,6 +917,7 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
             .flatten_unordered(None)
             .map_ok(|resp| futures::stream::iter(resp.data.into_iter().map(Ok)))
             .try_flatten_unordered(None)
+            .boxed()
     }
 
     /// Get the status of a Hype Train for the specified broadcaster.
@@ -1100,6 +1137,34 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
         make_stream(req, token, self, |broadcasts| broadcasts.segments.into())
     }
 
+    /// Get the schedule of a channel as an iCalendar
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// let calendar = client.get_channel_icalendar("141981764", &token).await?;
+    /// std::fs::write("schedule.ics", &calendar.calendar)?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_channel_icalendar<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<helix::schedule::ChannelICalendar, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::schedule::GetChannelICalendarRequest::broadcaster_id(broadcaster_id);
+        Ok(self.req_get(req, token).await?.data)
+    }
+
     /// Get all global emotes
     pub async fn get_global_emotes<T>(
         &'client self,
@@ -1457,6 +1522,377 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
         Ok(self.req_delete(req, token).await?.data)
     }
 
+    #[cfg(feature = "beta")]
+    /// Get the Guest Star settings of a channel
+    pub async fn get_channel_guest_star_settings<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<helix::guest_star::GuestStarSettings, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::guest_star::GetChannelGuestStarSettingsRequest::new(
+            broadcaster_id,
+            moderator_id,
+        );
+        Ok(self.req_get(req, token).await?.data)
+    }
+
+    #[cfg(feature = "beta")]
+    /// Get the active Guest Star session of a channel, if there is one
+    pub async fn get_guest_star_session<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<Option<helix::guest_star::GuestStarSession>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::guest_star::GetGuestStarSessionRequest::new(broadcaster_id, moderator_id);
+        Ok(self.req_get(req, token).await?.data)
+    }
+
+    #[cfg(feature = "beta")]
+    /// Create a Guest Star session on behalf of the broadcaster
+    pub async fn create_guest_star_session<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<helix::guest_star::GuestStarSession, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::guest_star::CreateGuestStarSessionRequest::broadcaster_id(broadcaster_id);
+        Ok(self.req_post(req, helix::EmptyBody, token).await?.data)
+    }
+
+    #[cfg(feature = "beta")]
+    /// End a Guest Star session on behalf of the broadcaster
+    pub async fn end_guest_star_session<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        session_id: impl types::IntoCow<'b, types::GuestStarSessionIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<helix::guest_star::GuestStarSession, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::guest_star::EndGuestStarSessionRequest::new(broadcaster_id, session_id);
+        Ok(self.req_delete(req, token).await?.data)
+    }
+
+    #[cfg(feature = "beta")]
+    /// Get the pending invites of a Guest Star session
+    pub async fn get_guest_star_invites<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        session_id: impl types::IntoCow<'b, types::GuestStarSessionIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<Vec<helix::guest_star::GuestStarInvite>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::guest_star::GetGuestStarInvitesRequest::new(
+            broadcaster_id,
+            moderator_id,
+            session_id,
+        );
+        Ok(self.req_get(req, token).await?.data)
+    }
+
+    #[cfg(feature = "beta")]
+    /// Invite a guest to a Guest Star session
+    pub async fn send_guest_star_invite<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        session_id: impl types::IntoCow<'b, types::GuestStarSessionIdRef> + Send + 'b,
+        guest_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<(), ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::guest_star::SendGuestStarInviteRequest::new(
+            broadcaster_id,
+            moderator_id,
+            session_id,
+            guest_id,
+        );
+        self.req_post(req, helix::EmptyBody, token).await?;
+        Ok(())
+    }
+
+    #[cfg(feature = "beta")]
+    /// Revoke the invite of a guest to a Guest Star session
+    pub async fn delete_guest_star_invite<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        session_id: impl types::IntoCow<'b, types::GuestStarSessionIdRef> + Send + 'b,
+        guest_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<(), ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::guest_star::DeleteGuestStarInviteRequest::new(
+            broadcaster_id,
+            moderator_id,
+            session_id,
+            guest_id,
+        );
+        self.req_delete(req, token).await?;
+        Ok(())
+    }
+
+    #[cfg(feature = "beta")]
+    /// Assign a ready guest to a slot in a Guest Star session
+    pub async fn assign_guest_star_slot<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        session_id: impl types::IntoCow<'b, types::GuestStarSessionIdRef> + Send + 'b,
+        guest_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        slot_id: impl types::IntoCow<'b, types::GuestStarSlotIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<(), ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::guest_star::AssignGuestStarSlotRequest::new(
+            broadcaster_id,
+            moderator_id,
+            session_id,
+            guest_id,
+            slot_id,
+        );
+        self.req_post(req, helix::EmptyBody, token).await?;
+        Ok(())
+    }
+
+    #[cfg(feature = "beta")]
+    /// Remove a guest from their slot in a Guest Star session
+    ///
+    /// If `should_reinvite_guest` is `true`, the guest is sent back to the invite queue.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "The endpoint requires this many arguments"
+    )]
+    pub async fn delete_guest_star_slot<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        moderator_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        session_id: impl types::IntoCow<'b, types::GuestStarSessionIdRef> + Send + 'b,
+        guest_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        slot_id: impl types::IntoCow<'b, types::GuestStarSlotIdRef> + Send + 'b,
+        should_reinvite_guest: bool,
+        token: &T,
+    ) -> Result<(), ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::guest_star::DeleteGuestStarSlotRequest::new(
+            broadcaster_id,
+            moderator_id,
+            session_id,
+            guest_id,
+            slot_id,
+        )
+        .should_reinvite_guest(should_reinvite_guest);
+        self.req_delete(req, token).await?;
+        Ok(())
+    }
+
+    /// Get the analytics reports for all extensions the authenticated user owns
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let reports: Vec<helix::analytics::ExtensionAnalytics> = client
+    ///     .get_extension_analytics(&token)
+    ///     .try_collect().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_extension_analytics<T>(
+        &'client self,
+        token: &'client T,
+    ) -> impl futures::Stream<Item = Result<helix::analytics::ExtensionAnalytics, ClientError<C>>>
+           + Send
+           + Unpin
+           + 'client
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::analytics::GetExtensionAnalyticsRequest::new();
+        make_stream(req, token, self, std::collections::VecDeque::from)
+    }
+
+    /// Get the analytics reports for all games the authenticated user owns
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let reports: Vec<helix::analytics::GameAnalytics> = client
+    ///     .get_game_analytics(&token)
+    ///     .try_collect().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_game_analytics<T>(
+        &'client self,
+        token: &'client T,
+    ) -> impl futures::Stream<Item = Result<helix::analytics::GameAnalytics, ClientError<C>>>
+           + Send
+           + Unpin
+           + 'client
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::analytics::GetGameAnalyticsRequest::new();
+        make_stream(req, token, self, std::collections::VecDeque::from)
+    }
+
+    /// Get all Drops entitlements granted for a game
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let entitlements: Vec<helix::entitlements::DropEntitlement> = client
+    ///     .get_drops_entitlements_for_game("33214", &token)
+    ///     .try_collect().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_drops_entitlements_for_game<'b: 'client, T>(
+        &'client self,
+        game_id: impl types::IntoCow<'b, types::CategoryIdRef> + 'b,
+        token: &'client T,
+    ) -> impl futures::Stream<Item = Result<helix::entitlements::DropEntitlement, ClientError<C>>>
+           + Send
+           + Unpin
+           + 'client
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::entitlements::GetDropsEntitlementsRequest::new().game_id(game_id);
+        make_stream(req, token, self, std::collections::VecDeque::from)
+    }
+
+    /// Set the fulfillment status of Drops entitlements
+    ///
+    /// Returns the IDs grouped by whether they were updated or why they weren't.
+    pub async fn update_drops_entitlements<'b, T>(
+        &'client self,
+        entitlement_ids: impl IntoIterator<Item = impl Into<Cow<'b, str>>> + Send + 'b,
+        fulfillment_status: helix::entitlements::FulfillmentStatus,
+        token: &T,
+    ) -> Result<Vec<helix::entitlements::UpdatedEntitlementSet>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::entitlements::UpdateDropsEntitlementsRequest::new();
+        let body = helix::entitlements::UpdateDropsEntitlementsBody::new(
+            entitlement_ids,
+            fulfillment_status,
+        );
+        Ok(self.req_patch(req, body, token).await?.data)
+    }
+
+    /// Get the custom Power-ups of a broadcaster
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// let power_ups: Vec<helix::bits::CustomPowerUp> = client
+    ///     .get_custom_power_ups("1234", &token)
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_custom_power_ups<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + Send + 'b,
+        token: &T,
+    ) -> Result<Vec<helix::bits::CustomPowerUp>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        Ok(self
+            .req_get(
+                helix::bits::GetCustomPowerUpsRequest::broadcaster_id(broadcaster_id),
+                token,
+            )
+            .await?
+            .data)
+    }
+
+    /// Get all transactions of an extension
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
+    /// # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+    /// use twitch_api::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let transactions: Vec<helix::bits::ExtensionTransaction> = client
+    ///     .get_extension_transactions("uo6dggojyb8d6soh92zknwmi5ej1q2", &token)
+    ///     .try_collect().await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_extension_transactions<'b: 'client, T>(
+        &'client self,
+        extension_id: impl types::IntoCow<'b, types::ExtensionIdRef> + 'b,
+        token: &'client T,
+    ) -> impl futures::Stream<Item = Result<helix::bits::ExtensionTransaction, ClientError<C>>>
+           + Send
+           + Unpin
+           + 'client
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::bits::GetExtensionTransactionsRequest::extension_id(extension_id);
+        make_stream(req, token, self, std::collections::VecDeque::from)
+    }
+
     /// Update a user's chat color
     pub async fn update_user_chat_color<'b, T>(
         &'client self,
@@ -1581,6 +2017,221 @@ impl<'client, C: crate::HttpClient + Sync + 'client> HelixClient<'client, C> {
         Ok(self.req_get(req, token).await?.data)
     }
 
+    /// Get the configuration segments of an extension
+    ///
+    /// Requires a signed JWT created by the extension backend, see [`helix::extensions`].
+    /// `broadcaster_id` is required for the [developer](helix::extensions::ExtensionSegment::Developer) and [broadcaster](helix::extensions::ExtensionSegment::Broadcaster) segments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix::{self, extensions::ExtensionSegment};
+    ///
+    /// let segments: Vec<helix::extensions::ExtensionConfigurationSegment> = client
+    ///     .get_extension_configuration_segment(
+    ///         "uo6dggojyb8d6soh92zknwmi5ej1q2",
+    ///         vec![ExtensionSegment::Global],
+    ///         None,
+    ///         &token,
+    ///     )
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_extension_configuration_segment<'b, T>(
+        &'client self,
+        extension_id: impl types::IntoCow<'b, types::ExtensionIdRef> + 'b + Send,
+        segments: impl Into<Vec<helix::extensions::ExtensionSegment>> + Send,
+        broadcaster_id: Option<&'b types::UserIdRef>,
+        token: &T,
+    ) -> Result<Vec<helix::extensions::ExtensionConfigurationSegment>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let mut req =
+            helix::extensions::GetExtensionConfigurationSegmentRequest::new(extension_id, segments);
+        req.broadcaster_id = broadcaster_id.map(Cow::Borrowed);
+
+        Ok(self.req_get(req, token).await?.data)
+    }
+
+    /// Get all live channels that have installed or activated an extension
+    ///
+    /// Requires a signed JWT created by the extension backend or an app access token.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    /// use futures::TryStreamExt;
+    ///
+    /// let channels: Vec<helix::extensions::ExtensionLiveChannel> = client
+    ///     .get_extension_live_channels("uo6dggojyb8d6soh92zknwmi5ej1q2", &token)
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get_extension_live_channels<'b: 'client, T>(
+        &'client self,
+        extension_id: impl types::IntoCow<'b, types::ExtensionIdRef> + 'b,
+        token: &'client T,
+    ) -> impl futures::Stream<Item = Result<helix::extensions::ExtensionLiveChannel, ClientError<C>>>
+           + Send
+           + Unpin
+           + 'client
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::extensions::GetExtensionLiveChannelsRequest::extension_id(extension_id)
+            .first(100);
+
+        make_stream(req, token, self, std::collections::VecDeque::from)
+    }
+
+    /// Get the shared secrets of an extension
+    ///
+    /// Requires a signed JWT created by the extension backend, see [`helix::extensions`].
+    pub async fn get_extension_secrets<'b, T>(
+        &'client self,
+        extension_id: impl types::IntoCow<'b, types::ExtensionIdRef> + 'b + Send,
+        token: &T,
+    ) -> Result<Vec<helix::extensions::ExtensionSecrets>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::extensions::GetExtensionSecretsRequest::extension_id(extension_id);
+
+        Ok(self.req_get(req, token).await?.data)
+    }
+
+    /// Send a chat message to a broadcaster's chat room as an extension
+    ///
+    /// Requires a signed JWT created by the extension backend, see [`helix::extensions`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+    /// use twitch_api::helix;
+    ///
+    /// client
+    ///     .send_extension_chat_message(
+    ///         "237757755",
+    ///         "Hello",
+    ///         "uo6dggojyb8d6soh92zknwmi5ej1q2",
+    ///         "0.0.9",
+    ///         &token,
+    ///     )
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn send_extension_chat_message<'b, T>(
+        &'client self,
+        broadcaster_id: impl types::IntoCow<'b, types::UserIdRef> + 'b + Send,
+        text: impl Into<Cow<'b, str>> + Send,
+        extension_id: impl types::IntoCow<'b, types::ExtensionIdRef> + 'b + Send,
+        extension_version: impl Into<Cow<'b, str>> + Send,
+        token: &T,
+    ) -> Result<helix::extensions::SendExtensionChatMessage, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        self.req_post(
+            helix::extensions::SendExtensionChatMessageRequest::broadcaster_id(broadcaster_id),
+            helix::extensions::SendExtensionChatMessageBody::new(
+                text,
+                extension_id,
+                extension_version,
+            ),
+            token,
+        )
+        .await
+        .map(|res| res.data)
+    }
+
+    /// Get information about an extension
+    ///
+    /// If `extension_version` is not specified, the latest released version is returned.
+    /// Requires a signed JWT created by the extension backend, see [`helix::extensions`].
+    pub async fn get_extension<'b, T>(
+        &'client self,
+        extension_id: impl types::IntoCow<'b, types::ExtensionIdRef> + 'b + Send,
+        extension_version: Option<&'b str>,
+        token: &T,
+    ) -> Result<Option<helix::extensions::Extension>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let mut req = helix::extensions::GetExtensionsRequest::extension_id(extension_id);
+        req.extension_version = extension_version.map(Cow::Borrowed);
+
+        Ok(self.req_get(req, token).await?.first())
+    }
+
+    /// Get information about a released extension
+    ///
+    /// If `extension_version` is not specified, the latest version is returned.
+    pub async fn get_released_extension<'b, T>(
+        &'client self,
+        extension_id: impl types::IntoCow<'b, types::ExtensionIdRef> + 'b + Send,
+        extension_version: Option<&'b str>,
+        token: &T,
+    ) -> Result<Option<helix::extensions::Extension>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let mut req = helix::extensions::GetReleasedExtensionsRequest::extension_id(extension_id);
+        req.extension_version = extension_version.map(Cow::Borrowed);
+
+        Ok(self.req_get(req, token).await?.first())
+    }
+
+    /// Get the Bits products of the extension identified by the client id of the app access token
+    ///
+    /// If `should_include_all` is true, disabled and expired products are included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, twitch_api::client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+    /// # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
+    /// # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+    /// use twitch_api::helix;
+    ///
+    /// let products: Vec<helix::extensions::BitsProduct> =
+    ///     client.get_extension_bits_products(false, &token).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn get_extension_bits_products<T>(
+        &'client self,
+        should_include_all: bool,
+        token: &T,
+    ) -> Result<Vec<helix::extensions::BitsProduct>, ClientError<C>>
+    where
+        T: TwitchToken + Send + Sync + ?Sized,
+    {
+        let req = helix::extensions::GetExtensionBitsProductsRequest::new()
+            .should_include_all(should_include_all);
+
+        Ok(self.req_get(req, token).await?.data)
+    }
+
     /// Retrieves the active shared chat session for a channel
     ///
     /// [`None`] is returned if no shared chat session is active.
@@ -1,4 +1,5 @@
 use super::*;
+use crate::helix::request::errors::ratelimit_reset;
 use std::convert::TryInto;
 
 /// Custom response retrieved from endpoint, used for specializing responses
@@ -46,13 +47,10 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
         T: TwitchToken + ?Sized,
         C: Send,
     {
-        let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        let mut req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        self.rebase(&request, &mut req)?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let (response, _) = self.send(req, R::PATH, token).await?;
         {
             let request = Some(request);
             let uri = &uri;
@@ -71,6 +69,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                     message,
                     uri: uri.clone(),
+                    ratelimit_reset: ratelimit_reset(response.headers()),
                 }
                 .into());
             }
@@ -107,14 +106,11 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
         T: TwitchToken + ?Sized,
         C: Send,
     {
-        let req =
+        let mut req =
             request.create_request(body, token.token().secret(), token.client_id().as_str())?;
+        self.rebase(&request, &mut req)?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let (response, _) = self.send(req, R::PATH, token).await?;
         {
             let request = Some(request);
             let uri = &uri;
@@ -133,6 +129,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                     message,
                     uri: uri.clone(),
+                    ratelimit_reset: ratelimit_reset(response.headers()),
                     body: response.body().clone(),
                 }
                 .into());
@@ -176,14 +173,11 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
         C: Send,
         F: Fn(&R, &http::Uri, &str, http::StatusCode) -> Result<(), HelixRequestPatchError>,
     {
-        let req =
+        let mut req =
             request.create_request(body, token.token().secret(), token.client_id().as_str())?;
+        self.rebase(&request, &mut req)?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let (response, _) = self.send(req, R::PATH, token).await?;
         {
             let uri = &uri;
             let text = std::str::from_utf8(response.body()).map_err(|e| {
@@ -200,6 +194,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                     message,
                     uri: uri.clone(),
+                    ratelimit_reset: ratelimit_reset(response.headers()),
                     body: response.body().clone(),
                 }
                 .into());
@@ -242,13 +237,10 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
         C: Send,
         F: Fn(&R, &http::Uri, &str, http::StatusCode) -> Result<(), HelixRequestDeleteError>,
     {
-        let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        let mut req = request.create_request(token.token().secret(), token.client_id().as_str())?;
+        self.rebase(&request, &mut req)?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let (response, _) = self.send(req, R::PATH, token).await?;
         {
             let uri = &uri;
             let text = std::str::from_utf8(response.body()).map_err(|e| {
@@ -265,6 +257,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                     message,
                     uri: uri.clone(),
+                    ratelimit_reset: ratelimit_reset(response.headers()),
                     body: response.body().clone(),
                 }
                 .into());
@@ -309,14 +302,11 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
         C: Send,
         F: Fn(&R, &http::Uri, &str, http::StatusCode) -> Result<(), HelixRequestDeleteError>,
     {
-        let req =
+        let mut req =
             request.create_request(body, token.token().secret(), token.client_id().as_str())?;
+        self.rebase(&request, &mut req)?;
         let uri = req.uri().clone();
-        let response = self
-            .client
-            .req(req)
-            .await
-            .map_err(ClientRequestError::RequestError)?;
+        let (response, _) = self.send(req, R::PATH, token).await?;
         {
             let uri = &uri;
             let text = std::str::from_utf8(response.body()).map_err(|e| {
@@ -333,6 +323,7 @@ impl<C: crate::HttpClient> HelixClient<'_, C> {
                     status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                     message,
                     uri: uri.clone(),
+                    ratelimit_reset: ratelimit_reset(response.headers()),
                     body: response.body().clone(),
                 }
                 .into());
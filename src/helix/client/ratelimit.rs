@@ -0,0 +1,218 @@
+//! Rate limiting for [`HelixClient`](super::HelixClient)
+//!
+//! Helix uses a token-bucket algorithm to limit requests. Every response carries the state of the
+//! bucket that was used in the `Ratelimit-Limit`, `Ratelimit-Remaining` and `Ratelimit-Reset` headers.
+//! Requests made with an app access token share one bucket per client id, requests made with a user access token
+//! share one bucket per client id and user.
+//!
+//! A [`RateLimiter`] tracks these buckets and delays requests until the bucket has been refilled,
+//! instead of sending them and getting a `429 Too Many Requests` back.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use twitch_api::helix::{HelixClient, RateLimiter};
+//! # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+//! let client: HelixClient<reqwest::Client> =
+//!     HelixClient::new().with_rate_limiter(RateLimiter::new());
+//! ```
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, SystemTime},
+};
+
+use twitch_oauth2::TwitchToken;
+
+use crate::{helix::request::errors, types};
+
+/// Header containing the rate at which points are added to the bucket.
+pub const RATELIMIT_LIMIT: &str = "ratelimit-limit";
+/// Header containing the number of points remaining in the bucket.
+pub const RATELIMIT_REMAINING: &str = "ratelimit-remaining";
+/// Header containing the unix epoch timestamp (in seconds) when the bucket is reset to full.
+pub const RATELIMIT_RESET: &str = errors::RATELIMIT_RESET;
+
+/// Identifies the bucket a request is counted against.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BucketKey {
+    /// Client id of the token
+    pub client_id: twitch_oauth2::ClientId,
+    /// User id of the token, [`None`] for app access tokens.
+    pub user_id: Option<types::UserId>,
+}
+
+impl BucketKey {
+    /// Get the bucket used by requests with this token
+    pub fn from_token<T: TwitchToken + ?Sized>(token: &T) -> Self {
+        Self {
+            client_id: token.client_id().to_owned(),
+            user_id: token.user_id().map(ToOwned::to_owned),
+        }
+    }
+}
+
+/// State of a bucket as reported by Helix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    /// The rate at which points are added to the bucket.
+    pub limit: u64,
+    /// The number of points remaining in the bucket.
+    pub remaining: u64,
+    /// When the bucket is reset to full.
+    pub reset: SystemTime,
+}
+
+impl Bucket {
+    /// Parse the bucket from the `Ratelimit-*` headers of a response.
+    ///
+    /// Returns [`None`] if any header is missing or malformed.
+    pub fn from_headers(headers: &http::HeaderMap) -> Option<Self> {
+        Some(Self {
+            limit: errors::ratelimit_header(headers, RATELIMIT_LIMIT)?,
+            remaining: errors::ratelimit_header(headers, RATELIMIT_REMAINING)?,
+            reset: errors::ratelimit_reset(headers)?,
+        })
+    }
+
+    /// Time left until the bucket is reset, or [`None`] if the reset has already happened.
+    pub fn reset_in(&self, now: SystemTime) -> Option<Duration> {
+        self.reset.duration_since(now).ok().filter(|d| !d.is_zero())
+    }
+}
+
+/// A rate limiter driven by the `Ratelimit-*` headers returned by Helix.
+///
+/// The limiter keeps one [`Bucket`] per [token](BucketKey). Before a request is sent, a point is taken from the bucket,
+/// if the bucket is empty, the request is delayed until the bucket resets.
+/// After every response, the bucket is updated with the values reported by Helix.
+/// Once the reported reset has passed, the bucket is unknown again and requests are let through until
+/// a response reports its new state.
+///
+/// The limiter is shared between all clones of a [`HelixClient`](super::HelixClient).
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    buckets: Mutex<HashMap<BucketKey, Bucket>>,
+    /// Extra time to wait after the reported reset, to account for clock skew.
+    margin: Duration,
+}
+
+impl RateLimiter {
+    /// Create a new rate limiter
+    pub fn new() -> Self { Self::default() }
+
+    /// Set extra time to wait after a bucket is reported to reset, to account for clock skew between you and Twitch.
+    pub fn with_margin(mut self, margin: Duration) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Get the last known state of a bucket
+    pub fn bucket(&self, key: &BucketKey) -> Option<Bucket> {
+        self.buckets
+            .lock()
+            .expect("rate limiter lock was poisoned")
+            .get(key)
+            .copied()
+    }
+
+    /// Try to take a point from the bucket.
+    ///
+    /// Returns the time to wait before trying again if the bucket is empty.
+    pub fn try_acquire(&self, key: &BucketKey) -> Result<(), Duration> {
+        let now = SystemTime::now();
+        let mut buckets = self.buckets.lock().expect("rate limiter lock was poisoned");
+        let Some(bucket) = buckets.get_mut(key) else {
+            // We know nothing about this bucket yet, let the request through and learn from the response.
+            return Ok(());
+        };
+        match bucket.reset_in(now) {
+            // The bucket has been reset since we last heard from it, we don't know when it resets next,
+            // so forget it until the next response tells us.
+            None => {
+                buckets.remove(key);
+                Ok(())
+            }
+            Some(_) if bucket.remaining > 0 => {
+                bucket.remaining -= 1;
+                Ok(())
+            }
+            Some(wait) => Err(wait + self.margin),
+        }
+    }
+
+    /// Wait until a point can be taken from the bucket.
+    pub async fn acquire(&self, key: &BucketKey) {
+        while let Err(wait) = self.try_acquire(key) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?wait, "rate limited, delaying request");
+            futures_timer::Delay::new(wait).await;
+        }
+    }
+
+    /// Update the bucket with the `Ratelimit-*` headers from a response.
+    pub fn update(&self, key: &BucketKey, headers: &http::HeaderMap) {
+        if let Some(bucket) = Bucket::from_headers(headers) {
+            self.buckets
+                .lock()
+                .expect("rate limiter lock was poisoned")
+                .insert(key.clone(), bucket);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::UNIX_EPOCH;
+
+    fn key() -> BucketKey {
+        BucketKey {
+            client_id: twitch_oauth2::ClientId::new("clientid".to_string()),
+            user_id: None,
+        }
+    }
+
+    fn headers(limit: u64, remaining: u64, reset: SystemTime) -> http::HeaderMap {
+        let reset = reset.duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let mut headers = http::HeaderMap::new();
+        headers.insert(RATELIMIT_LIMIT, limit.into());
+        headers.insert(RATELIMIT_REMAINING, remaining.into());
+        headers.insert(RATELIMIT_RESET, reset.into());
+        headers
+    }
+
+    #[test]
+    fn unknown_bucket_is_allowed() {
+        let limiter = RateLimiter::new();
+        assert_eq!(limiter.try_acquire(&key()), Ok(()));
+    }
+
+    #[test]
+    fn empty_bucket_waits() {
+        let limiter = RateLimiter::new();
+        let reset = SystemTime::now() + Duration::from_secs(30);
+        limiter.update(&key(), &headers(800, 1, reset));
+        assert_eq!(limiter.try_acquire(&key()), Ok(()));
+        let wait = limiter.try_acquire(&key()).unwrap_err();
+        assert!(wait <= Duration::from_secs(30));
+        assert_eq!(limiter.bucket(&key()).unwrap().remaining, 0);
+    }
+
+    #[test]
+    fn reset_bucket_is_forgotten() {
+        let limiter = RateLimiter::new();
+        let reset = SystemTime::now() - Duration::from_secs(1);
+        limiter.update(&key(), &headers(800, 0, reset));
+        assert_eq!(limiter.try_acquire(&key()), Ok(()));
+        assert_eq!(limiter.bucket(&key()), None);
+        assert_eq!(limiter.try_acquire(&key()), Ok(()));
+    }
+
+    #[test]
+    fn missing_headers_are_ignored() {
+        let limiter = RateLimiter::new();
+        limiter.update(&key(), &http::HeaderMap::new());
+        assert_eq!(limiter.bucket(&key()), None);
+    }
+}
@@ -0,0 +1,245 @@
+//! Automatic token refreshing for [`HelixClient`](super::HelixClient)
+//!
+//! User access tokens expire, and a request made with an expired token fails with `401 Unauthorized`.
+//! A [`TokenRefresher`] holds a refreshable token, like a [`UserToken`](twitch_oauth2::UserToken) with a refresh token and client secret
+//! or an [`AppAccessToken`](twitch_oauth2::AppAccessToken), behind a shared lock.
+//!
+//! When a client [uses a refresher](super::HelixClient::with_token_refresher) and a request made with the refresher's token fails with `401 Unauthorized`,
+//! the token is refreshed once and the request is sent again with the new token.
+//! Concurrent requests failing with the same token share the refresh.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+//! use std::sync::Arc;
+//! use twitch_api::helix::{HelixClient, TokenRefresher};
+//! use twitch_api::twitch_oauth2::TwitchToken;
+//! # let token = twitch_oauth2::UserToken::from_existing_unchecked(
+//! #     twitch_oauth2::AccessToken::new("totallyvalidtoken".to_string()), None,
+//! #     twitch_oauth2::ClientId::new("validclientid".to_string()), None, "justintv".into(), "1337".into(), None, None);
+//! let refresher = Arc::new(TokenRefresher::new(token).on_refresh(|token| {
+//!     // persist the new refresh token
+//!     println!("token refreshed, expires in {:?}", token.expires_in());
+//! }));
+//! let client: HelixClient<reqwest::Client> =
+//!     HelixClient::new().with_token_refresher(refresher.clone());
+//!
+//! // Always use the current token of the refresher
+//! let user = client
+//!     .get_user_from_login("justintv", &*refresher.token())
+//!     .await?;
+//! # Ok(()) }
+//! ```
+use std::sync::{Arc, Mutex};
+
+use twitch_oauth2::{tokens::errors::RefreshTokenError, AccessToken, TwitchToken};
+
+use crate::client::{BoxedFuture, CompatError};
+
+/// A shared, refreshable token.
+///
+/// See the [module level documentation](self)
+pub struct TokenRefresher<T> {
+    state: Mutex<State<T>>,
+    refreshing: futures::lock::Mutex<()>,
+    #[allow(clippy::type_complexity)]
+    on_refresh: Option<Box<dyn Fn(&T) + Send + Sync>>,
+}
+
+struct State<T> {
+    current: Arc<T>,
+    /// Secret of the token that was replaced by `current`
+    previous: Option<String>,
+}
+
+impl<T> std::fmt::Debug for TokenRefresher<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokenRefresher")
+            .field("on_refresh", &self.on_refresh.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: TwitchToken + Clone + Send + Sync + 'static> TokenRefresher<T> {
+    /// Create a new refresher for `token`
+    pub fn new(token: T) -> Self {
+        Self {
+            state: Mutex::new(State {
+                current: Arc::new(token),
+                previous: None,
+            }),
+            refreshing: futures::lock::Mutex::new(()),
+            on_refresh: None,
+        }
+    }
+
+    /// Call `on_refresh` with the new token every time the token has been refreshed.
+    ///
+    /// Use this to persist the new refresh token.
+    pub fn on_refresh(mut self, on_refresh: impl Fn(&T) + Send + Sync + 'static) -> Self {
+        self.on_refresh = Some(Box::new(on_refresh));
+        self
+    }
+
+    /// Get the current token
+    pub fn token(&self) -> Arc<T> { self.state.lock().expect("poisoned").current.clone() }
+
+    /// Refresh the token, if `stale` is the access token of the current token.
+    ///
+    /// If `stale` has already been replaced, the current token is returned without refreshing it again.
+    /// Returns `None` if `stale` is not a token of this refresher.
+    pub async fn refresh<C>(
+        &self,
+        client: &C,
+        stale: &AccessToken,
+    ) -> Result<Option<Arc<T>>, RefreshTokenError<<C as twitch_oauth2::client::Client>::Error>>
+    where
+        C: twitch_oauth2::client::Client + Sync,
+    {
+        if let Some(replaced) = self.replaced(stale) {
+            return Ok(replaced);
+        }
+        let _refreshing = self.refreshing.lock().await;
+        // Another task might have refreshed the token while we were waiting
+        if let Some(replaced) = self.replaced(stale) {
+            return Ok(replaced);
+        }
+        let mut token = T::clone(&self.token());
+        token.refresh_token(client).await?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(expires_in = ?token.expires_in(), "refreshed token");
+        if let Some(on_refresh) = &self.on_refresh {
+            on_refresh(&token);
+        }
+        let token = Arc::new(token);
+        let mut state = self.state.lock().expect("poisoned");
+        state.previous = Some(stale.secret().to_owned());
+        state.current = token.clone();
+        Ok(Some(token))
+    }
+
+    /// Check if `stale` is not the current token.
+    ///
+    /// Returns `Some(None)` if the token is unknown, `Some(Some(current))` if it was replaced and `None` if it is the current token.
+    #[allow(clippy::option_option)]
+    fn replaced(&self, stale: &AccessToken) -> Option<Option<Arc<T>>> {
+        let state = self.state.lock().expect("poisoned");
+        if state.current.token().secret() == stale.secret() {
+            None
+        } else if state.previous.as_deref() == Some(stale.secret()) {
+            Some(Some(state.current.clone()))
+        } else {
+            Some(None)
+        }
+    }
+}
+
+/// Type erased [`TokenRefresher`], stored in [`HelixClient`](super::HelixClient)
+pub(crate) trait Refresh<C>: Send + Sync {
+    /// Refresh the token if `stale` belongs to this refresher, returning the new access token.
+    #[allow(clippy::type_complexity)]
+    fn refresh_stale<'s>(
+        &'s self,
+        client: &'s super::HelixClient<'_, C>,
+        stale: &'s AccessToken,
+    ) -> BoxedFuture<'s, Result<Option<AccessToken>, RefreshTokenError<CompatError<C::Error>>>>
+    where
+        C: crate::HttpClient;
+}
+
+impl<C, T> Refresh<C> for TokenRefresher<T>
+where
+    C: crate::HttpClient + Sync + 'static,
+    T: TwitchToken + Clone + Send + Sync + 'static,
+{
+    fn refresh_stale<'s>(
+        &'s self,
+        client: &'s super::HelixClient<'_, C>,
+        stale: &'s AccessToken,
+    ) -> BoxedFuture<'s, Result<Option<AccessToken>, RefreshTokenError<CompatError<C::Error>>>>
+    {
+        Box::pin(async move {
+            Ok(self
+                .refresh(client, stale)
+                .await?
+                .map(|token| token.token().clone()))
+        })
+    }
+}
+
+/// Check if a `401 Unauthorized` response can be fixed by refreshing the token.
+pub(crate) fn is_refreshable(response: &crate::client::Response) -> bool {
+    if response.status() != http::StatusCode::UNAUTHORIZED {
+        return false;
+    }
+    let message = std::str::from_utf8(response.body())
+        .ok()
+        .and_then(|body| crate::parse_json::<crate::helix::HelixRequestError>(body, false).ok())
+        .map(|error| error.message)
+        .unwrap_or_default();
+    crate::helix::HelixErrorKind::classify(response.status(), &message, None)
+        == crate::helix::HelixErrorKind::InvalidToken
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::MockClient;
+
+    #[tokio::test]
+    async fn refreshes_on_unauthorized() {
+        let client = MockClient::helix([
+            (
+                401,
+                r#"{"error":"Unauthorized","status":401,"message":"Invalid OAuth token"}"#,
+            ),
+            (
+                200,
+                r#"{"access_token":"newtoken","refresh_token":"newrefreshtoken","expires_in":14400,"scope":[],"token_type":"bearer"}"#,
+            ),
+            (
+                200,
+                r#"{"data":[{"id":"1337","login":"justintv","display_name":"justintv","type":"","broadcaster_type":"","description":"","profile_image_url":"","offline_image_url":"","view_count":0,"created_at":"2007-05-22T10:37:47Z"}]}"#,
+            ),
+        ]);
+        let token = twitch_oauth2::UserToken::from_existing_unchecked(
+            AccessToken::new("expiredtoken".to_string()),
+            Some(twitch_oauth2::RefreshToken::new("refreshtoken".to_string())),
+            twitch_oauth2::ClientId::new("validclientid".to_string()),
+            Some(twitch_oauth2::ClientSecret::new("secret".to_string())),
+            "justintv".into(),
+            "1337".into(),
+            None,
+            None,
+        );
+        let refreshed = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let refresher = Arc::new(TokenRefresher::new(token).on_refresh({
+            let refreshed = refreshed.clone();
+            move |_| {
+                refreshed.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            }
+        }));
+        let client = client.with_token_refresher(refresher.clone());
+
+        let stale = refresher.token();
+        let ids: &[&crate::types::UserIdRef] = &["1337".into()];
+        let req = crate::helix::users::GetUsersRequest::ids(ids);
+        let response = client.req_get(req, &*stale).await.unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(refresher.token().token().secret(), "newtoken");
+        assert_eq!(refreshed.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(client.get_client().requests.lock().unwrap().len(), 3);
+
+        // The stale token was already replaced, so this doesn't refresh again
+        let current = refresher
+            .refresh(&client, stale.token())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(current.token().secret(), "newtoken");
+        assert_eq!(refreshed.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,272 @@
+//! Retrying of failed requests for [`HelixClient`](super::HelixClient)
+//!
+//! Helix occasionally responds with `429 Too Many Requests`, `500 Internal Server Error`, `502 Bad Gateway` or
+//! `503 Service Unavailable`, and connections can fail. These failures are usually transient,
+//! and sending the same request again after a short delay will succeed.
+//!
+//! A [`RetryPolicy`] decides if and when a request should be sent again. By default,
+//! only idempotent requests (`GET`, `PUT` and `DELETE`) are retried, other endpoints need to opt in with [`RetryPolicy::retry_non_idempotent`]
+//! or [`RetryPolicy::retry_non_idempotent_patch`].
+//!
+//! The number of attempts that were made for a request is available in [`Response::attempts`](crate::helix::Response::attempts).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use twitch_api::helix::{chat::SendChatMessageRequest, HelixClient, RetryPolicy};
+//! # pub mod reqwest {pub type Client = twitch_api::client::DummyHttpClient;}
+//! let policy = RetryPolicy::new()
+//!     .max_attempts(5)
+//!     .retry_non_idempotent::<SendChatMessageRequest>();
+//! let client: HelixClient<reqwest::Client> =
+//!     HelixClient::new().with_retry_policy(policy);
+//! ```
+use std::{
+    collections::HashSet,
+    convert::TryFrom,
+    time::{Duration, SystemTime},
+};
+
+use super::ratelimit::Bucket;
+use crate::helix::{RequestPatch, RequestPost};
+
+/// Policy for retrying requests that failed in a way that is likely to be transient.
+///
+/// Delays between attempts grow exponentially, starting at [`base_delay`](RetryPolicy::base_delay)
+/// and capped at [`max_delay`](RetryPolicy::max_delay), with random jitter applied to avoid many clients retrying at the same time.
+/// When Helix responds with `429 Too Many Requests`, the request is instead delayed until the time given in the `Ratelimit-Reset` header.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: usize,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    non_idempotent: HashSet<(&'static str, http::Method)>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            non_idempotent: HashSet::new(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a new retry policy, making at most 3 attempts with a base delay of 500 milliseconds.
+    pub fn new() -> Self { Self::default() }
+
+    /// Set the maximum amount of attempts made for a request, including the first one.
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the delay before the first retry.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set the maximum delay between two attempts.
+    ///
+    /// This does not apply to delays given by the `Ratelimit-Reset` header.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Enable or disable random jitter on the delays. Enabled by default.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Allow retrying `POST` requests to the endpoint `R`, even if it's not idempotent.
+    ///
+    /// Only opt in to this for endpoints where sending the request twice is harmless to you,
+    /// e.g [Send Chat Message](crate::helix::chat::SendChatMessageRequest) where a duplicated message is better than no message.
+    /// Other methods on the same path are not affected.
+    pub fn retry_non_idempotent<R: RequestPost>(mut self) -> Self {
+        self.non_idempotent.insert((R::PATH, http::Method::POST));
+        self
+    }
+
+    /// Allow retrying `PATCH` requests to the endpoint `R`, even if it's not idempotent.
+    ///
+    /// See [`retry_non_idempotent`](RetryPolicy::retry_non_idempotent)
+    pub fn retry_non_idempotent_patch<R: RequestPatch>(mut self) -> Self {
+        self.non_idempotent.insert((R::PATH, http::Method::PATCH));
+        self
+    }
+
+    /// Get the maximum amount of attempts made for a request
+    pub fn get_max_attempts(&self) -> usize { self.max_attempts }
+
+    /// Check if a request with this method to the given endpoint path may be retried.
+    pub fn is_retryable_request(&self, method: &http::Method, path: &str) -> bool {
+        matches!(
+            *method,
+            http::Method::GET | http::Method::HEAD | http::Method::PUT | http::Method::DELETE
+        ) || self
+            .non_idempotent
+            .iter()
+            .any(|(p, m)| *p == path && m == method)
+    }
+
+    /// Check if a response status is likely to be transient.
+    pub fn is_retryable_status(status: http::StatusCode) -> bool {
+        matches!(
+            status,
+            http::StatusCode::TOO_MANY_REQUESTS
+                | http::StatusCode::INTERNAL_SERVER_ERROR
+                | http::StatusCode::BAD_GATEWAY
+                | http::StatusCode::SERVICE_UNAVAILABLE
+        )
+    }
+
+    /// Get the delay before the next attempt, or [`None`] if the result should not be retried.
+    ///
+    /// `attempt` is the amount of attempts made so far.
+    /// Errors from the [`HttpClient`](crate::HttpClient) are assumed to be connection errors and are always retried.
+    pub fn retry_after<E>(
+        &self,
+        attempt: usize,
+        result: &Result<crate::client::Response, E>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        match result {
+            Ok(response) if response.status() == http::StatusCode::TOO_MANY_REQUESTS => Some(
+                Bucket::from_headers(response.headers())
+                    .and_then(|b| b.reset_in(SystemTime::now()))
+                    .unwrap_or_else(|| self.backoff(attempt)),
+            ),
+            Ok(response) if Self::is_retryable_status(response.status()) => {
+                Some(self.backoff(attempt))
+            }
+            Ok(_) => None,
+            Err(_) => Some(self.backoff(attempt)),
+        }
+    }
+
+    /// Get the exponential backoff after `attempt` attempts have been made.
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let exp = u32::try_from(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+        let delay = self
+            .base_delay
+            .checked_mul(2u32.saturating_pow(exp))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        if self.jitter {
+            // "Equal jitter", wait at least half of the delay.
+            delay / 2 + delay.mul_f64(random_fraction() / 2.0)
+        } else {
+            delay
+        }
+    }
+}
+
+/// A random number in `0.0..=1.0`, good enough for jitter.
+fn random_fraction() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+    hasher.finish() as f64 / u64::MAX as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helix::Request;
+
+    fn response(status: u16) -> Result<crate::client::Response, ()> {
+        Ok(http::Response::builder()
+            .status(status)
+            .body(Default::default())
+            .unwrap())
+    }
+
+    #[test]
+    fn backoff_is_exponential() {
+        let policy = RetryPolicy::new()
+            .jitter(false)
+            .base_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(5));
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(4), Duration::from_secs(5));
+        assert_eq!(policy.backoff(100), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn jitter_is_bounded() {
+        let policy = RetryPolicy::new().base_delay(Duration::from_secs(2));
+        for _ in 0..100 {
+            let delay = policy.backoff(1);
+            assert!(delay >= Duration::from_secs(1) && delay <= Duration::from_secs(2));
+        }
+    }
+
+    #[test]
+    fn retries_transient_failures() {
+        let policy = RetryPolicy::new().max_attempts(2);
+        assert!(policy.retry_after(1, &response(503)).is_some());
+        assert!(policy
+            .retry_after(1, &Err::<crate::client::Response, _>(()))
+            .is_some());
+        assert!(policy.retry_after(1, &response(400)).is_none());
+        assert!(policy.retry_after(1, &response(200)).is_none());
+        assert!(policy.retry_after(2, &response(503)).is_none());
+    }
+
+    #[test]
+    fn honours_ratelimit_reset() {
+        let reset = SystemTime::now() + Duration::from_secs(10);
+        let reset = reset
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let response: Result<_, ()> = Ok(http::Response::builder()
+            .status(429)
+            .header("Ratelimit-Limit", 800)
+            .header("Ratelimit-Remaining", 0)
+            .header("Ratelimit-Reset", reset)
+            .body(Default::default())
+            .unwrap());
+        let delay = RetryPolicy::new().retry_after(1, &response).unwrap();
+        assert!(delay > Duration::from_secs(5) && delay <= Duration::from_secs(10));
+    }
+
+    #[test]
+    fn only_idempotent_by_default() {
+        use crate::helix::chat::SendChatMessageRequest;
+
+        let policy = RetryPolicy::new();
+        assert!(policy.is_retryable_request(&http::Method::GET, "users"));
+        assert!(!policy.is_retryable_request(&http::Method::POST, SendChatMessageRequest::PATH));
+        let policy = policy.retry_non_idempotent::<SendChatMessageRequest>();
+        assert!(policy.is_retryable_request(&http::Method::POST, SendChatMessageRequest::PATH));
+    }
+
+    #[test]
+    fn opt_in_is_per_method() {
+        use crate::helix::moderation::BanUserRequest;
+
+        let policy = RetryPolicy::new().retry_non_idempotent::<BanUserRequest>();
+        assert!(policy.is_retryable_request(&http::Method::POST, BanUserRequest::PATH));
+        assert!(!policy.is_retryable_request(&http::Method::PATCH, BanUserRequest::PATH));
+    }
+}
@@ -0,0 +1,209 @@
+//! Gets an analytics report for one or more extensions.
+//! [`get-extension-analytics`](https://dev.twitch.tv/docs/api/reference#get-extension-analytics)
+//!
+//! The response contains the URLs used to download the reports (CSV files).
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetExtensionAnalyticsRequest]
+//!
+//! To use this endpoint, construct a [`GetExtensionAnalyticsRequest`] with the [`GetExtensionAnalyticsRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::analytics::get_extension_analytics;
+//! let request = get_extension_analytics::GetExtensionAnalyticsRequest::new()
+//!     .extension_id("efgh");
+//! ```
+//!
+//! ## Response: [ExtensionAnalytics]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, analytics::get_extension_analytics};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_extension_analytics::GetExtensionAnalyticsRequest::new()
+//!     .extension_id("efgh");
+//! let response: Vec<get_extension_analytics::ExtensionAnalytics> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetExtensionAnalyticsRequest::parse_response(None, &request.get_uri(), response)`](GetExtensionAnalyticsRequest::parse_response)
+
+use super::*;
+use helix::{PaginationState, RequestGet};
+
+/// Query Parameters for [Get Extension Analytics](super::get_extension_analytics)
+///
+/// [`get-extension-analytics`](https://dev.twitch.tv/docs/api/reference#get-extension-analytics)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct GetExtensionAnalyticsRequest<'a> {
+    /// The extension’s client ID. If specified, the response contains a report for the specified extension. If not specified, the response includes a report for each extension that the authenticated user owns.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub extension_id: Option<Cow<'a, types::ExtensionIdRef>>,
+    /// The type of analytics report to get.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[serde(rename = "type")]
+    pub type_: Option<AnalyticsType>,
+    /// The reporting window’s start date, in RFC3339 format. Set the time portion to zeroes (for example, 2021-10-22T00:00:00Z).
+    ///
+    /// If you specify a start date, you must specify an end date.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub started_at: Option<Cow<'a, types::TimestampRef>>,
+    /// The reporting window’s end date, in RFC3339 format. Set the time portion to zeroes (for example, 2021-10-27T00:00:00Z). The report is inclusive of the end date.
+    ///
+    /// Specify an end date only if you provide a start date.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub ended_at: Option<Cow<'a, types::TimestampRef>>,
+    /// The maximum number of report URLs to return per page in the response. The minimum page size is 1 URL per page and the maximum is 100 URLs per page. The default is 20.
+    ///
+    /// This field is ignored if `extension_id` is specified.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    pub first: Option<usize>,
+    /// The cursor used to get the next page of results. This field is ignored if `extension_id` is specified.
+    #[cfg_attr(feature = "typed-builder", builder(default))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub after: Option<Cow<'a, helix::CursorRef>>,
+}
+
+impl<'a> GetExtensionAnalyticsRequest<'a> {
+    /// Get analytics reports for all extensions the authenticated user owns
+    pub fn new() -> Self { Self::default() }
+
+    /// Only get the report for this extension
+    pub fn extension_id(
+        mut self,
+        extension_id: impl types::IntoCow<'a, types::ExtensionIdRef> + 'a,
+    ) -> Self {
+        self.extension_id = Some(extension_id.into_cow());
+        self
+    }
+
+    /// Set the type of report to get
+    pub fn type_(mut self, type_: AnalyticsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Set the reporting window’s start date
+    pub fn started_at(
+        mut self,
+        started_at: impl types::IntoCow<'a, types::TimestampRef> + 'a,
+    ) -> Self {
+        self.started_at = Some(started_at.into_cow());
+        self
+    }
+
+    /// Set the reporting window’s end date
+    pub fn ended_at(mut self, ended_at: impl types::IntoCow<'a, types::TimestampRef> + 'a) -> Self {
+        self.ended_at = Some(ended_at.into_cow());
+        self
+    }
+
+    /// Set amount of results returned per page.
+    pub const fn first(mut self, first: usize) -> Self {
+        self.first = Some(first);
+        self
+    }
+}
+
+/// Return Values for [Get Extension Analytics](super::get_extension_analytics)
+///
+/// [`get-extension-analytics`](https://dev.twitch.tv/docs/api/reference#get-extension-analytics)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionAnalytics {
+    /// An ID that identifies the extension that the report was generated for.
+    pub extension_id: types::ExtensionId,
+    /// The URL that you use to download the report. The URL is valid for 5 minutes.
+    #[serde(rename = "URL")]
+    pub url: String,
+    /// The type of report.
+    #[serde(rename = "type")]
+    pub type_: AnalyticsType,
+    /// The reporting window’s start and end dates, in RFC3339 format.
+    pub date_range: DateRange,
+}
+
+impl Request for GetExtensionAnalyticsRequest<'_> {
+    type PaginationData = PaginationState<Self>;
+    type Response = Vec<ExtensionAnalytics>;
+
+    const PATH: &'static str = "analytics/extensions";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator =
+        twitch_oauth2::validator![twitch_oauth2::Scope::AnalyticsReadExtensions];
+}
+
+impl RequestGet for GetExtensionAnalyticsRequest<'_> {}
+
+impl helix::Paginated for GetExtensionAnalyticsRequest<'_> {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) {
+        self.after = cursor.map(|c| c.into_cow())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use std::convert::TryFrom;
+
+    use helix::*;
+
+    let start = types::Timestamp::try_from("2018-03-01T00:00:00Z").unwrap();
+    let end = types::Timestamp::try_from("2018-06-01T00:00:00Z").unwrap();
+    let req = GetExtensionAnalyticsRequest::new()
+        .extension_id("efgh")
+        .type_(AnalyticsType::OverviewV2)
+        .started_at(start)
+        .ended_at(end);
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "extension_id": "efgh",
+                "URL": "https://twitch-piper-reports.s3-us-west-2.amazonaws.com/dynamic/LoL%20ADC...",
+                "type": "overview_v2",
+                "date_range": {
+                    "started_at": "2018-03-01T00:00:00Z",
+                    "ended_at": "2018-06-01T00:00:00Z"
+                }
+            }
+        ],
+        "pagination": {"cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6MX19"}
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/analytics/extensions?extension_id=efgh&type=overview_v2&started_at=2018-03-01T00%3A00%3A00Z&ended_at=2018-06-01T00%3A00%3A00Z"
+    );
+
+    let res = GetExtensionAnalyticsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data[0].type_, AnalyticsType::OverviewV2);
+    assert_eq!(
+        res.data[0].date_range.ended_at.as_str(),
+        "2018-06-01T00:00:00Z"
+    );
+    assert!(res.pagination_data.cursor.is_some());
+}
@@ -0,0 +1,55 @@
+//! Helix endpoints regarding analytics
+//!
+//! The reports are CSV files that can be downloaded from the returned URLs, which expire after 5 minutes.
+//!
+//! # Implemented endpoints
+//!
+//! <!-- generate with "cargo xtask overview" (with a nightly toolchain) -->
+//! <!-- BEGIN-OVERVIEW -->
+//! <details open><summary style="cursor: pointer">Analytics 🟢 2/2</summary>
+//!
+//! | Endpoint | Helper | Module |
+//! |---|---|---|
+//! | [Get Extension Analytics](https://dev.twitch.tv/docs/api/reference#get-extension-analytics) | [`HelixClient::get_extension_analytics`](crate::helix::HelixClient::get_extension_analytics) | [`get_extension_analytics`] |
+//! | [Get Game Analytics](https://dev.twitch.tv/docs/api/reference#get-game-analytics) | [`HelixClient::get_game_analytics`](crate::helix::HelixClient::get_game_analytics) | [`get_game_analytics`] |
+//!
+//! </details>
+//!
+//! <!-- END-OVERVIEW -->
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde_derive::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+pub mod get_extension_analytics;
+pub mod get_game_analytics;
+
+#[doc(inline)]
+pub use get_extension_analytics::{ExtensionAnalytics, GetExtensionAnalyticsRequest};
+#[doc(inline)]
+pub use get_game_analytics::{GameAnalytics, GetGameAnalyticsRequest};
+
+/// The type of an analytics report
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum AnalyticsType {
+    /// The overview report, version 2
+    #[serde(rename = "overview_v2")]
+    OverviewV2,
+    /// An unknown report type, contains the raw string provided by Twitch.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The reporting window of an analytics report
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct DateRange {
+    /// The reporting window’s start date.
+    pub started_at: types::Timestamp,
+    /// The reporting window’s end date.
+    pub ended_at: types::Timestamp,
+}
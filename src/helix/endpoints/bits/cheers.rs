@@ -0,0 +1,175 @@
+//! Finding cheers in chat messages.
+//!
+//! Messages from EventSub already contain [cheermote fragments](crate::common::chat::Fragment::Cheermote),
+//! but messages from IRC or logs are plain text. A [`CheerParser`] finds the cheers in such text using the [cheermotes](Cheermote) of a channel.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api::{client, helix};
+//! use twitch_api::{
+//!     common::chat::render::{HtmlRenderer, RenderMessage},
+//!     helix::bits::{CheerParser, GetCheermotesRequest},
+//! };
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let cheermotes = client
+//!     .req_get(GetCheermotesRequest::broadcaster_id("1234"), &token)
+//!     .await?
+//!     .data;
+//! let parsed = CheerParser::new(&cheermotes).parse("Cheer100 great stream! Cheer50");
+//! assert_eq!(parsed.total_bits, 150);
+//! println!(
+//!     "{}",
+//!     parsed
+//!         .message
+//!         .to_html(&HtmlRenderer::new().cheermotes(&cheermotes))
+//! );
+//! # Ok(())
+//! # }
+//! ```
+use std::convert::TryFrom;
+
+use super::get_cheermotes::{Cheermote, Tiers};
+use crate::common::chat::{self, Fragment};
+
+/// Finds cheers in text.
+///
+/// A cheer is a word made of the prefix of a cheermote, ignoring case, followed by the amount of bits, like `Cheer100`.
+///
+/// See the [module level documentation](self)
+#[derive(Clone, Copy, Debug)]
+pub struct CheerParser<'a> {
+    cheermotes: &'a [Cheermote],
+}
+
+/// A cheer found by a [`CheerParser`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Cheer<'a> {
+    /// The cheermote used
+    pub cheermote: &'a Cheermote,
+    /// The tier of the cheermote for the amount of bits, use it for the [color](Tiers::color) and [image](Tiers::image_url) of the cheer
+    pub tier: &'a Tiers,
+    /// The amount of bits cheered
+    pub bits: i32,
+}
+
+/// Text parsed by a [`CheerParser`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParsedCheers<'a> {
+    /// The text, split into [text](Fragment::Text) and [cheermote](Fragment::Cheermote) fragments
+    pub message: chat::Message,
+    /// The cheers in the text, in the same order as the cheermote fragments
+    pub cheers: Vec<Cheer<'a>>,
+    /// Sum of the bits of all cheers
+    pub total_bits: i64,
+}
+
+impl<'a> CheerParser<'a> {
+    /// Create a parser for these cheermotes, as returned by [Get Cheermotes](super::get_cheermotes)
+    pub fn new(cheermotes: &'a [Cheermote]) -> Self { Self { cheermotes } }
+
+    /// Split `text` into text and cheers
+    pub fn parse(&self, text: &str) -> ParsedCheers<'a> {
+        let mut fragments = vec![];
+        let mut cheers = vec![];
+        // start of the text not yet added to a fragment
+        let mut start = 0;
+        let mut offset = 0;
+        for piece in text.split_inclusive(char::is_whitespace) {
+            let word = piece.trim_end_matches(char::is_whitespace);
+            if let Some(cheer) = self.cheer(word) {
+                if start < offset {
+                    fragments.push(Fragment::Text {
+                        text: text[start..offset].to_owned(),
+                    });
+                }
+                fragments.push(Fragment::Cheermote {
+                    text: word.to_owned(),
+                    cheermote: chat::Cheermote {
+                        prefix: word[..word.len() - digits(word)].to_owned(),
+                        bits: cheer.bits,
+                        tier: i32::try_from(cheer.tier.min_bits).unwrap_or(i32::MAX),
+                    },
+                });
+                cheers.push(cheer);
+                start = offset + word.len();
+            }
+            offset += piece.len();
+        }
+        if start < text.len() {
+            fragments.push(Fragment::Text {
+                text: text[start..].to_owned(),
+            });
+        }
+        ParsedCheers {
+            message: chat::Message {
+                text: text.to_owned(),
+                fragments,
+            },
+            total_bits: cheers.iter().map(|cheer| i64::from(cheer.bits)).sum(),
+            cheers,
+        }
+    }
+
+    /// Parse a single word as a cheer
+    fn cheer(&self, word: &str) -> Option<Cheer<'a>> {
+        let digits = digits(word);
+        if digits == 0 || digits == word.len() {
+            return None;
+        }
+        let (prefix, bits) = word.split_at(word.len() - digits);
+        let bits: i32 = bits.parse().ok().filter(|bits| *bits > 0)?;
+        let cheermote = self
+            .cheermotes
+            .iter()
+            .find(|cheermote| cheermote.prefix.eq_ignore_ascii_case(prefix))?;
+        Some(Cheer {
+            cheermote,
+            tier: cheermote.tier(bits.into())?,
+            bits,
+        })
+    }
+}
+
+/// Length of the trailing digits of `word`
+fn digits(word: &str) -> usize {
+    word.len() - word.trim_end_matches(|c: char| c.is_ascii_digit()).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_cheers() {
+        let cheermotes: Vec<Cheermote> = serde_json::from_str(
+            r##"[{"prefix":"Cheer","tiers":[{"min_bits":1,"id":"1","color":"#979797","images":{"dark":{"animated":{"1":"dark/animated/1/1.gif","1.5":"dark/animated/1/1.5.gif","2":"dark/animated/1/2.gif","3":"dark/animated/1/3.gif","4":"dark/animated/1/4.gif"},"static":{"1":"dark/static/1/1.png","1.5":"dark/static/1/1.5.png","2":"dark/static/1/2.png","3":"dark/static/1/3.png","4":"dark/static/1/4.png"}},"light":{"animated":{"1":"light/animated/1/1.gif","1.5":"light/animated/1/1.5.gif","2":"light/animated/1/2.gif","3":"light/animated/1/3.gif","4":"light/animated/1/4.gif"},"static":{"1":"light/static/1/1.png","1.5":"light/static/1/1.5.png","2":"light/static/1/2.png","3":"light/static/1/3.png","4":"light/static/1/4.png"}}},"can_cheer":true,"show_in_bits_card":true},{"min_bits":100,"id":"100","color":"#9c3ee8","images":{"dark":{"animated":{"1":"dark/animated/100/1.gif","1.5":"dark/animated/100/1.5.gif","2":"dark/animated/100/2.gif","3":"dark/animated/100/3.gif","4":"dark/animated/100/4.gif"},"static":{"1":"dark/static/100/1.png","1.5":"dark/static/100/1.5.png","2":"dark/static/100/2.png","3":"dark/static/100/3.png","4":"dark/static/100/4.png"}},"light":{"animated":{"1":"light/animated/100/1.gif","1.5":"light/animated/100/1.5.gif","2":"light/animated/100/2.gif","3":"light/animated/100/3.gif","4":"light/animated/100/4.gif"},"static":{"1":"light/static/100/1.png","1.5":"light/static/100/1.5.png","2":"light/static/100/2.png","3":"light/static/100/3.png","4":"light/static/100/4.png"}}},"can_cheer":true,"show_in_bits_card":true}],"type":"global_first_party","order":1,"last_updated":"2018-05-22T00:06:04Z","is_charitable":false}]"##,
+        )
+        .unwrap();
+        let parsed =
+            CheerParser::new(&cheermotes).parse("Cheer100 hello  cheer1 Kappa5 cheer0 cheerx1");
+        assert_eq!(parsed.total_bits, 101);
+        assert_eq!(parsed.cheers.len(), 2);
+        assert_eq!(parsed.cheers[0].tier.color, "#9c3ee8");
+        assert_eq!(parsed.cheers[1].tier.color, "#979797");
+        assert_eq!(
+            parsed
+                .message
+                .fragments
+                .iter()
+                .map(Fragment::text)
+                .collect::<Vec<_>>(),
+            ["Cheer100", " hello  ", "cheer1", " Kappa5 cheer0 cheerx1"]
+        );
+        assert!(matches!(
+            &parsed.message.fragments[2],
+            Fragment::Cheermote { cheermote, .. } if cheermote.prefix == "cheer" && cheermote.bits == 1 && cheermote.tier == 1
+        ));
+    }
+}
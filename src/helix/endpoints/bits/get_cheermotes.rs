@@ -36,6 +36,7 @@
 //! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
 //! and parse the [`http::Response`] with [`GetCheermotesRequest::parse_response(None, &request.get_uri(), response)`](GetCheermotesRequest::parse_response)
 use super::*;
+use crate::common::chat::render::{Scale, Theme};
 use helix::RequestGet;
 
 /// Query Parameters for [Get Cheermotes](super::get_cheermotes)
@@ -93,6 +94,16 @@ pub struct Cheermote {
     pub type_: CheermoteType,
 }
 
+impl Cheermote {
+    /// Get the tier used when cheering `bits` bits, e.g the tier with the highest [`min_bits`](Tiers::min_bits) not above `bits`.
+    pub fn tier(&self, bits: i64) -> Option<&Tiers> {
+        self.tiers
+            .iter()
+            .filter(|tier| tier.min_bits <= bits)
+            .max_by_key(|tier| tier.min_bits)
+    }
+}
+
 /// Types of cheermotes
 #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
@@ -134,6 +145,28 @@ pub struct Tiers {
     pub show_in_bits_card: bool,
 }
 
+impl Tiers {
+    /// Get the URL of the image of this tier.
+    ///
+    /// The [small](Scale::Small), [medium](Scale::Medium) and [large](Scale::Large) images are 28 × 28, 56 × 56 and 112 × 112.
+    pub fn image_url(&self, theme: Theme, scale: Scale, animated: bool) -> &str {
+        let images = match theme {
+            Theme::Dark => &self.images.dark,
+            Theme::Light => &self.images.light,
+        };
+        let images = if animated {
+            &images.animated
+        } else {
+            &images.static_
+        };
+        match scale {
+            Scale::Small => &images.url_1x,
+            Scale::Medium => &images.url_2x,
+            Scale::Large => &images.url_4x,
+        }
+    }
+}
+
 /// Images for different themes
 #[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
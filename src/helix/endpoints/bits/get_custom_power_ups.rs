@@ -0,0 +1,166 @@
+//! Gets a list of custom Power-ups that the specified broadcaster created.
+//! [`get-custom-power-up`](https://dev.twitch.tv/docs/api/reference#get-custom-power-up)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetCustomPowerUpsRequest]
+//!
+//! To use this endpoint, construct a [`GetCustomPowerUpsRequest`] with the [`GetCustomPowerUpsRequest::broadcaster_id()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::bits::get_custom_power_ups;
+//! let request =
+//!     get_custom_power_ups::GetCustomPowerUpsRequest::broadcaster_id(
+//!         "274637212",
+//!     );
+//! ```
+//!
+//! ## Response: [CustomPowerUp]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, bits::get_custom_power_ups};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_custom_power_ups::GetCustomPowerUpsRequest::broadcaster_id("274637212");
+//! let response: Vec<get_custom_power_ups::CustomPowerUp> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetCustomPowerUpsRequest::parse_response(None, &request.get_uri(), response)`](GetCustomPowerUpsRequest::parse_response)
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Custom Power-up](super::get_custom_power_ups)
+///
+/// [`get-custom-power-up`](https://dev.twitch.tv/docs/api/reference#get-custom-power-up)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct GetCustomPowerUpsRequest<'a> {
+    /// The ID of the broadcaster whose custom Power-ups you want to get. This ID must match the user ID found in the OAuth token.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub broadcaster_id: Cow<'a, types::UserIdRef>,
+    /// A list of IDs to filter the custom Power-ups by. You may specify a maximum of 50 IDs.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    // FIXME: This is essentially the same as borrow, but worse
+    #[cfg_attr(not(feature = "deser_borrow"), serde(bound(deserialize = "'de: 'a")))]
+    pub id: types::Collection<'a, types::RewardId>,
+}
+
+impl<'a> GetCustomPowerUpsRequest<'a> {
+    /// Get the custom Power-ups of this broadcaster
+    pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
+        Self {
+            broadcaster_id: broadcaster_id.into_cow(),
+            id: types::Collection::default(),
+        }
+    }
+
+    /// Get custom Power-ups with these ids. Maximum 50
+    pub fn ids(mut self, id: impl Into<types::Collection<'a, types::RewardId>>) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+/// Return Values for [Get Custom Power-up](super::get_custom_power_ups)
+///
+/// [`get-custom-power-up`](https://dev.twitch.tv/docs/api/reference#get-custom-power-up)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct CustomPowerUp {
+    /// ID of the channel the custom Power-up is for
+    pub broadcaster_id: types::UserId,
+    /// Login of the channel the custom Power-up is for
+    pub broadcaster_login: types::UserName,
+    /// Display name of the channel the custom Power-up is for
+    pub broadcaster_name: types::DisplayName,
+    /// ID of the custom Power-up
+    pub id: types::RewardId,
+    /// The title of the custom Power-up
+    pub title: String,
+    /// The prompt for the viewer when they are redeeming the custom Power-up
+    pub prompt: String,
+    /// The amount of Bits the custom Power-up costs
+    pub bits: i64,
+    /// Set of custom images of 1x, 2x and 4x sizes for the custom Power-up, can be null if no images have been uploaded
+    pub image: Option<types::Image>,
+    /// Set of default images of 1x, 2x and 4x sizes for the custom Power-up
+    pub default_image: Option<types::Image>,
+    /// Custom background color for the custom Power-up. Format: Hex with # prefix. Example: #00E5CB.
+    pub background_color: String,
+    /// Is the custom Power-up currently enabled, if false the custom Power-up won’t show up to viewers
+    pub is_enabled: bool,
+    /// Does the user need to enter information when redeeming the custom Power-up
+    pub is_user_input_required: bool,
+}
+
+impl Request for GetCustomPowerUpsRequest<'_> {
+    type PaginationData = ();
+    type Response = Vec<CustomPowerUp>;
+
+    const PATH: &'static str = "bits/custom_power_ups";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator =
+        twitch_oauth2::validator![twitch_oauth2::Scope::BitsRead];
+}
+
+impl RequestGet for GetCustomPowerUpsRequest<'_> {}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetCustomPowerUpsRequest::broadcaster_id("274637212")
+        .ids(vec!["92af127c-7326-4483-a52b-b0da0be61c01"]);
+
+    let data = br##"
+    {
+        "data": [
+            {
+                "broadcaster_id": "274637212",
+                "broadcaster_login": "torpedo09",
+                "broadcaster_name": "torpedo09",
+                "id": "92af127c-7326-4483-a52b-b0da0be61c01",
+                "title": "Hydrate!",
+                "prompt": "The streamer drinks a glass of water",
+                "bits": 100,
+                "image": null,
+                "default_image": {
+                    "url_1x": "https://static-cdn.jtvnw.net/custom-reward-images/default-1.png",
+                    "url_2x": "https://static-cdn.jtvnw.net/custom-reward-images/default-2.png",
+                    "url_4x": "https://static-cdn.jtvnw.net/custom-reward-images/default-4.png"
+                },
+                "background_color": "#00E5CB",
+                "is_enabled": true,
+                "is_user_input_required": false
+            }
+        ]
+    }
+    "##
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/bits/custom_power_ups?broadcaster_id=274637212&id=92af127c-7326-4483-a52b-b0da0be61c01"
+    );
+
+    let res = GetCustomPowerUpsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data.len(), 1);
+    assert_eq!(res.data[0].bits, 100);
+}
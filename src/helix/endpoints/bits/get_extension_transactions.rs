@@ -0,0 +1,237 @@
+//! Gets an extension’s list of transactions.
+//! [`get-extension-transactions`](https://dev.twitch.tv/docs/api/reference#get-extension-transactions)
+//!
+//! A transaction records the exchange of a currency (for example, Bits) for a digital product.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetExtensionTransactionsRequest]
+//!
+//! To use this endpoint, construct a [`GetExtensionTransactionsRequest`] with the [`GetExtensionTransactionsRequest::extension_id()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::bits::get_extension_transactions;
+//! let request = get_extension_transactions::GetExtensionTransactionsRequest::extension_id(
+//!     "1234",
+//! );
+//! ```
+//!
+//! ## Response: [ExtensionTransaction]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, bits::get_extension_transactions};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_extension_transactions::GetExtensionTransactionsRequest::extension_id("1234");
+//! let response: Vec<get_extension_transactions::ExtensionTransaction> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetExtensionTransactionsRequest::parse_response(None, &request.get_uri(), response)`](GetExtensionTransactionsRequest::parse_response)
+use super::*;
+use helix::{PaginationState, RequestGet};
+
+/// Query Parameters for [Get Extension Transactions](super::get_extension_transactions)
+///
+/// [`get-extension-transactions`](https://dev.twitch.tv/docs/api/reference#get-extension-transactions)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct GetExtensionTransactionsRequest<'a> {
+    /// The ID of the extension whose list of transactions you want to get.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub extension_id: Cow<'a, types::ExtensionIdRef>,
+    /// A transaction ID used to filter the list of transactions. You may specify a maximum of 100 IDs.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    // FIXME: This is essentially the same as borrow, but worse
+    #[cfg_attr(not(feature = "deser_borrow"), serde(bound(deserialize = "'de: 'a")))]
+    pub id: types::Collection<'a, types::BitsTransactionId>,
+    /// The cursor used to get the next page of results.
+    #[cfg_attr(feature = "typed-builder", builder(default))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub after: Option<Cow<'a, helix::CursorRef>>,
+    /// The maximum number of items to return per page in the response. The minimum page size is 1 item per page and the maximum is 100 items per page. The default is 20.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    pub first: Option<usize>,
+}
+
+impl<'a> GetExtensionTransactionsRequest<'a> {
+    /// Get the transactions of this extension
+    pub fn extension_id(extension_id: impl types::IntoCow<'a, types::ExtensionIdRef> + 'a) -> Self {
+        Self {
+            extension_id: extension_id.into_cow(),
+            id: types::Collection::default(),
+            after: None,
+            first: None,
+        }
+    }
+
+    /// Only get transactions with these ids. Maximum 100
+    pub fn ids(mut self, id: impl Into<types::Collection<'a, types::BitsTransactionId>>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Set amount of results returned per page.
+    pub const fn first(mut self, first: usize) -> Self {
+        self.first = Some(first);
+        self
+    }
+}
+
+/// Return Values for [Get Extension Transactions](super::get_extension_transactions)
+///
+/// [`get-extension-transactions`](https://dev.twitch.tv/docs/api/reference#get-extension-transactions)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionTransaction {
+    /// An ID that identifies the transaction.
+    pub id: types::BitsTransactionId,
+    /// The UTC date and time (in RFC3339 format) of the transaction.
+    pub timestamp: types::Timestamp,
+    /// The ID of the broadcaster that owns the channel where the transaction occurred.
+    pub broadcaster_id: types::UserId,
+    /// The broadcaster’s login name.
+    pub broadcaster_login: types::UserName,
+    /// The broadcaster’s display name.
+    pub broadcaster_name: types::DisplayName,
+    /// The ID of the user that purchased the digital product.
+    pub user_id: types::UserId,
+    /// The user’s login name.
+    pub user_login: types::UserName,
+    /// The user’s display name.
+    pub user_name: types::DisplayName,
+    /// The type of transaction.
+    pub product_type: ExtensionProductType,
+    /// Contains details about the digital product.
+    pub product_data: ExtensionProductData,
+}
+
+/// The type of an [extension transaction](ExtensionTransaction)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum ExtensionProductType {
+    /// Bits spent in an extension
+    BitsInExtension,
+    /// An unknown product type, contains the raw string provided by Twitch.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The digital product of an [extension transaction](ExtensionTransaction)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ExtensionProductData {
+    /// Set to `twitch.ext.` + `<the extension's ID>`.
+    pub domain: String,
+    /// An ID that identifies the digital product.
+    pub sku: String,
+    /// Contains details about the digital product’s cost.
+    pub cost: helix::extensions::BitsProductCost,
+    /// A Boolean value that determines whether the product is in development. Is true if the digital product is in development and cannot be exchanged.
+    pub in_development: bool,
+    /// The name of the digital product.
+    pub display_name: String,
+    /// This field is always empty since you may purchase only unexpired products.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
+    pub expiration: Option<types::Timestamp>,
+    /// A Boolean value that determines whether the data was broadcast to all instances of the extension. Is true if the data was broadcast to all instances.
+    pub broadcast: bool,
+}
+
+impl Request for GetExtensionTransactionsRequest<'_> {
+    type PaginationData = PaginationState<Self>;
+    type Response = Vec<ExtensionTransaction>;
+
+    const PATH: &'static str = "extensions/transactions";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+}
+
+impl RequestGet for GetExtensionTransactionsRequest<'_> {}
+
+impl helix::Paginated for GetExtensionTransactionsRequest<'_> {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) {
+        self.after = cursor.map(|c| c.into_cow())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetExtensionTransactionsRequest::extension_id("1234")
+        .ids(vec!["74c52265-e214-48a6-91b9-23b6014e8041"]);
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "id": "74c52265-e214-48a6-91b9-23b6014e8041",
+                "timestamp": "2019-01-28T04:15:17.065Z",
+                "broadcaster_id": "439964613",
+                "broadcaster_login": "chikachi",
+                "broadcaster_name": "chikachi",
+                "user_id": "424596340",
+                "user_login": "quotrok",
+                "user_name": "quotrok",
+                "product_type": "BITS_IN_EXTENSION",
+                "product_data": {
+                    "domain": "twitch.ext.uo6dggojyb8d6soh92zknwmi5ej1q2",
+                    "sku": "testSku100",
+                    "cost": {
+                        "amount": 100,
+                        "type": "bits"
+                    },
+                    "inDevelopment": false,
+                    "displayName": "Test Product 100",
+                    "expiration": "",
+                    "broadcast": false
+                }
+            }
+        ],
+        "pagination": {
+            "cursor": "cursorString"
+        }
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/transactions?extension_id=1234&id=74c52265-e214-48a6-91b9-23b6014e8041"
+    );
+
+    let res =
+        GetExtensionTransactionsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data.len(), 1);
+    assert_eq!(
+        res.data[0].product_type,
+        ExtensionProductType::BitsInExtension
+    );
+    assert_eq!(res.data[0].product_data.cost.amount, 100);
+    assert!(res.data[0].product_data.expiration.is_none());
+    assert!(res.pagination_data.cursor.is_some());
+}
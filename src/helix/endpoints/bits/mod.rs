@@ -21,14 +21,14 @@
 //!
 //! <!-- generate with "cargo xtask overview" (with a nightly toolchain) -->
 //! <!-- BEGIN-OVERVIEW -->
-//! <details open><summary style="cursor: pointer">Bits 🟡 2/4</summary>
+//! <details open><summary style="cursor: pointer">Bits 🟢 4/4</summary>
 //!
 //! | Endpoint | Helper | Module |
 //! |---|---|---|
 //! | [Get Bits Leaderboard](https://dev.twitch.tv/docs/api/reference#get-bits-leaderboard) | - | [`get_bits_leaderboard`] |
 //! | [Get Cheermotes](https://dev.twitch.tv/docs/api/reference#get-cheermotes) | - | [`get_cheermotes`] |
-//! | [Get Custom Power-up](https://dev.twitch.tv/docs/api/reference#get-custom-power-up) | - | - |
-//! | [Get Extension Transactions](https://dev.twitch.tv/docs/api/reference#get-extension-transactions) | - | - |
+//! | [Get Custom Power-up](https://dev.twitch.tv/docs/api/reference#get-custom-power-up) | [`HelixClient::get_custom_power_ups`](crate::helix::HelixClient::get_custom_power_ups) | [`get_custom_power_ups`] |
+//! | [Get Extension Transactions](https://dev.twitch.tv/docs/api/reference#get-extension-transactions) | [`HelixClient::get_extension_transactions`](crate::helix::HelixClient::get_extension_transactions) | [`get_extension_transactions`] |
 //!
 //! </details>
 //!
@@ -41,10 +41,22 @@ use crate::{
 use serde_derive::{Deserialize, Serialize};
 use std::borrow::Cow;
 
+pub mod cheers;
 pub mod get_bits_leaderboard;
 pub mod get_cheermotes;
+pub mod get_custom_power_ups;
+pub mod get_extension_transactions;
 
+#[doc(inline)]
+pub use cheers::CheerParser;
 #[doc(inline)]
 pub use get_bits_leaderboard::{BitsLeaderboard, GetBitsLeaderboardRequest};
 #[doc(inline)]
 pub use get_cheermotes::{Cheermote, GetCheermotesRequest};
+#[doc(inline)]
+pub use get_custom_power_ups::{CustomPowerUp, GetCustomPowerUpsRequest};
+#[doc(inline)]
+pub use get_extension_transactions::{
+    ExtensionProductData, ExtensionProductType, ExtensionTransaction,
+    GetExtensionTransactionsRequest,
+};
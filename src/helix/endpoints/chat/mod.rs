@@ -51,6 +51,7 @@ pub mod get_shared_chat_session;
 pub mod get_user_chat_color;
 pub mod get_user_emotes;
 pub mod pin_chat_message;
+pub mod resolve;
 pub mod send_a_shoutout;
 pub mod send_chat_announcement;
 pub mod send_chat_message;
@@ -86,6 +87,8 @@ pub use get_user_emotes::{GetUserEmotesRequest, UserEmote};
 #[doc(inline)]
 pub use pin_chat_message::{PinChatMessageRequest, PinChatMessageResponse};
 #[doc(inline)]
+pub use resolve::{BadgeResolver, EmoteResolver};
+#[doc(inline)]
 pub use send_a_shoutout::{SendAShoutoutRequest, SendAShoutoutResponse};
 #[doc(inline)]
 pub use send_chat_announcement::{
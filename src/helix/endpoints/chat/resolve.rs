@@ -0,0 +1,440 @@
+//! Resolving emotes and badges to image URLs.
+//!
+//! An [`EmoteResolver`] builds the CDN URL of an emote from the `template` returned by the emote endpoints,
+//! and checks that the emote is available in the requested format, theme and scale.
+//! A [`BadgeResolver`] maps chat badges to their images, preferring the badges of a channel over the global badges.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api::{client, helix, types};
+//! use twitch_api::{
+//!     common::chat::render::{Scale, Theme},
+//!     helix::chat::{
+//!         resolve::{EmoteFormat, EmoteOptions},
+//!         BadgeResolver, EmoteResolver, GetChannelChatBadgesRequest,
+//!         GetGlobalChatBadgesRequest, GetGlobalEmotesRequest,
+//!     },
+//! };
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let response = client
+//!     .req_get(GetGlobalEmotesRequest::default(), &token)
+//!     .await?;
+//! let emotes = EmoteResolver::from_response(&response).with_emotes(&response.data);
+//! let url = emotes.url(
+//!     "25".into(),
+//!     &EmoteOptions::new()
+//!         .format(EmoteFormat::Static)
+//!         .theme(Theme::Light)
+//!         .scale(Scale::Large),
+//! )?;
+//!
+//! let global = client
+//!     .req_get(GetGlobalChatBadgesRequest::new(), &token)
+//!     .await?
+//!     .data;
+//! let channel = client
+//!     .req_get(GetChannelChatBadgesRequest::broadcaster_id("1234"), &token)
+//!     .await?
+//!     .data;
+//! let badges = BadgeResolver::new().with_global(&global).with_channel(&channel);
+//! let subscriber = badges.url("subscriber".into(), "12".into(), Scale::Medium);
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::HashMap;
+
+use super::{BadgeSet, ChatBadge};
+use crate::{
+    common::chat::render::{Scale, Theme},
+    helix, types,
+};
+
+/// Template used when no template is known
+const TEMPLATE: &str =
+    "https://static-cdn.jtvnw.net/emoticons/v2/{{id}}/{{format}}/{{theme_mode}}/{{scale}}";
+
+/// Format of an emote image
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EmoteFormat {
+    /// Animated if the emote is animated, static otherwise
+    #[default]
+    Default,
+    /// A static PNG
+    Static,
+    /// An animated GIF
+    Animated,
+}
+
+impl EmoteFormat {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Static => "static",
+            Self::Animated => "animated",
+        }
+    }
+}
+
+/// The image to get for an emote, see [`EmoteResolver::url`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EmoteOptions {
+    format: EmoteFormat,
+    theme: Theme,
+    scale: Scale,
+}
+
+impl EmoteOptions {
+    /// The default image of an emote, in the smallest size for a dark background
+    pub fn new() -> Self { Self::default() }
+
+    /// Set the format
+    pub fn format(mut self, format: EmoteFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Set the background theme
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Set the size, `1.0`, `2.0` or `3.0`
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+/// Errors for [`EmoteResolver::url`]
+#[derive(Debug, Clone, thiserror::Error, displaydoc::Display)]
+#[non_exhaustive]
+pub enum EmoteUrlError {
+    /// emote {0} is not known by the resolver
+    UnknownEmote(types::EmoteId),
+    /// emote {0} is not available in the format `{1}`
+    UnsupportedFormat(types::EmoteId, &'static str),
+    /// emote {0} is not available in the theme `{1}`
+    UnsupportedTheme(types::EmoteId, &'static str),
+    /// emote {0} is not available in the scale `{1}`
+    UnsupportedScale(types::EmoteId, &'static str),
+}
+
+/// Formats, themes and scales an emote is available in
+#[derive(Clone, Debug, Default)]
+struct Available {
+    format: Vec<String>,
+    theme_mode: Vec<String>,
+    scale: Vec<String>,
+}
+
+/// Builds image URLs for emotes.
+///
+/// See the [module level documentation](self)
+#[derive(Clone, Debug)]
+pub struct EmoteResolver {
+    template: String,
+    emotes: HashMap<types::EmoteId, Available>,
+}
+
+impl Default for EmoteResolver {
+    fn default() -> Self {
+        Self {
+            template: TEMPLATE.to_owned(),
+            emotes: HashMap::new(),
+        }
+    }
+}
+
+impl EmoteResolver {
+    /// Create a resolver using the current template of Twitch
+    pub fn new() -> Self { Self::default() }
+
+    /// Create a resolver using the `template` returned with a response from an emote endpoint,
+    /// like [Get Global Emotes](super::get_global_emotes) or [Get Channel Emotes](super::get_channel_emotes)
+    pub fn from_response<R, D>(response: &helix::Response<R, D>) -> Self
+    where
+        R: helix::Request,
+        D: serde::de::DeserializeOwned + PartialEq, {
+        let template = response
+            .get_other("template")
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| TEMPLATE.to_owned());
+        Self::new().template(template)
+    }
+
+    /// Set the URL template, with the placeholders `{{id}}`, `{{format}}`, `{{theme_mode}}` and `{{scale}}`
+    pub fn template(mut self, template: impl Into<String>) -> Self {
+        self.template = template.into();
+        self
+    }
+
+    /// Add emotes
+    pub fn with_emotes<'e, E: Emote + 'e>(
+        mut self,
+        emotes: impl IntoIterator<Item = &'e E>,
+    ) -> Self {
+        for emote in emotes {
+            self.insert(
+                emote.id().to_owned(),
+                emote.format(),
+                emote.theme_mode(),
+                emote.scale(),
+            );
+        }
+        self
+    }
+
+    /// Add an emote that is available in the given formats, themes and scales
+    pub fn insert(
+        &mut self,
+        id: types::EmoteId,
+        format: &[types::EmoteAnimationSetting],
+        theme_mode: &[types::EmoteThemeMode],
+        scale: &[types::EmoteScale],
+    ) {
+        self.emotes.insert(
+            id,
+            Available {
+                format: to_strings(format),
+                theme_mode: to_strings(theme_mode),
+                scale: to_strings(scale),
+            },
+        );
+    }
+
+    /// Get the URL of an emote image.
+    ///
+    /// Fails if the emote is not known, or not available as requested.
+    pub fn url(
+        &self,
+        id: &types::EmoteIdRef,
+        options: &EmoteOptions,
+    ) -> Result<String, EmoteUrlError> {
+        let available = self
+            .emotes
+            .get(id)
+            .ok_or_else(|| EmoteUrlError::UnknownEmote(id.to_owned()))?;
+        let format = options.format.as_str();
+        if options.format != EmoteFormat::Default && !available.format.iter().any(|f| f == format) {
+            return Err(EmoteUrlError::UnsupportedFormat(id.to_owned(), format));
+        }
+        let theme = match options.theme {
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+        };
+        if !available.theme_mode.iter().any(|t| t == theme) {
+            return Err(EmoteUrlError::UnsupportedTheme(id.to_owned(), theme));
+        }
+        let scale = match options.scale {
+            Scale::Small => "1.0",
+            Scale::Medium => "2.0",
+            Scale::Large => "3.0",
+        };
+        if !available.scale.iter().any(|s| s == scale) {
+            return Err(EmoteUrlError::UnsupportedScale(id.to_owned(), scale));
+        }
+        Ok(self
+            .template
+            .replace("{{id}}", id.as_str())
+            .replace("{{format}}", format)
+            .replace("{{theme_mode}}", theme)
+            .replace("{{scale}}", scale))
+    }
+}
+
+/// Get the values like they are returned by Twitch, e.g `"1.0"` for the scale
+fn to_strings<T: serde::Serialize>(values: &[T]) -> Vec<String> {
+    values
+        .iter()
+        .filter_map(|value| match serde_json::to_value(value) {
+            Ok(serde_json::Value::String(value)) => Some(value),
+            _ => None,
+        })
+        .collect()
+}
+
+/// An emote returned by Helix, with the formats, themes and scales it is available in
+pub trait Emote {
+    /// ID of the emote
+    fn id(&self) -> &types::EmoteIdRef;
+    /// The formats that the emote is available in
+    fn format(&self) -> &[types::EmoteAnimationSetting];
+    /// The background themes that the emote is available in
+    fn theme_mode(&self) -> &[types::EmoteThemeMode];
+    /// The sizes that the emote is available in
+    fn scale(&self) -> &[types::EmoteScale];
+}
+
+macro_rules! impl_emote {
+    ($($emote:ty),* $(,)?) => {
+        $(
+            impl Emote for $emote {
+                fn id(&self) -> &types::EmoteIdRef { &self.id }
+
+                fn format(&self) -> &[types::EmoteAnimationSetting] { &self.format }
+
+                fn theme_mode(&self) -> &[types::EmoteThemeMode] { &self.theme_mode }
+
+                fn scale(&self) -> &[types::EmoteScale] { &self.scale }
+            }
+        )*
+    };
+}
+
+impl_emote!(
+    super::ChannelEmote,
+    super::GlobalEmote,
+    super::get_emote_sets::Emote,
+    super::UserEmote,
+);
+
+/// Maps chat badges to their images.
+///
+/// Badges of the channel take precedence over global badges with the same set and version,
+/// for example for custom subscriber or bits badges.
+///
+/// See the [module level documentation](self)
+#[derive(Clone, Debug, Default)]
+pub struct BadgeResolver {
+    global: HashMap<types::BadgeSetId, HashMap<types::ChatBadgeId, ChatBadge>>,
+    channel: HashMap<types::BadgeSetId, HashMap<types::ChatBadgeId, ChatBadge>>,
+}
+
+impl BadgeResolver {
+    /// Create an empty resolver
+    pub fn new() -> Self { Self::default() }
+
+    /// Add global badges, as returned by [Get Global Chat Badges](super::get_global_chat_badges)
+    pub fn with_global(mut self, badges: &[BadgeSet]) -> Self {
+        insert_badges(&mut self.global, badges);
+        self
+    }
+
+    /// Add badges of the channel, as returned by [Get Channel Chat Badges](super::get_channel_chat_badges)
+    pub fn with_channel(mut self, badges: &[BadgeSet]) -> Self {
+        insert_badges(&mut self.channel, badges);
+        self
+    }
+
+    /// Get a badge
+    pub fn badge(
+        &self,
+        set_id: &types::BadgeSetIdRef,
+        id: &types::ChatBadgeIdRef,
+    ) -> Option<&ChatBadge> {
+        self.channel
+            .get(set_id)
+            .and_then(|versions| versions.get(id))
+            .or_else(|| self.global.get(set_id)?.get(id))
+    }
+
+    /// Get the URL of a badge image, [large](Scale::Large) is [`image_url_4x`](ChatBadge::image_url_4x).
+    pub fn url(
+        &self,
+        set_id: &types::BadgeSetIdRef,
+        id: &types::ChatBadgeIdRef,
+        scale: Scale,
+    ) -> Option<&str> {
+        let badge = self.badge(set_id, id)?;
+        Some(match scale {
+            Scale::Small => &badge.image_url_1x,
+            Scale::Medium => &badge.image_url_2x,
+            Scale::Large => &badge.image_url_4x,
+        })
+    }
+
+    /// Get the URLs of the badges of a chatter, skipping unknown badges.
+    #[cfg(feature = "eventsub")]
+    #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
+    pub fn urls<'b>(
+        &'b self,
+        badges: &'b [crate::eventsub::channel::chat::Badge],
+        scale: Scale,
+    ) -> impl Iterator<Item = &'b str> + 'b {
+        badges
+            .iter()
+            .filter_map(move |badge| self.url(&badge.set_id, &badge.id, scale))
+    }
+}
+
+fn insert_badges(
+    map: &mut HashMap<types::BadgeSetId, HashMap<types::ChatBadgeId, ChatBadge>>,
+    badges: &[BadgeSet],
+) {
+    for set in badges {
+        map.entry(set.set_id.clone()).or_default().extend(
+            set.versions
+                .iter()
+                .map(|badge| (badge.id.clone(), badge.clone())),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emote_url() {
+        let emotes: Vec<super::super::GlobalEmote> = serde_json::from_str(
+            r#"[{"id":"196892","name":"TwitchUnity","images":{"url_1x":"https://static-cdn.jtvnw.net/emoticons/v2/196892/static/light/1.0","url_2x":"https://static-cdn.jtvnw.net/emoticons/v2/196892/static/light/2.0","url_4x":"https://static-cdn.jtvnw.net/emoticons/v2/196892/static/light/3.0"},"format":["static"],"scale":["1.0","2.0","3.0"],"theme_mode":["light","dark"]}]"#,
+        )
+        .unwrap();
+        let resolver = EmoteResolver::new().with_emotes(&emotes);
+        assert_eq!(
+            resolver
+                .url(
+                    "196892".into(),
+                    &EmoteOptions::new()
+                        .format(EmoteFormat::Static)
+                        .theme(Theme::Light)
+                        .scale(Scale::Large)
+                )
+                .unwrap(),
+            "https://static-cdn.jtvnw.net/emoticons/v2/196892/static/light/3.0"
+        );
+        assert!(matches!(
+            resolver.url(
+                "196892".into(),
+                &EmoteOptions::new().format(EmoteFormat::Animated)
+            ),
+            Err(EmoteUrlError::UnsupportedFormat(_, "animated"))
+        ));
+        assert!(matches!(
+            resolver.url("25".into(), &EmoteOptions::new()),
+            Err(EmoteUrlError::UnknownEmote(_))
+        ));
+    }
+
+    #[test]
+    fn badge_url() {
+        let global: Vec<BadgeSet> = serde_json::from_str(
+            r#"[{"set_id":"subscriber","versions":[{"id":"0","image_url_1x":"global/1","image_url_2x":"global/2","image_url_4x":"global/3","title":"Subscriber","description":"Subscriber"}]},{"set_id":"vip","versions":[{"id":"1","image_url_1x":"vip/1","image_url_2x":"vip/2","image_url_4x":"vip/3","title":"VIP","description":"VIP"}]}]"#,
+        )
+        .unwrap();
+        let channel: Vec<BadgeSet> = serde_json::from_str(
+            r#"[{"set_id":"subscriber","versions":[{"id":"0","image_url_1x":"channel/1","image_url_2x":"channel/2","image_url_4x":"channel/3","title":"Subscriber","description":"Subscriber"}]}]"#,
+        )
+        .unwrap();
+        // the order badges are added in doesn't matter
+        let resolver = BadgeResolver::new()
+            .with_channel(&channel)
+            .with_global(&global);
+        assert_eq!(
+            resolver.url("subscriber".into(), "0".into(), Scale::Medium),
+            Some("channel/2")
+        );
+        assert_eq!(
+            resolver.url("vip".into(), "1".into(), Scale::Large),
+            Some("vip/3")
+        );
+        assert_eq!(resolver.url("vip".into(), "2".into(), Scale::Small), None);
+    }
+}
@@ -0,0 +1,234 @@
+//! Gets an organization’s list of entitlements that have been granted to a game, a user, or both.
+//! [`get-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#get-drops-entitlements)
+//!
+//! Entitlements returned in the response body data are not guaranteed to be sorted by any field returned by the API.
+//! To retrieve [`Claimed`](FulfillmentStatus::Claimed) or [`Fulfilled`](FulfillmentStatus::Fulfilled) entitlements,
+//! use the [`fulfillment_status`](GetDropsEntitlementsRequest::fulfillment_status) query parameter to filter results.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetDropsEntitlementsRequest]
+//!
+//! To use this endpoint, construct a [`GetDropsEntitlementsRequest`] with the [`GetDropsEntitlementsRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::entitlements::{
+//!     get_drops_entitlements, FulfillmentStatus,
+//! };
+//! let request = get_drops_entitlements::GetDropsEntitlementsRequest::new()
+//!     .game_id("33214")
+//!     .fulfillment_status(FulfillmentStatus::Claimed);
+//! ```
+//!
+//! ## Response: [DropEntitlement]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, entitlements::get_drops_entitlements};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_drops_entitlements::GetDropsEntitlementsRequest::new().game_id("33214");
+//! let response: Vec<get_drops_entitlements::DropEntitlement> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetDropsEntitlementsRequest::parse_response(None, &request.get_uri(), response)`](GetDropsEntitlementsRequest::parse_response)
+
+use super::*;
+use helix::{PaginationState, RequestGet};
+
+pub use super::FulfillmentStatus;
+
+/// Query Parameters for [Get Drops Entitlements](super::get_drops_entitlements)
+///
+/// [`get-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#get-drops-entitlements)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct GetDropsEntitlementsRequest<'a> {
+    /// An ID that identifies the entitlement to get. You may specify a maximum of 100 IDs.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub id: Vec<Cow<'a, str>>,
+    /// An ID that identifies a user that was granted entitlements.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub user_id: Option<Cow<'a, types::UserIdRef>>,
+    /// An ID that identifies a game that offered entitlements.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub game_id: Option<Cow<'a, types::CategoryIdRef>>,
+    /// The entitlement’s fulfillment status. Used to filter the list to only those with the specified status.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    pub fulfillment_status: Option<FulfillmentStatus>,
+    /// The cursor used to get the next page of results.
+    #[cfg_attr(feature = "typed-builder", builder(default))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub after: Option<Cow<'a, helix::CursorRef>>,
+    /// The maximum number of entitlements to return per page in the response. The minimum page size is 1 entitlement per page and the maximum is 1000. The default is 20.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    pub first: Option<usize>,
+}
+
+impl<'a> GetDropsEntitlementsRequest<'a> {
+    /// Get all entitlements of the organization, use the methods to filter the results
+    pub fn new() -> Self { Self::default() }
+
+    /// Get these entitlements
+    pub fn ids(ids: impl IntoIterator<Item = impl Into<Cow<'a, str>>>) -> Self {
+        Self {
+            id: ids.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Only get the entitlements granted to this user
+    pub fn user_id(mut self, user_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
+        self.user_id = Some(user_id.into_cow());
+        self
+    }
+
+    /// Only get the entitlements offered by this game
+    pub fn game_id(mut self, game_id: impl types::IntoCow<'a, types::CategoryIdRef> + 'a) -> Self {
+        self.game_id = Some(game_id.into_cow());
+        self
+    }
+
+    /// Only get entitlements with this fulfillment status
+    pub const fn fulfillment_status(mut self, fulfillment_status: FulfillmentStatus) -> Self {
+        self.fulfillment_status = Some(fulfillment_status);
+        self
+    }
+
+    /// Set amount of results returned per page.
+    pub const fn first(mut self, first: usize) -> Self {
+        self.first = Some(first);
+        self
+    }
+}
+
+/// Return Values for [Get Drops Entitlements](super::get_drops_entitlements)
+///
+/// [`get-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#get-drops-entitlements)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct DropEntitlement {
+    /// An ID that identifies the entitlement.
+    pub id: String,
+    /// An ID that identifies the benefit (reward).
+    pub benefit_id: String,
+    /// The UTC date and time (in RFC3339 format) of when the entitlement was granted.
+    pub timestamp: types::Timestamp,
+    /// An ID that identifies the user who was granted the entitlement.
+    pub user_id: types::UserId,
+    /// An ID that identifies the game the user was playing when the reward was entitled.
+    pub game_id: types::CategoryId,
+    /// The entitlement’s fulfillment status.
+    pub fulfillment_status: FulfillmentStatus,
+    /// The UTC date and time (in RFC3339 format) of when the entitlement was last updated.
+    pub last_updated: types::Timestamp,
+}
+
+impl Request for GetDropsEntitlementsRequest<'_> {
+    type PaginationData = PaginationState<Self>;
+    type Response = Vec<DropEntitlement>;
+
+    const PATH: &'static str = "entitlements/drops";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+}
+
+impl RequestGet for GetDropsEntitlementsRequest<'_> {}
+
+impl helix::Paginated for GetDropsEntitlementsRequest<'_> {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) {
+        self.after = cursor.map(|c| c.into_cow())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetDropsEntitlementsRequest::new()
+        .user_id("25009227")
+        .game_id("33214");
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "id": "fb78259e-fb81-4d1b-8333-34a06ffc24c0",
+                "benefit_id": "74c52265-e214-48a6-91b9-23b6014e8041",
+                "timestamp": "2019-01-28T04:17:53.325Z",
+                "user_id": "25009227",
+                "game_id": "33214",
+                "fulfillment_status": "CLAIMED",
+                "last_updated": "2019-01-28T04:17:53.325Z"
+            },
+            {
+                "id": "862750a5-265e-4ab6-9f0a-c64df3d54dd0",
+                "benefit_id": "74c52265-e214-48a6-91b9-23b6014e8041",
+                "timestamp": "2019-01-28T04:16:53.325Z",
+                "user_id": "25009227",
+                "game_id": "33214",
+                "fulfillment_status": "CLAIMED",
+                "last_updated": "2021-06-15T04:16:53.325Z"
+            },
+            {
+                "id": "d8879baa-3966-4d10-8856-15fdd62cce02",
+                "benefit_id": "cdfdc5c3-65a2-43bc-8767-fde06eb4ab2c",
+                "timestamp": "2019-01-28T04:15:53.325Z",
+                "user_id": "25009227",
+                "game_id": "33214",
+                "fulfillment_status": "FULFILLED",
+                "last_updated": "2019-01-28T04:17:53.325Z"
+            }
+        ],
+        "pagination": {
+            "cursor": "eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6ImV5SnBaQ0k2SW5OdmJXVnBaQ0o5In19"
+        }
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/entitlements/drops?user_id=25009227&game_id=33214"
+    );
+
+    let res = GetDropsEntitlementsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data.len(), 3);
+    assert_eq!(res.data[2].fulfillment_status, FulfillmentStatus::Fulfilled);
+    assert!(res.pagination_data.cursor.is_some());
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_ids() {
+    use helix::*;
+    let req = GetDropsEntitlementsRequest::ids([
+        "fb78259e-fb81-4d1b-8333-34a06ffc24c0",
+        "862750a5-265e-4ab6-9f0a-c64df3d54dd0",
+    ])
+    .fulfillment_status(FulfillmentStatus::Claimed);
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/entitlements/drops?id=fb78259e-fb81-4d1b-8333-34a06ffc24c0&id=862750a5-265e-4ab6-9f0a-c64df3d54dd0&fulfillment_status=CLAIMED"
+    );
+}
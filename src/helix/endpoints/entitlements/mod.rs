@@ -0,0 +1,44 @@
+//! Helix endpoints regarding Drops entitlements
+//!
+//! # Implemented endpoints
+//!
+//! <!-- generate with "cargo xtask overview" (with a nightly toolchain) -->
+//! <!-- BEGIN-OVERVIEW -->
+//! <details open><summary style="cursor: pointer">Entitlements 🟢 2/2</summary>
+//!
+//! | Endpoint | Helper | Module |
+//! |---|---|---|
+//! | [Get Drops Entitlements](https://dev.twitch.tv/docs/api/reference#get-drops-entitlements) | [`HelixClient::get_drops_entitlements_for_game`](crate::helix::HelixClient::get_drops_entitlements_for_game) | [`get_drops_entitlements`] |
+//! | [Update Drops Entitlements](https://dev.twitch.tv/docs/api/reference#update-drops-entitlements) | [`HelixClient::update_drops_entitlements`](crate::helix::HelixClient::update_drops_entitlements) | [`update_drops_entitlements`] |
+//!
+//! </details>
+//!
+//! <!-- END-OVERVIEW -->
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde_derive::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+pub mod get_drops_entitlements;
+pub mod update_drops_entitlements;
+
+#[doc(inline)]
+pub use get_drops_entitlements::{DropEntitlement, GetDropsEntitlementsRequest};
+#[doc(inline)]
+pub use update_drops_entitlements::{
+    UpdateDropsEntitlementsBody, UpdateDropsEntitlementsRequest, UpdatedEntitlementSet,
+    UpdatedEntitlementStatus,
+};
+
+/// The fulfillment status of a Drops entitlement
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum FulfillmentStatus {
+    /// The entitlement was claimed by the user, but not yet granted in the game.
+    Claimed,
+    /// The entitlement was granted to the user in the game.
+    Fulfilled,
+}
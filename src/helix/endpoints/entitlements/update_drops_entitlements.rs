@@ -0,0 +1,246 @@
+//! Updates the Drop entitlement’s fulfillment status.
+//! [`update-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#update-drops-entitlements)
+//!
+//! The response contains one set of IDs per status, telling you which entitlements were updated and which weren't.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateDropsEntitlementsRequest]
+//!
+//! To use this endpoint, construct a [`UpdateDropsEntitlementsRequest`] with the [`UpdateDropsEntitlementsRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::entitlements::update_drops_entitlements;
+//! let request =
+//!     update_drops_entitlements::UpdateDropsEntitlementsRequest::new();
+//! ```
+//!
+//! ## Body: [UpdateDropsEntitlementsBody]
+//!
+//! We also need to provide a body to the request containing the entitlements to update.
+//!
+//! ```
+//! # use twitch_api::helix::entitlements::{update_drops_entitlements, FulfillmentStatus};
+//! let body = update_drops_entitlements::UpdateDropsEntitlementsBody::new(
+//!     ["fb78259e-fb81-4d1b-8333-34a06ffc24c0"],
+//!     FulfillmentStatus::Fulfilled,
+//! );
+//! ```
+//!
+//! ## Response: [UpdatedEntitlementSet]
+//!
+//! Send the request to receive the response with [`HelixClient::req_patch()`](helix::HelixClient::req_patch).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, entitlements::{update_drops_entitlements, FulfillmentStatus}};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = update_drops_entitlements::UpdateDropsEntitlementsRequest::new();
+//! let body = update_drops_entitlements::UpdateDropsEntitlementsBody::new(
+//!     ["fb78259e-fb81-4d1b-8333-34a06ffc24c0"],
+//!     FulfillmentStatus::Fulfilled,
+//! );
+//! let response: Vec<update_drops_entitlements::UpdatedEntitlementSet> = client.req_patch(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPatch::create_request)
+//! and parse the [`http::Response`] with [`UpdateDropsEntitlementsRequest::parse_response(None, &request.get_uri(), response)`](UpdateDropsEntitlementsRequest::parse_response)
+
+use super::*;
+use helix::RequestPatch;
+
+pub use super::FulfillmentStatus;
+
+/// Query Parameters for [Update Drops Entitlements](super::update_drops_entitlements)
+///
+/// [`update-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#update-drops-entitlements)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct UpdateDropsEntitlementsRequest<'a> {
+    #[serde(skip)]
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+
+impl UpdateDropsEntitlementsRequest<'_> {
+    /// Create a new update request
+    pub fn new() -> Self { Self::default() }
+}
+
+/// Body Parameters for [Update Drops Entitlements](super::update_drops_entitlements)
+///
+/// [`update-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#update-drops-entitlements)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[non_exhaustive]
+pub struct UpdateDropsEntitlementsBody<'a> {
+    /// A list of IDs that identify the entitlements to update. You may specify a maximum of 100 IDs.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entitlement_ids: Option<Vec<Cow<'a, str>>>,
+    /// The fulfillment status to set the entitlements to.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillment_status: Option<FulfillmentStatus>,
+}
+
+impl<'a> UpdateDropsEntitlementsBody<'a> {
+    /// Set these entitlements to the given fulfillment status
+    pub fn new(
+        entitlement_ids: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+        fulfillment_status: FulfillmentStatus,
+    ) -> Self {
+        Self {
+            entitlement_ids: Some(entitlement_ids.into_iter().map(Into::into).collect()),
+            fulfillment_status: Some(fulfillment_status),
+        }
+    }
+}
+
+impl helix::private::SealedSerialize for UpdateDropsEntitlementsBody<'_> {}
+
+/// Return Values for [Update Drops Entitlements](super::update_drops_entitlements)
+///
+/// [`update-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#update-drops-entitlements)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct UpdatedEntitlementSet {
+    /// A string that indicates whether the status of the entitlements in the `ids` field were successfully updated.
+    pub status: UpdatedEntitlementStatus,
+    /// The list of entitlements that the status in the `status` field applies to.
+    pub ids: Vec<String>,
+}
+
+/// Status of a set of entitlements in [Update Drops Entitlements](super::update_drops_entitlements)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum UpdatedEntitlementStatus {
+    /// The entitlement’s status was successfully updated.
+    Success,
+    /// The specified entitlement ID is not valid.
+    InvalidId,
+    /// The specified entitlement ID was not found.
+    NotFound,
+    /// The client in the access token is not authorized to update the specified entitlement.
+    Unauthorized,
+    /// The update failed. These are considered transient errors and the request should be retried later.
+    UpdateFailed,
+    /// An unknown status, contains the raw string provided by Twitch.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl Request for UpdateDropsEntitlementsRequest<'_> {
+    type PaginationData = ();
+    type Response = Vec<UpdatedEntitlementSet>;
+
+    const PATH: &'static str = "entitlements/drops";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+}
+
+impl<'a> RequestPatch for UpdateDropsEntitlementsRequest<'a> {
+    type Body = UpdateDropsEntitlementsBody<'a>;
+
+    fn parse_inner_response(
+        _request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, <Self as Request>::Response>, helix::HelixRequestPatchError>
+    where
+        Self: Sized,
+    {
+        let inner_response: helix::InnerResponse<<Self as Request>::Response> =
+            crate::parse_json(response, true).map_err(|e| {
+                helix::HelixRequestPatchError::DeserializeError(
+                    response.to_string(),
+                    e,
+                    uri.clone(),
+                    status,
+                )
+            })?;
+        Ok(helix::Response::new(
+            inner_response.data,
+            (),
+            inner_response.other,
+        ))
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateDropsEntitlementsRequest::new();
+
+    let body = UpdateDropsEntitlementsBody::new(
+        [
+            "fb78259e-fb81-4d1b-8333-34a06ffc24c0",
+            "862750a5-265e-4ab6-9f0a-c64df3d54dd0",
+            "d8879baa-3966-4d10-8856-15fdd62cce02",
+            "9a290126-7e3b-4f66-a9ae-551537893b65",
+        ],
+        FulfillmentStatus::Fulfilled,
+    );
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"entitlement_ids":["fb78259e-fb81-4d1b-8333-34a06ffc24c0","862750a5-265e-4ab6-9f0a-c64df3d54dd0","d8879baa-3966-4d10-8856-15fdd62cce02","9a290126-7e3b-4f66-a9ae-551537893b65"],"fulfillment_status":"FULFILLED"}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "status": "SUCCESS",
+                "ids": [
+                    "fb78259e-fb81-4d1b-8333-34a06ffc24c0",
+                    "862750a5-265e-4ab6-9f0a-c64df3d54dd0"
+                ]
+            },
+            {
+                "status": "UNAUTHORIZED",
+                "ids": [
+                    "d8879baa-3966-4d10-8856-15fdd62cce02"
+                ]
+            },
+            {
+                "status": "UPDATE_FAILED",
+                "ids": [
+                    "9a290126-7e3b-4f66-a9ae-551537893b65"
+                ]
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/entitlements/drops?"
+    );
+
+    let res = UpdateDropsEntitlementsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res.len(), 3);
+    assert_eq!(res[0].status, UpdatedEntitlementStatus::Success);
+    assert_eq!(res[2].status, UpdatedEntitlementStatus::UpdateFailed);
+}
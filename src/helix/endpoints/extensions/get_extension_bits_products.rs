@@ -0,0 +1,186 @@
+//! Gets the list of Bits products that belongs to the extension.
+//! [`get-extension-bits-products`](https://dev.twitch.tv/docs/api/reference#get-extension-bits-products)
+//!
+//! The client ID in the app access token identifies the extension.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetExtensionBitsProductsRequest]
+//!
+//! To use this endpoint, construct a [`GetExtensionBitsProductsRequest`] with the [`GetExtensionBitsProductsRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::extensions::get_extension_bits_products;
+//! let request =
+//!     get_extension_bits_products::GetExtensionBitsProductsRequest::new()
+//!         .should_include_all(true);
+//! ```
+//!
+//! ## Response: [BitsProduct]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, extensions::get_extension_bits_products};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+//! # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let request = get_extension_bits_products::GetExtensionBitsProductsRequest::new();
+//! let response: Vec<get_extension_bits_products::BitsProduct> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetExtensionBitsProductsRequest::parse_response(None, &request.get_uri(), response)`](GetExtensionBitsProductsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Extension Bits Products](super::get_extension_bits_products)
+///
+/// [`get-extension-bits-products`](https://dev.twitch.tv/docs/api/reference#get-extension-bits-products)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct GetExtensionBitsProductsRequest {
+    /// A Boolean value that determines whether to include disabled or expired Bits products in the response. The default is false.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    pub should_include_all: Option<bool>,
+}
+
+impl GetExtensionBitsProductsRequest {
+    /// Get the Bits products of the extension
+    pub fn new() -> Self { Self::default() }
+
+    /// Include disabled or expired Bits products in the response.
+    pub const fn should_include_all(mut self, should_include_all: bool) -> Self {
+        self.should_include_all = Some(should_include_all);
+        self
+    }
+}
+
+/// Return Values for [Get Extension Bits Products](super::get_extension_bits_products) and [Update Extension Bits Product](super::update_extension_bits_product)
+///
+/// [`get-extension-bits-products`](https://dev.twitch.tv/docs/api/reference#get-extension-bits-products)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct BitsProduct {
+    /// The product’s SKU. The SKU is unique across an extension’s products.
+    pub sku: String,
+    /// An object that contains the product’s cost information.
+    pub cost: BitsProductCost,
+    /// A Boolean value that indicates whether the product is in development. If true, the product is not available for public use.
+    pub in_development: bool,
+    /// The product’s name as displayed in the extension.
+    pub display_name: String,
+    /// The date and time, in RFC3339 format, when the product expires.
+    #[serde(
+        default,
+        deserialize_with = "crate::deserialize_none_from_empty_string"
+    )]
+    pub expiration: Option<types::Timestamp>,
+    /// A Boolean value that determines whether Bits product purchase events are broadcast to all instances of an extension on a channel. The events are broadcast via the onTransactionComplete helper callback. Is true if the event is broadcast to all instances.
+    pub is_broadcast: bool,
+}
+
+/// The cost of a [Bits product](BitsProduct)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct BitsProductCost {
+    /// The product’s price.
+    pub amount: i64,
+    /// The type of currency.
+    #[serde(rename = "type")]
+    pub type_: BitsProductCostType,
+}
+
+impl BitsProductCost {
+    /// A cost of `amount` Bits
+    pub const fn bits(amount: i64) -> Self {
+        Self {
+            amount,
+            type_: BitsProductCostType::Bits,
+        }
+    }
+}
+
+/// The currency of a [Bits product](BitsProduct)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum BitsProductCostType {
+    /// Bits
+    Bits,
+}
+
+impl Request for GetExtensionBitsProductsRequest {
+    type PaginationData = ();
+    type Response = Vec<BitsProduct>;
+
+    const PATH: &'static str = "bits/extensions";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+}
+
+impl RequestGet for GetExtensionBitsProductsRequest {}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetExtensionBitsProductsRequest::new().should_include_all(true);
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "sku": "1010",
+                "cost": {
+                    "amount": 990,
+                    "type": "bits"
+                },
+                "in_development": true,
+                "display_name": "Rusty Crate 2",
+                "expiration": "2021-05-18T09:10:13.397Z",
+                "is_broadcast": false
+            },
+            {
+                "sku": "1011",
+                "cost": {
+                    "amount": 100,
+                    "type": "bits"
+                },
+                "in_development": false,
+                "display_name": "Shiny Crate",
+                "expiration": "",
+                "is_broadcast": true
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/bits/extensions?should_include_all=true"
+    );
+
+    let res = GetExtensionBitsProductsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res[0].cost, BitsProductCost::bits(990));
+    assert!(res[0].expiration.is_some());
+    assert!(res[1].expiration.is_none());
+}
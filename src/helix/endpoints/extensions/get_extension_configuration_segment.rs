@@ -0,0 +1,180 @@
+//! Gets the specified configuration segment from the specified extension.
+//! [`get-extension-configuration-segment`](https://dev.twitch.tv/docs/api/reference#get-extension-configuration-segment)
+//!
+//! Requires a signed JWT created by an Extension Backend Service (EBS), see [the module](super).
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetExtensionConfigurationSegmentRequest]
+//!
+//! To use this endpoint, construct a [`GetExtensionConfigurationSegmentRequest`] with the [`GetExtensionConfigurationSegmentRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::extensions::{
+//!     get_extension_configuration_segment, ExtensionSegment,
+//! };
+//! let request =
+//!     get_extension_configuration_segment::GetExtensionConfigurationSegmentRequest::new(
+//!         "uo6dggojyb8d6soh92zknwmi5ej1q2",
+//!         vec![ExtensionSegment::Global],
+//!     );
+//! ```
+//!
+//! ## Response: [ExtensionConfigurationSegment]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, extensions::{get_extension_configuration_segment, ExtensionSegment}};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_extension_configuration_segment::GetExtensionConfigurationSegmentRequest::new(
+//!     "uo6dggojyb8d6soh92zknwmi5ej1q2",
+//!     vec![ExtensionSegment::Global],
+//! );
+//! let response: Vec<get_extension_configuration_segment::ExtensionConfigurationSegment> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetExtensionConfigurationSegmentRequest::parse_response(None, &request.get_uri(), response)`](GetExtensionConfigurationSegmentRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Extension Configuration Segment](super::get_extension_configuration_segment)
+///
+/// [`get-extension-configuration-segment`](https://dev.twitch.tv/docs/api/reference#get-extension-configuration-segment)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct GetExtensionConfigurationSegmentRequest<'a> {
+    /// The ID of the broadcaster that installed the extension.
+    ///
+    /// This parameter is required if you set the segment parameter to broadcaster or developer. Do not specify this parameter if you set segment to global.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub broadcaster_id: Option<Cow<'a, types::UserIdRef>>,
+    /// The ID of the extension that contains the configuration segment you want to get.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub extension_id: Cow<'a, types::ExtensionIdRef>,
+    /// The type of configuration segment to get. You may specify one or more segments.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    pub segment: Vec<ExtensionSegment>,
+}
+
+impl<'a> GetExtensionConfigurationSegmentRequest<'a> {
+    /// Get the given configuration segments of an extension
+    pub fn new(
+        extension_id: impl types::IntoCow<'a, types::ExtensionIdRef> + 'a,
+        segment: impl Into<Vec<ExtensionSegment>>,
+    ) -> Self {
+        Self {
+            broadcaster_id: None,
+            extension_id: extension_id.into_cow(),
+            segment: segment.into(),
+        }
+    }
+
+    /// Get the segments for this broadcaster
+    pub fn broadcaster_id(
+        mut self,
+        broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+    ) -> Self {
+        self.broadcaster_id = Some(broadcaster_id.into_cow());
+        self
+    }
+}
+
+/// Return Values for [Get Extension Configuration Segment](super::get_extension_configuration_segment)
+///
+/// [`get-extension-configuration-segment`](https://dev.twitch.tv/docs/api/reference#get-extension-configuration-segment)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionConfigurationSegment {
+    /// The type of segment.
+    pub segment: ExtensionSegment,
+    /// The ID of the broadcaster that installed the extension. The object includes this field only if the segment query parameter is set to developer or broadcaster.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub broadcaster_id: Option<types::UserId>,
+    /// The contents of the segment. This string may be a plain-text string or a string-encoded JSON object.
+    pub content: String,
+    /// The version number that identifies this definition of the segment’s data.
+    pub version: String,
+}
+
+impl Request for GetExtensionConfigurationSegmentRequest<'_> {
+    type PaginationData = ();
+    type Response = Vec<ExtensionConfigurationSegment>;
+
+    const PATH: &'static str = "extensions/configurations";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+}
+
+impl RequestGet for GetExtensionConfigurationSegmentRequest<'_> {}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetExtensionConfigurationSegmentRequest::new(
+        "uo6dggojyb8d6soh92zknwmi5ej1q2",
+        vec![ExtensionSegment::Global],
+    );
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "segment": "global",
+                "content": "hello config!",
+                "version": "0.0.1"
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/configurations?extension_id=uo6dggojyb8d6soh92zknwmi5ej1q2&segment=global"
+    );
+
+    let res =
+        GetExtensionConfigurationSegmentRequest::parse_response(Some(req), &uri, http_response)
+            .unwrap()
+            .data;
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].segment, ExtensionSegment::Global);
+    assert_eq!(res[0].content, "hello config!");
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_multiple_segments() {
+    use helix::*;
+    let req = GetExtensionConfigurationSegmentRequest::new(
+        "uo6dggojyb8d6soh92zknwmi5ej1q2",
+        vec![ExtensionSegment::Broadcaster, ExtensionSegment::Developer],
+    )
+    .broadcaster_id("1234");
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/configurations?broadcaster_id=1234&extension_id=uo6dggojyb8d6soh92zknwmi5ej1q2&segment=broadcaster&segment=developer"
+    );
+}
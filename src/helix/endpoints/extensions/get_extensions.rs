@@ -0,0 +1,393 @@
+//! Gets information about an extension.
+//! [`get-extensions`](https://dev.twitch.tv/docs/api/reference#get-extensions)
+//!
+//! Requires a signed JWT created by an Extension Backend Service (EBS), see [the module](super).
+//! The JWT must set the `role` claim to `external`.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetExtensionsRequest]
+//!
+//! To use this endpoint, construct a [`GetExtensionsRequest`] with the [`GetExtensionsRequest::extension_id()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::extensions::get_extensions;
+//! let request = get_extensions::GetExtensionsRequest::extension_id(
+//!     "uo6dggojyb8d6soh92zknwmi5ej1q2",
+//! )
+//! .extension_version("0.0.9");
+//! ```
+//!
+//! ## Response: [Extension]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, extensions::get_extensions};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_extensions::GetExtensionsRequest::extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2");
+//! let response: Vec<get_extensions::Extension> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetExtensionsRequest::parse_response(None, &request.get_uri(), response)`](GetExtensionsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Extensions](super::get_extensions)
+///
+/// [`get-extensions`](https://dev.twitch.tv/docs/api/reference#get-extensions)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct GetExtensionsRequest<'a> {
+    /// The ID of the extension to get.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub extension_id: Cow<'a, types::ExtensionIdRef>,
+    /// The version of the extension to get. If not specified, it returns the latest, released version. If you don’t have a released version, you must specify a version; otherwise, the list is empty.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub extension_version: Option<Cow<'a, str>>,
+}
+
+impl<'a> GetExtensionsRequest<'a> {
+    /// Get information about this extension
+    pub fn extension_id(extension_id: impl types::IntoCow<'a, types::ExtensionIdRef> + 'a) -> Self {
+        Self {
+            extension_id: extension_id.into_cow(),
+            extension_version: None,
+        }
+    }
+
+    /// Get this version of the extension
+    pub fn extension_version(mut self, extension_version: impl Into<Cow<'a, str>>) -> Self {
+        self.extension_version = Some(extension_version.into());
+        self
+    }
+}
+
+/// Return Values for [Get Extensions](super::get_extensions) and [Get Released Extensions](super::get_released_extensions)
+///
+/// [`get-extensions`](https://dev.twitch.tv/docs/api/reference#get-extensions)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Extension {
+    /// The name of the user or organization that owns the extension.
+    pub author_name: String,
+    /// A Boolean value that determines whether the extension has features that use Bits.
+    pub bits_enabled: bool,
+    /// A Boolean value that determines whether a user can install the extension on their channel.
+    pub can_install: bool,
+    /// The location of where the extension’s configuration is stored.
+    pub configuration_location: ExtensionConfigurationLocation,
+    /// A longer description of the extension. It appears on the details page.
+    pub description: String,
+    /// A URL to the extension’s Terms of Service.
+    pub eula_tos_url: String,
+    /// A Boolean value that determines whether the extension can communicate with the installed channel’s chat room.
+    pub has_chat_support: bool,
+    /// A URL to the default icon that’s displayed in the Extensions directory.
+    pub icon_url: String,
+    /// A dictionary that contains URLs to different sizes of the default icon. The dictionary’s key identifies the icon’s size (for example, 24x24), and the dictionary’s value contains the URL to the icon.
+    pub icon_urls: std::collections::HashMap<String, String>,
+    /// The extension’s ID.
+    pub id: types::ExtensionId,
+    /// The extension’s state.
+    pub state: ExtensionState,
+    /// The extension’s name.
+    pub name: String,
+    /// A URL to the extension’s privacy policy.
+    pub privacy_policy_url: String,
+    /// A Boolean value that determines whether the extension wants to explicitly ask viewers to link their Twitch identity.
+    pub request_identity_link: bool,
+    /// A list of URLs to screenshots that are shown in the Extensions marketplace.
+    pub screenshot_urls: Vec<String>,
+    /// Indicates whether the extension can view the user’s subscription level on the channel that the extension is installed on.
+    pub subscriptions_support_level: ExtensionSubscriptionsSupportLevel,
+    /// A short description of the extension that streamers see when hovering over the Discover page in the Extensions manager.
+    pub summary: String,
+    /// The email address that users use to get support for the extension.
+    pub support_email: String,
+    /// The extension’s version number.
+    pub version: String,
+    /// A brief description displayed on the channel to explain how the extension works.
+    pub viewer_summary: String,
+    /// Describes all views-related information such as how the extension is displayed on mobile devices.
+    pub views: ExtensionViews,
+    /// Allowlisted configuration URLs for displaying the extension (the allowlist is configured on Twitch’s developer site under the Extensions -> Extension -> Version -> Capabilities).
+    pub allowlisted_config_urls: Vec<String>,
+    /// Allowlisted panel URLs for displaying the extension (the allowlist is configured on Twitch’s developer site under the Extensions -> Extension -> Version -> Capabilities).
+    pub allowlisted_panel_urls: Vec<String>,
+}
+
+/// The location of where an extension’s configuration is stored.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ExtensionConfigurationLocation {
+    /// The Extensions Configuration Service hosts the configuration.
+    Hosted,
+    /// The Extension Backend Service (EBS) hosts the configuration.
+    Custom,
+    /// The extension doesn't require configuration.
+    None,
+    /// An unknown location, contains the raw string provided by Twitch.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The state of an extension.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum ExtensionState {
+    /// The extension has been approved.
+    Approved,
+    /// The assets of the extension have been uploaded.
+    AssetsUploaded,
+    /// The extension has been deleted.
+    Deleted,
+    /// The extension has been deprecated.
+    Deprecated,
+    /// The extension is in review.
+    InReview,
+    /// The extension is in test.
+    InTest,
+    /// The extension is pending action.
+    PendingAction,
+    /// The extension has been rejected.
+    Rejected,
+    /// The extension has been released.
+    Released,
+    /// An unknown state, contains the raw string provided by Twitch.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Indicates whether an extension can view the user’s subscription level on the channel that the extension is installed on.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ExtensionSubscriptionsSupportLevel {
+    /// The extension can't view the user’s subscription level.
+    None,
+    /// The extension can view the user’s subscription level.
+    Optional,
+    /// An unknown level, contains the raw string provided by Twitch.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Views-related information of an extension
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionViews {
+    /// Describes how the extension is displayed on mobile devices.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<ExtensionMobileView>,
+    /// Describes how the extension is rendered if the extension may be activated as a panel extension.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub panel: Option<ExtensionPanelView>,
+    /// Describes how the extension is rendered if the extension may be activated as a video-overlay extension.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub video_overlay: Option<ExtensionVideoOverlayView>,
+    /// Describes how the extension is rendered if the extension may be activated as a video-component extension.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component: Option<ExtensionComponentView>,
+    /// Describes the view that is shown to broadcasters while they are configuring your extension within the Extension Manager.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<ExtensionConfigView>,
+}
+
+/// How an extension is displayed on mobile devices.
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionMobileView {
+    /// The HTML file that is shown to viewers on mobile devices. This page is presented to viewers as a panel behind the chat area of the mobile app.
+    pub viewer_url: String,
+}
+
+/// How an extension is rendered as a panel extension.
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionPanelView {
+    /// The HTML file that is shown to viewers on the channel page when the extension is activated in a Panel slot.
+    pub viewer_url: String,
+    /// The height, in pixels, of the panel component that the extension is rendered in.
+    pub height: i64,
+    /// A Boolean value that determines whether the extension can link to non-Twitch domains.
+    pub can_link_external_content: bool,
+}
+
+/// How an extension is rendered as a video-overlay extension.
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionVideoOverlayView {
+    /// The HTML file that is shown to viewers on the channel page when the extension is activated on the Video - Overlay slot.
+    pub viewer_url: String,
+    /// A Boolean value that determines whether the extension can link to non-Twitch domains.
+    pub can_link_external_content: bool,
+}
+
+/// How an extension is rendered as a video-component extension.
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionComponentView {
+    /// The HTML file that is shown to viewers on the channel page when the extension is activated in a Video - Component slot.
+    pub viewer_url: String,
+    /// The width value of the ratio (width : height) which determines the extension’s width, and how the extension’s iframe will resize in different video player environments.
+    pub aspect_width: i64,
+    /// The height value of the ratio (width : height) which determines the extension’s height, and how the extension’s iframe will resize in different video player environments.
+    pub aspect_height: i64,
+    /// The X value of the aspect ratio.
+    pub aspect_ratio_x: i64,
+    /// The Y value of the aspect ratio.
+    pub aspect_ratio_y: i64,
+    /// A Boolean value that determines whether to apply CSS zoom. If true, a CSS zoom is applied such that the size of the extension is variable but the inner dimensions are fixed based on Scale Pixels. This allows your extension to render as if it is of fixed width and height. If false, the inner dimensions of the extension iframe are variable, meaning your extension must implement responsiveness.
+    pub autoscale: bool,
+    /// The base width, in pixels, of the extension to use when scaling (see autoscale). This value is ignored if autoscale is false.
+    pub scale_pixels: i64,
+    /// The height as a percent of the maximum height of a video component extension. Values are between 1% - 100%.
+    pub target_height: i64,
+    /// The size of the component.
+    #[serde(default)]
+    pub size: i64,
+    /// Whether the component should be zoomed.
+    #[serde(default)]
+    pub zoom: bool,
+    /// The amount of pixels to zoom.
+    #[serde(default)]
+    pub zoom_pixels: i64,
+    /// A Boolean value that determines whether the extension can link to non-Twitch domains.
+    pub can_link_external_content: bool,
+}
+
+/// The view that is shown to broadcasters while they are configuring an extension within the Extension Manager.
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionConfigView {
+    /// The HTML file shown to broadcasters while they are configuring your extension within the Extension Manager.
+    pub viewer_url: String,
+    /// A Boolean value that determines whether the extension can link to non-Twitch domains.
+    pub can_link_external_content: bool,
+}
+
+impl Request for GetExtensionsRequest<'_> {
+    type PaginationData = ();
+    type Response = Vec<Extension>;
+
+    const PATH: &'static str = "extensions";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+}
+
+impl RequestGet for GetExtensionsRequest<'_> {}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetExtensionsRequest::extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+        .extension_version("0.0.9");
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "author_name": "Twitch Developer Experience",
+                "bits_enabled": true,
+                "can_install": false,
+                "configuration_location": "hosted",
+                "description": "An extension for testing all the features that we add to extensions",
+                "eula_tos_url": "",
+                "has_chat_support": true,
+                "icon_url": "https://extensions-discovery-images.twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/logob6c995d8-8b45-48cc-a748-b256e92ec1b7",
+                "icon_urls": {
+                    "100x100": "https://extensions-discovery-images.twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/logob6c995d8-8b45-48cc-a748-b256e92ec1b7",
+                    "24x24": "https://extensions-discovery-images.twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/taskbar905b19da-e7e7-4706-8d60-2b7b1a5d7a56",
+                    "300x200": "https://extensions-discovery-images.twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/discovery4be9b6a0-0d3e-4e7e-ba81-99fe8ab4a41e"
+                },
+                "id": "uo6dggojyb8d6soh92zknwmi5ej1q2",
+                "name": "Dev Experience Test",
+                "privacy_policy_url": "",
+                "request_identity_link": true,
+                "screenshot_urls": [
+                    "https://extensions-discovery-images.twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/screenshot40e17a4d-e2a8-4e8b-8aa4-8c5e9a1e8c64"
+                ],
+                "state": "Released",
+                "subscriptions_support_level": "optional",
+                "summary": "Test ALL the extensions features!",
+                "support_email": "dx-extensions-test-dev@justin.tv",
+                "version": "0.0.9",
+                "viewer_summary": "Test ALL the extensions features!",
+                "views": {
+                    "mobile": {
+                        "viewer_url": "https://uo6dggojyb8d6soh92zknwmi5ej1q2.ext-twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/a8a47ee1ba90e4ec7a8a4bd4e2e9e8fc/mobile.html"
+                    },
+                    "panel": {
+                        "viewer_url": "https://uo6dggojyb8d6soh92zknwmi5ej1q2.ext-twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/a8a47ee1ba90e4ec7a8a4bd4e2e9e8fc/panel.html",
+                        "height": 300,
+                        "can_link_external_content": false
+                    },
+                    "video_overlay": {
+                        "viewer_url": "https://uo6dggojyb8d6soh92zknwmi5ej1q2.ext-twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/a8a47ee1ba90e4ec7a8a4bd4e2e9e8fc/video_overlay.html",
+                        "can_link_external_content": false
+                    },
+                    "component": {
+                        "viewer_url": "https://uo6dggojyb8d6soh92zknwmi5ej1q2.ext-twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/a8a47ee1ba90e4ec7a8a4bd4e2e9e8fc/component.html",
+                        "aspect_width": 0,
+                        "aspect_height": 0,
+                        "aspect_ratio_x": 48000,
+                        "aspect_ratio_y": 36000,
+                        "autoscale": true,
+                        "scale_pixels": 1024,
+                        "target_height": 5333,
+                        "size": 0,
+                        "zoom": false,
+                        "zoom_pixels": 0,
+                        "can_link_external_content": false
+                    },
+                    "config": {
+                        "viewer_url": "https://uo6dggojyb8d6soh92zknwmi5ej1q2.ext-twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/a8a47ee1ba90e4ec7a8a4bd4e2e9e8fc/config.html",
+                        "can_link_external_content": false
+                    }
+                },
+                "allowlisted_config_urls": [],
+                "allowlisted_panel_urls": []
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions?extension_id=uo6dggojyb8d6soh92zknwmi5ej1q2&extension_version=0.0.9"
+    );
+
+    let res = GetExtensionsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res[0].state, ExtensionState::Released);
+    assert_eq!(res[0].views.panel.as_ref().unwrap().height, 300);
+}
@@ -0,0 +1,156 @@
+//! Gets information about a released extension.
+//! [`get-released-extensions`](https://dev.twitch.tv/docs/api/reference#get-released-extensions)
+//!
+//! Returns the extension if its state is Released.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetReleasedExtensionsRequest]
+//!
+//! To use this endpoint, construct a [`GetReleasedExtensionsRequest`] with the [`GetReleasedExtensionsRequest::extension_id()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::extensions::get_released_extensions;
+//! let request =
+//!     get_released_extensions::GetReleasedExtensionsRequest::extension_id(
+//!         "uo6dggojyb8d6soh92zknwmi5ej1q2",
+//!     );
+//! ```
+//!
+//! ## Response: [Extension]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, extensions::get_released_extensions};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_released_extensions::GetReleasedExtensionsRequest::extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2");
+//! let response: Vec<helix::extensions::Extension> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetReleasedExtensionsRequest::parse_response(None, &request.get_uri(), response)`](GetReleasedExtensionsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Released Extensions](super::get_released_extensions)
+///
+/// [`get-released-extensions`](https://dev.twitch.tv/docs/api/reference#get-released-extensions)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct GetReleasedExtensionsRequest<'a> {
+    /// The ID of the extension to get.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub extension_id: Cow<'a, types::ExtensionIdRef>,
+    /// The version of the extension to get. If not specified, it returns the latest version.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub extension_version: Option<Cow<'a, str>>,
+}
+
+impl<'a> GetReleasedExtensionsRequest<'a> {
+    /// Get information about this released extension
+    pub fn extension_id(extension_id: impl types::IntoCow<'a, types::ExtensionIdRef> + 'a) -> Self {
+        Self {
+            extension_id: extension_id.into_cow(),
+            extension_version: None,
+        }
+    }
+
+    /// Get this version of the extension
+    pub fn extension_version(mut self, extension_version: impl Into<Cow<'a, str>>) -> Self {
+        self.extension_version = Some(extension_version.into());
+        self
+    }
+}
+
+impl Request for GetReleasedExtensionsRequest<'_> {
+    type PaginationData = ();
+    type Response = Vec<Extension>;
+
+    const PATH: &'static str = "extensions/released";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+}
+
+impl RequestGet for GetReleasedExtensionsRequest<'_> {}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetReleasedExtensionsRequest::extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2")
+        .extension_version("0.0.9");
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "author_name": "Twitch Developer Experience",
+                "bits_enabled": true,
+                "can_install": false,
+                "configuration_location": "hosted",
+                "description": "An extension for testing all the features that we add to extensions",
+                "eula_tos_url": "",
+                "has_chat_support": true,
+                "icon_url": "https://extensions-discovery-images.twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/logob6c995d8-8b45-48cc-a748-b256e92ec1b7",
+                "icon_urls": {
+                    "100x100": "https://extensions-discovery-images.twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/logob6c995d8-8b45-48cc-a748-b256e92ec1b7",
+                    "24x24": "https://extensions-discovery-images.twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/taskbar905b19da-e7e7-4706-8d60-2b7b1a5d7a56",
+                    "300x200": "https://extensions-discovery-images.twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/discovery4be9b6a0-0d3e-4e7e-ba81-99fe8ab4a41e"
+                },
+                "id": "uo6dggojyb8d6soh92zknwmi5ej1q2",
+                "name": "Dev Experience Test",
+                "privacy_policy_url": "",
+                "request_identity_link": true,
+                "screenshot_urls": [],
+                "state": "Released",
+                "subscriptions_support_level": "none",
+                "summary": "Test ALL the extensions features!",
+                "support_email": "dx-extensions-test-dev@justin.tv",
+                "version": "0.0.9",
+                "viewer_summary": "Test ALL the extensions features!",
+                "views": {
+                    "panel": {
+                        "viewer_url": "https://uo6dggojyb8d6soh92zknwmi5ej1q2.ext-twitch.tv/uo6dggojyb8d6soh92zknwmi5ej1q2/0.0.9/a8a47ee1ba90e4ec7a8a4bd4e2e9e8fc/panel.html",
+                        "height": 300,
+                        "can_link_external_content": false
+                    }
+                },
+                "allowlisted_config_urls": [],
+                "allowlisted_panel_urls": []
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/released?extension_id=uo6dggojyb8d6soh92zknwmi5ej1q2&extension_version=0.0.9"
+    );
+
+    let res = GetReleasedExtensionsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(
+        res[0].subscriptions_support_level,
+        ExtensionSubscriptionsSupportLevel::None
+    );
+    assert!(res[0].views.mobile.is_none());
+}
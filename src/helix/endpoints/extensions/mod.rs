@@ -0,0 +1,129 @@
+//! Helix endpoints regarding extensions
+//!
+//! Most of these endpoints require a signed JWT created by the extension backend with the extension secret,
+//! instead of a regular OAuth token. You can use such a JWT with the [`HelixClient`](crate::helix::HelixClient) by wrapping it in a
+//! [token](twitch_oauth2::TwitchToken), e.g with [`UserToken::from_existing_unchecked`](twitch_oauth2::UserToken::from_existing_unchecked).
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api::helix::{HelixClient};
+//! # use twitch_api::helix::extensions::{GetExtensionConfigurationSegmentRequest, ExtensionSegment};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api::DummyHttpClient> = &client;
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = GetExtensionConfigurationSegmentRequest::new(
+//!     "uo6dggojyb8d6soh92zknwmi5ej1q2",
+//!     vec![ExtensionSegment::Global],
+//! );
+//!
+//! println!("{:?}", &client.req_get(request, &token).await?.data.first());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Implemented endpoints
+//!
+//! <!-- generate with "cargo xtask overview" (with a nightly toolchain) -->
+//! <!-- BEGIN-OVERVIEW -->
+//! <details open><summary style="cursor: pointer">Extensions 🟢 12/12</summary>
+//!
+//! | Endpoint | Helper | Module |
+//! |---|---|---|
+//! | [Get Extension Configuration Segment](https://dev.twitch.tv/docs/api/reference#get-extension-configuration-segment) | [`HelixClient::get_extension_configuration_segment`](crate::helix::HelixClient::get_extension_configuration_segment) | [`get_extension_configuration_segment`] |
+//! | [Set Extension Configuration Segment](https://dev.twitch.tv/docs/api/reference#set-extension-configuration-segment) | - | [`set_extension_configuration_segment`] |
+//! | [Set Extension Required Configuration](https://dev.twitch.tv/docs/api/reference#set-extension-required-configuration) | - | [`set_extension_required_configuration`] |
+//! | [Send Extension PubSub Message](https://dev.twitch.tv/docs/api/reference#send-extension-pubsub-message) | - | [`send_extension_pubsub_message`] |
+//! | [Get Extension Live Channels](https://dev.twitch.tv/docs/api/reference#get-extension-live-channels) | [`HelixClient::get_extension_live_channels`](crate::helix::HelixClient::get_extension_live_channels) | [`get_extension_live_channels`] |
+//! | [Get Extension Secrets](https://dev.twitch.tv/docs/api/reference#get-extension-secrets) | [`HelixClient::get_extension_secrets`](crate::helix::HelixClient::get_extension_secrets) | [`get_extension_secrets`] |
+//! | [Create Extension Secret](https://dev.twitch.tv/docs/api/reference#create-extension-secret) | - | [`create_extension_secret`] |
+//! | [Send Extension Chat Message](https://dev.twitch.tv/docs/api/reference#send-extension-chat-message) | [`HelixClient::send_extension_chat_message`](crate::helix::HelixClient::send_extension_chat_message) | [`send_extension_chat_message`] |
+//! | [Get Extensions](https://dev.twitch.tv/docs/api/reference#get-extensions) | [`HelixClient::get_extension`](crate::helix::HelixClient::get_extension) | [`get_extensions`] |
+//! | [Get Released Extensions](https://dev.twitch.tv/docs/api/reference#get-released-extensions) | [`HelixClient::get_released_extension`](crate::helix::HelixClient::get_released_extension) | [`get_released_extensions`] |
+//! | [Get Extension Bits Products](https://dev.twitch.tv/docs/api/reference#get-extension-bits-products) | [`HelixClient::get_extension_bits_products`](crate::helix::HelixClient::get_extension_bits_products) | [`get_extension_bits_products`] |
+//! | [Update Extension Bits Product](https://dev.twitch.tv/docs/api/reference#update-extension-bits-product) | - | [`update_extension_bits_product`] |
+//!
+//! </details>
+//!
+//! <!-- END-OVERVIEW -->
+
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde_derive::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+pub mod create_extension_secret;
+pub mod get_extension_bits_products;
+pub mod get_extension_configuration_segment;
+pub mod get_extension_live_channels;
+pub mod get_extension_secrets;
+pub mod get_extensions;
+pub mod get_released_extensions;
+pub mod send_extension_chat_message;
+pub mod send_extension_pubsub_message;
+pub mod set_extension_configuration_segment;
+pub mod set_extension_required_configuration;
+pub mod update_extension_bits_product;
+
+#[doc(inline)]
+pub use create_extension_secret::CreateExtensionSecretRequest;
+#[doc(inline)]
+pub use get_extension_bits_products::{
+    BitsProduct, BitsProductCost, BitsProductCostType, GetExtensionBitsProductsRequest,
+};
+#[doc(inline)]
+pub use get_extension_configuration_segment::{
+    ExtensionConfigurationSegment, GetExtensionConfigurationSegmentRequest,
+};
+#[doc(inline)]
+pub use get_extension_live_channels::{ExtensionLiveChannel, GetExtensionLiveChannelsRequest};
+#[doc(inline)]
+pub use get_extension_secrets::{ExtensionSecret, ExtensionSecrets, GetExtensionSecretsRequest};
+#[doc(inline)]
+pub use get_extensions::{
+    Extension, ExtensionComponentView, ExtensionConfigView, ExtensionConfigurationLocation,
+    ExtensionMobileView, ExtensionPanelView, ExtensionState, ExtensionSubscriptionsSupportLevel,
+    ExtensionVideoOverlayView, ExtensionViews, GetExtensionsRequest,
+};
+#[doc(inline)]
+pub use get_released_extensions::GetReleasedExtensionsRequest;
+#[doc(inline)]
+pub use send_extension_chat_message::{
+    SendExtensionChatMessage, SendExtensionChatMessageBody, SendExtensionChatMessageRequest,
+};
+#[doc(inline)]
+pub use send_extension_pubsub_message::{
+    SendExtensionPubSubMessage, SendExtensionPubSubMessageBody, SendExtensionPubSubMessageRequest,
+};
+#[doc(inline)]
+pub use set_extension_configuration_segment::{
+    SetExtensionConfigurationSegment, SetExtensionConfigurationSegmentBody,
+    SetExtensionConfigurationSegmentRequest,
+};
+#[doc(inline)]
+pub use set_extension_required_configuration::{
+    SetExtensionRequiredConfiguration, SetExtensionRequiredConfigurationBody,
+    SetExtensionRequiredConfigurationRequest,
+};
+#[doc(inline)]
+pub use update_extension_bits_product::{
+    UpdateExtensionBitsProductBody, UpdateExtensionBitsProductRequest,
+};
+
+/// A configuration segment of an extension
+#[derive(PartialEq, Eq, Serialize, Deserialize, Copy, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ExtensionSegment {
+    /// The broadcaster segment, set by the broadcaster for their channel.
+    Broadcaster,
+    /// The developer segment, set by the developer for a specific broadcaster.
+    Developer,
+    /// The global segment, set by the developer for all channels.
+    Global,
+}
@@ -0,0 +1,248 @@
+//! Sends a message to one or more viewers.
+//! [`send-extension-pubsub-message`](https://dev.twitch.tv/docs/api/reference#send-extension-pubsub-message)
+//!
+//! You can send messages to a specific channel or to all channels where your extension is active.
+//! This endpoint uses the same mechanism as the send JavaScript helper function used to send messages.
+//!
+//! Requires a signed JWT created by an Extension Backend Service (EBS), see [the module](super).
+//! The JWT must contain the `pubsub_perms` object with the targets you're sending to.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [SendExtensionPubSubMessageRequest]
+//!
+//! To use this endpoint, construct a [`SendExtensionPubSubMessageRequest`] with the [`SendExtensionPubSubMessageRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::extensions::send_extension_pubsub_message;
+//! let request =
+//!     send_extension_pubsub_message::SendExtensionPubSubMessageRequest::new();
+//! ```
+//!
+//! ## Body: [SendExtensionPubSubMessageBody]
+//!
+//! We also need to provide a body to the request containing what we want to send.
+//!
+//! ```
+//! # use twitch_api::helix::extensions::send_extension_pubsub_message;
+//! let body = send_extension_pubsub_message::SendExtensionPubSubMessageBody::broadcast(
+//!     "141981764",
+//!     "hello world!",
+//! );
+//! ```
+//!
+//! ## Response: [SendExtensionPubSubMessage]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, extensions::send_extension_pubsub_message};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = send_extension_pubsub_message::SendExtensionPubSubMessageRequest::new();
+//! let body = send_extension_pubsub_message::SendExtensionPubSubMessageBody::broadcast(
+//!     "141981764",
+//!     "hello world!",
+//! );
+//! let response: send_extension_pubsub_message::SendExtensionPubSubMessage = client.req_post(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`SendExtensionPubSubMessageRequest::parse_response(None, &request.get_uri(), response)`](SendExtensionPubSubMessageRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Send Extension PubSub Message](super::send_extension_pubsub_message)
+///
+/// [`send-extension-pubsub-message`](https://dev.twitch.tv/docs/api/reference#send-extension-pubsub-message)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct SendExtensionPubSubMessageRequest<'a> {
+    #[serde(skip)]
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+
+impl SendExtensionPubSubMessageRequest<'_> {
+    /// Send a PubSub message
+    pub fn new() -> Self { Self::default() }
+}
+
+/// Body Parameters for [Send Extension PubSub Message](super::send_extension_pubsub_message)
+///
+/// [`send-extension-pubsub-message`](https://dev.twitch.tv/docs/api/reference#send-extension-pubsub-message)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[non_exhaustive]
+pub struct SendExtensionPubSubMessageBody<'a> {
+    /// The target of the message. Possible values are:
+    ///
+    /// * `broadcast`
+    /// * `global`
+    /// * `whisper-<user-id>`
+    ///
+    /// If `is_global_broadcast` is true, you must set this field to `global`. The `broadcast` and `global` values are mutually exclusive; specify only one of them.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub target: Vec<Cow<'a, str>>,
+    /// The ID of the broadcaster to send the message to. Don’t include this field if `is_global_broadcast` is set to true.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub broadcaster_id: Option<Cow<'a, types::UserIdRef>>,
+    /// A Boolean value that determines whether the message should be sent to all channels where your extension is active.
+    #[cfg_attr(feature = "typed-builder", builder(default))]
+    pub is_global_broadcast: bool,
+    /// The message to send. The message can be a plain-text string or a string-encoded JSON object. The message is limited to a maximum of 5 KB.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub message: Cow<'a, str>,
+}
+
+impl<'a> SendExtensionPubSubMessageBody<'a> {
+    /// Send a message to all viewers of the broadcaster
+    pub fn broadcast(
+        broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        message: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            target: vec!["broadcast".into()],
+            broadcaster_id: Some(broadcaster_id.into_cow()),
+            is_global_broadcast: false,
+            message: message.into(),
+        }
+    }
+
+    /// Send a message to all channels where the extension is active
+    pub fn global(message: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            target: vec!["global".into()],
+            broadcaster_id: None,
+            is_global_broadcast: true,
+            message: message.into(),
+        }
+    }
+
+    /// Send a message to a specific viewer of the broadcaster
+    pub fn whisper(
+        broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        user_id: &types::UserIdRef,
+        message: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            target: vec![format!("whisper-{user_id}").into()],
+            broadcaster_id: Some(broadcaster_id.into_cow()),
+            is_global_broadcast: false,
+            message: message.into(),
+        }
+    }
+}
+
+impl helix::private::SealedSerialize for SendExtensionPubSubMessageBody<'_> {}
+
+/// Return Values for [Send Extension PubSub Message](super::send_extension_pubsub_message)
+///
+/// [`send-extension-pubsub-message`](https://dev.twitch.tv/docs/api/reference#send-extension-pubsub-message)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum SendExtensionPubSubMessage {
+    /// Successfully sent the message.
+    Success,
+}
+
+impl Request for SendExtensionPubSubMessageRequest<'_> {
+    type PaginationData = ();
+    type Response = SendExtensionPubSubMessage;
+
+    const PATH: &'static str = "extensions/pubsub";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+}
+
+impl<'a> RequestPost for SendExtensionPubSubMessageRequest<'a> {
+    type Body = SendExtensionPubSubMessageBody<'a>;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        match status {
+            http::StatusCode::NO_CONTENT => Ok(helix::Response::with_data(
+                SendExtensionPubSubMessage::Success,
+                request,
+            )),
+            _ => Err(helix::HelixRequestPostError::InvalidResponse {
+                reason: "unexpected status",
+                response: response.to_string(),
+                status,
+                uri: uri.clone(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SendExtensionPubSubMessageRequest::new();
+
+    let body = SendExtensionPubSubMessageBody::broadcast("141981764", "hello world!");
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"target":["broadcast"],"broadcaster_id":"141981764","is_global_broadcast":false,"message":"hello world!"}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/pubsub?"
+    );
+
+    dbg!(
+        SendExtensionPubSubMessageRequest::parse_response(Some(req), &uri, http_response).unwrap()
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_body() {
+    use helix::*;
+
+    let body = SendExtensionPubSubMessageBody::global("hello world!");
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"target":["global"],"is_global_broadcast":true,"message":"hello world!"}"#
+    );
+
+    let body = SendExtensionPubSubMessageBody::whisper(
+        "141981764",
+        types::UserIdRef::from_static("1234"),
+        "hello you!",
+    );
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"target":["whisper-1234"],"broadcaster_id":"141981764","is_global_broadcast":false,"message":"hello you!"}"#
+    );
+}
@@ -0,0 +1,206 @@
+//! Updates the extension’s required_configuration string.
+//! [`set-extension-required-configuration`](https://dev.twitch.tv/docs/api/reference#set-extension-required-configuration)
+//!
+//! Use this endpoint if your extension requires the broadcaster to configure the extension before activating it
+//! (to require configuration, you must select Custom/My Own Service in Extension Capabilities).
+//!
+//! Requires a signed JWT created by an Extension Backend Service (EBS), see [the module](super).
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [SetExtensionRequiredConfigurationRequest]
+//!
+//! To use this endpoint, construct a [`SetExtensionRequiredConfigurationRequest`] with the [`SetExtensionRequiredConfigurationRequest::broadcaster_id()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::extensions::set_extension_required_configuration;
+//! let request =
+//!     set_extension_required_configuration::SetExtensionRequiredConfigurationRequest::broadcaster_id(
+//!         "274637212",
+//!     );
+//! ```
+//!
+//! ## Body: [SetExtensionRequiredConfigurationBody]
+//!
+//! We also need to provide a body to the request containing what we want to change.
+//!
+//! ```
+//! # use twitch_api::helix::extensions::set_extension_required_configuration;
+//! let body = set_extension_required_configuration::SetExtensionRequiredConfigurationBody::new(
+//!     "uo6dggojyb8d6soh92zknwmi5ej1q2",
+//!     "0.0.1",
+//!     "RCS",
+//! );
+//! ```
+//!
+//! ## Response: [SetExtensionRequiredConfiguration]
+//!
+//! Send the request to receive the response with [`HelixClient::req_put()`](helix::HelixClient::req_put).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, extensions::set_extension_required_configuration};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = set_extension_required_configuration::SetExtensionRequiredConfigurationRequest::broadcaster_id("274637212");
+//! let body = set_extension_required_configuration::SetExtensionRequiredConfigurationBody::new(
+//!     "uo6dggojyb8d6soh92zknwmi5ej1q2",
+//!     "0.0.1",
+//!     "RCS",
+//! );
+//! let response: set_extension_required_configuration::SetExtensionRequiredConfiguration = client.req_put(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPut::create_request)
+//! and parse the [`http::Response`] with [`SetExtensionRequiredConfigurationRequest::parse_response(None, &request.get_uri(), response)`](SetExtensionRequiredConfigurationRequest::parse_response)
+
+use super::*;
+use helix::RequestPut;
+
+/// Query Parameters for [Set Extension Required Configuration](super::set_extension_required_configuration)
+///
+/// [`set-extension-required-configuration`](https://dev.twitch.tv/docs/api/reference#set-extension-required-configuration)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct SetExtensionRequiredConfigurationRequest<'a> {
+    /// The ID of the broadcaster that installed the extension on their channel.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub broadcaster_id: Cow<'a, types::UserIdRef>,
+}
+
+impl<'a> SetExtensionRequiredConfigurationRequest<'a> {
+    /// Set the required configuration for the extension on this broadcasters channel
+    pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
+        Self {
+            broadcaster_id: broadcaster_id.into_cow(),
+        }
+    }
+}
+
+/// Body Parameters for [Set Extension Required Configuration](super::set_extension_required_configuration)
+///
+/// [`set-extension-required-configuration`](https://dev.twitch.tv/docs/api/reference#set-extension-required-configuration)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[non_exhaustive]
+pub struct SetExtensionRequiredConfigurationBody<'a> {
+    /// The ID of the extension to update.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub extension_id: Cow<'a, types::ExtensionIdRef>,
+    /// The version of the extension to update.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub extension_version: Cow<'a, str>,
+    /// The required_configuration string to use with the extension.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub required_configuration: Cow<'a, str>,
+}
+
+impl<'a> SetExtensionRequiredConfigurationBody<'a> {
+    /// Set the required configuration of this version of the extension
+    pub fn new(
+        extension_id: impl types::IntoCow<'a, types::ExtensionIdRef> + 'a,
+        extension_version: impl Into<Cow<'a, str>>,
+        required_configuration: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            extension_id: extension_id.into_cow(),
+            extension_version: extension_version.into(),
+            required_configuration: required_configuration.into(),
+        }
+    }
+}
+
+impl helix::private::SealedSerialize for SetExtensionRequiredConfigurationBody<'_> {}
+
+/// Return Values for [Set Extension Required Configuration](super::set_extension_required_configuration)
+///
+/// [`set-extension-required-configuration`](https://dev.twitch.tv/docs/api/reference#set-extension-required-configuration)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum SetExtensionRequiredConfiguration {
+    /// Successfully updated the extension’s required_configuration string.
+    Success,
+}
+
+impl Request for SetExtensionRequiredConfigurationRequest<'_> {
+    type PaginationData = ();
+    type Response = SetExtensionRequiredConfiguration;
+
+    const PATH: &'static str = "extensions/required_configuration";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+}
+
+impl<'a> RequestPut for SetExtensionRequiredConfigurationRequest<'a> {
+    type Body = SetExtensionRequiredConfigurationBody<'a>;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPutError>
+    where
+        Self: Sized,
+    {
+        match status {
+            http::StatusCode::NO_CONTENT => Ok(helix::Response::with_data(
+                SetExtensionRequiredConfiguration::Success,
+                request,
+            )),
+            _ => Err(helix::HelixRequestPutError::InvalidResponse {
+                reason: "unexpected status",
+                response: response.to_string(),
+                status,
+                uri: uri.clone(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SetExtensionRequiredConfigurationRequest::broadcaster_id("274637212");
+
+    let body = SetExtensionRequiredConfigurationBody::new(
+        "uo6dggojyb8d6soh92zknwmi5ej1q2",
+        "0.0.1",
+        "RCS",
+    );
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"extension_id":"uo6dggojyb8d6soh92zknwmi5ej1q2","extension_version":"0.0.1","required_configuration":"RCS"}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/required_configuration?broadcaster_id=274637212"
+    );
+
+    dbg!(
+        SetExtensionRequiredConfigurationRequest::parse_response(Some(req), &uri, http_response)
+            .unwrap()
+    );
+}
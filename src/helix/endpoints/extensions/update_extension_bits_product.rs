@@ -0,0 +1,231 @@
+//! Adds or updates a Bits product that the extension created.
+//! [`update-extension-bits-product`](https://dev.twitch.tv/docs/api/reference#update-extension-bits-product)
+//!
+//! If the SKU doesn’t exist, the product is added. You may update all fields except the sku field.
+//! The client ID in the app access token identifies the extension.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateExtensionBitsProductRequest]
+//!
+//! To use this endpoint, construct a [`UpdateExtensionBitsProductRequest`] with the [`UpdateExtensionBitsProductRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::extensions::update_extension_bits_product;
+//! let request =
+//!     update_extension_bits_product::UpdateExtensionBitsProductRequest::new();
+//! ```
+//!
+//! ## Body: [UpdateExtensionBitsProductBody]
+//!
+//! We also need to provide a body to the request containing what we want to change.
+//!
+//! ```
+//! # use twitch_api::helix::extensions::{update_extension_bits_product, BitsProductCost};
+//! let body = update_extension_bits_product::UpdateExtensionBitsProductBody::new(
+//!     "1010",
+//!     BitsProductCost::bits(990),
+//!     "Rusty Crate 2",
+//! )
+//! .in_development(true)
+//! .is_broadcast(true);
+//! ```
+//!
+//! ## Response: [BitsProduct]
+//!
+//! Send the request to receive the response with [`HelixClient::req_put()`](helix::HelixClient::req_put).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, extensions::{update_extension_bits_product, BitsProductCost}};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let client_id = twitch_oauth2::types::ClientId::from_static("your_client_id");
+//! # let client_secret = twitch_oauth2::types::ClientSecret::from_static("your_client_id");
+//! # let token = twitch_oauth2::AppAccessToken::get_app_access_token(&client, client_id, client_secret, vec![]).await?;
+//! let request = update_extension_bits_product::UpdateExtensionBitsProductRequest::new();
+//! let body = update_extension_bits_product::UpdateExtensionBitsProductBody::new(
+//!     "1010",
+//!     BitsProductCost::bits(990),
+//!     "Rusty Crate 2",
+//! );
+//! let response: helix::extensions::BitsProduct = client.req_put(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPut::create_request)
+//! and parse the [`http::Response`] with [`UpdateExtensionBitsProductRequest::parse_response(None, &request.get_uri(), response)`](UpdateExtensionBitsProductRequest::parse_response)
+
+use super::*;
+use helix::RequestPut;
+
+/// Query Parameters for [Update Extension Bits Product](super::update_extension_bits_product)
+///
+/// [`update-extension-bits-product`](https://dev.twitch.tv/docs/api/reference#update-extension-bits-product)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct UpdateExtensionBitsProductRequest<'a> {
+    #[serde(skip)]
+    _phantom: std::marker::PhantomData<&'a ()>,
+}
+
+impl UpdateExtensionBitsProductRequest<'_> {
+    /// Add or update a Bits product
+    pub fn new() -> Self { Self::default() }
+}
+
+/// Body Parameters for [Update Extension Bits Product](super::update_extension_bits_product)
+///
+/// [`update-extension-bits-product`](https://dev.twitch.tv/docs/api/reference#update-extension-bits-product)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[non_exhaustive]
+pub struct UpdateExtensionBitsProductBody<'a> {
+    /// The product’s SKU. The SKU must be unique within an extension. The product’s SKU cannot be changed. The SKU may contain only alphanumeric characters, dashes (-), underscores (_), and periods (.) and is limited to a maximum of 255 characters. No spaces.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub sku: Cow<'a, str>,
+    /// An object that contains the product’s cost information.
+    pub cost: BitsProductCost,
+    /// The product’s name as displayed in the extension. The maximum length is 255 characters.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub display_name: Cow<'a, str>,
+    /// A Boolean value that indicates whether the product is in development. Set to true if the product is in development and not available for public use. The default is false.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_development: Option<bool>,
+    /// The date and time, in RFC3339 format, when the product expires. If not set, the product does not expire.
+    ///
+    /// To disable the product, set the expiration date to a date in the past.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<Cow<'a, types::TimestampRef>>,
+    /// A Boolean value that determines whether Bits product purchase events are broadcast to all instances of the extension on a channel. The events are broadcast via the onTransactionComplete helper callback. The default is false.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_broadcast: Option<bool>,
+}
+
+impl<'a> UpdateExtensionBitsProductBody<'a> {
+    /// Add or update the product with this SKU
+    pub fn new(
+        sku: impl Into<Cow<'a, str>>,
+        cost: BitsProductCost,
+        display_name: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            sku: sku.into(),
+            cost,
+            display_name: display_name.into(),
+            in_development: None,
+            expiration: None,
+            is_broadcast: None,
+        }
+    }
+
+    /// Set if the product is in development
+    pub const fn in_development(mut self, in_development: bool) -> Self {
+        self.in_development = Some(in_development);
+        self
+    }
+
+    /// Set when the product expires
+    pub fn expiration(
+        mut self,
+        expiration: impl types::IntoCow<'a, types::TimestampRef> + 'a,
+    ) -> Self {
+        self.expiration = Some(expiration.into_cow());
+        self
+    }
+
+    /// Set if purchase events are broadcast to all instances of the extension
+    pub const fn is_broadcast(mut self, is_broadcast: bool) -> Self {
+        self.is_broadcast = Some(is_broadcast);
+        self
+    }
+}
+
+impl helix::private::SealedSerialize for UpdateExtensionBitsProductBody<'_> {}
+
+impl Request for UpdateExtensionBitsProductRequest<'_> {
+    type PaginationData = ();
+    type Response = BitsProduct;
+
+    const PATH: &'static str = "bits/extensions";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+}
+
+impl<'a> RequestPut for UpdateExtensionBitsProductRequest<'a> {
+    type Body = UpdateExtensionBitsProductBody<'a>;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPutError>
+    where
+        Self: Sized,
+    {
+        helix::parse_single_return(request, uri, response, status)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateExtensionBitsProductRequest::new();
+
+    let body =
+        UpdateExtensionBitsProductBody::new("1010", BitsProductCost::bits(990), "Rusty Crate 2")
+            .in_development(true)
+            .is_broadcast(true)
+            .expiration(types::Timestamp::from_static("2021-05-18T09:10:13.397Z"));
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"sku":"1010","cost":{"amount":990,"type":"bits"},"display_name":"Rusty Crate 2","in_development":true,"expiration":"2021-05-18T09:10:13.397Z","is_broadcast":true}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "sku": "1010",
+                "cost": {
+                    "amount": 990,
+                    "type": "bits"
+                },
+                "in_development": true,
+                "display_name": "Rusty Crate 2",
+                "expiration": "2021-05-18T09:10:13.397Z",
+                "is_broadcast": true
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/bits/extensions?"
+    );
+
+    dbg!(
+        UpdateExtensionBitsProductRequest::parse_response(Some(req), &uri, http_response).unwrap()
+    );
+}
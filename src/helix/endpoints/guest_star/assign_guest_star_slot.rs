@@ -0,0 +1,175 @@
+//! Allows a previously invited user to be assigned a slot within the active Guest Star session, once that guest has indicated they are ready to join.
+//! [`assign-guest-star-slot`](https://dev.twitch.tv/docs/api/reference#assign-guest-star-slot)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [AssignGuestStarSlotRequest]
+//!
+//! To use this endpoint, construct a [`AssignGuestStarSlotRequest`] with the [`AssignGuestStarSlotRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::guest_star::assign_guest_star_slot;
+//! let request = assign_guest_star_slot::AssignGuestStarSlotRequest::new(
+//!     "9321049",
+//!     "9321049",
+//!     "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+//!     "144601104",
+//!     "1",
+//! );
+//! ```
+//!
+//! ## Response: [AssignGuestStarSlot]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, guest_star::assign_guest_star_slot};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = assign_guest_star_slot::AssignGuestStarSlotRequest::new(
+//!     "9321049",
+//!     "9321049",
+//!     "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+//!     "144601104",
+//!     "1",
+//! );
+//! let response: assign_guest_star_slot::AssignGuestStarSlot = client.req_post(request, helix::EmptyBody, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`AssignGuestStarSlotRequest::parse_response(None, &request.get_uri(), response)`](AssignGuestStarSlotRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Assign Guest Star Slot](super::assign_guest_star_slot)
+///
+/// [`assign-guest-star-slot`](https://dev.twitch.tv/docs/api/reference#assign-guest-star-slot)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct AssignGuestStarSlotRequest<'a> {
+    /// The ID of the broadcaster running the Guest Star session.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub broadcaster_id: Cow<'a, types::UserIdRef>,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub moderator_id: Cow<'a, types::UserIdRef>,
+    /// The ID of the Guest Star session in which to assign the slot.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub session_id: Cow<'a, types::GuestStarSessionIdRef>,
+    /// The Twitch User ID corresponding to the guest to assign a slot in the session. This user must already have an invite to this session, and have indicated that they are ready to join.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub guest_id: Cow<'a, types::UserIdRef>,
+    /// The slot assignment to give to the user. Must be a numeric identifier between “1” and “N” where N is the max number of slots for the session.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub slot_id: Cow<'a, types::GuestStarSlotIdRef>,
+}
+
+impl<'a> AssignGuestStarSlotRequest<'a> {
+    /// Assign a guest to a slot in this Guest Star session
+    pub fn new(
+        broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        session_id: impl types::IntoCow<'a, types::GuestStarSessionIdRef> + 'a,
+        guest_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        slot_id: impl types::IntoCow<'a, types::GuestStarSlotIdRef> + 'a,
+    ) -> Self {
+        Self {
+            broadcaster_id: broadcaster_id.into_cow(),
+            moderator_id: moderator_id.into_cow(),
+            session_id: session_id.into_cow(),
+            guest_id: guest_id.into_cow(),
+            slot_id: slot_id.into_cow(),
+        }
+    }
+}
+
+/// Return Values for [Assign Guest Star Slot](super::assign_guest_star_slot)
+///
+/// [`assign-guest-star-slot`](https://dev.twitch.tv/docs/api/reference#assign-guest-star-slot)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum AssignGuestStarSlot {
+    /// 204 - Successfully assigned the guest to the slot.
+    Success,
+}
+
+impl Request for AssignGuestStarSlotRequest<'_> {
+    type PaginationData = ();
+    type Response = AssignGuestStarSlot;
+
+    const PATH: &'static str = "guest_star/slot";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![any(
+        twitch_oauth2::Scope::ChannelManageGuestStar,
+        twitch_oauth2::Scope::ModeratorManageGuestStar,
+    )];
+}
+
+impl RequestPost for AssignGuestStarSlotRequest<'_> {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        match status {
+            http::StatusCode::NO_CONTENT => Ok(helix::Response::with_data(
+                AssignGuestStarSlot::Success,
+                request,
+            )),
+            _ => Err(helix::HelixRequestPostError::InvalidResponse {
+                reason: "unexpected status",
+                response: response.to_string(),
+                status,
+                uri: uri.clone(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = AssignGuestStarSlotRequest::new(
+        "9321049",
+        "9321049",
+        "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+        "144601104",
+        "1",
+    );
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/slot?broadcaster_id=9321049&moderator_id=9321049&session_id=2KFRQbFtpmfyD3IevNRnCzOPRJI&guest_id=144601104&slot_id=1"
+    );
+
+    dbg!(AssignGuestStarSlotRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
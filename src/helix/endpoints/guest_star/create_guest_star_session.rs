@@ -0,0 +1,149 @@
+//! Programmatically creates a Guest Star session on behalf of the broadcaster.
+//! [`create-guest-star-session`](https://dev.twitch.tv/docs/api/reference#create-guest-star-session)
+//!
+//! Requires the broadcaster to be present in the call interface, or the call will be ended automatically.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [CreateGuestStarSessionRequest]
+//!
+//! To use this endpoint, construct a [`CreateGuestStarSessionRequest`] with the [`CreateGuestStarSessionRequest::broadcaster_id()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::guest_star::create_guest_star_session;
+//! let request =
+//!     create_guest_star_session::CreateGuestStarSessionRequest::broadcaster_id(
+//!         "9321049",
+//!     );
+//! ```
+//!
+//! ## Response: [GuestStarSession]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, guest_star::create_guest_star_session};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = create_guest_star_session::CreateGuestStarSessionRequest::broadcaster_id("9321049");
+//! let response: helix::guest_star::GuestStarSession = client.req_post(request, helix::EmptyBody, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`CreateGuestStarSessionRequest::parse_response(None, &request.get_uri(), response)`](CreateGuestStarSessionRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+pub use super::GuestStarSession;
+
+/// Query Parameters for [Create Guest Star Session](super::create_guest_star_session)
+///
+/// [`create-guest-star-session`](https://dev.twitch.tv/docs/api/reference#create-guest-star-session)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct CreateGuestStarSessionRequest<'a> {
+    /// The ID of the broadcaster you want to create a Guest Star session for. Provided broadcaster_id must match the user_id in the auth token.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub broadcaster_id: Cow<'a, types::UserIdRef>,
+}
+
+impl<'a> CreateGuestStarSessionRequest<'a> {
+    /// Create a Guest Star session for this broadcaster
+    pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
+        Self {
+            broadcaster_id: broadcaster_id.into_cow(),
+        }
+    }
+}
+
+impl Request for CreateGuestStarSessionRequest<'_> {
+    type PaginationData = ();
+    type Response = GuestStarSession;
+
+    const PATH: &'static str = "guest_star/session";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator =
+        twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageGuestStar];
+}
+
+impl RequestPost for CreateGuestStarSessionRequest<'_> {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, <Self as Request>::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        helix::parse_single_return(request, uri, response, status)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = CreateGuestStarSessionRequest::broadcaster_id("9321049");
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "id": "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+                "guests": [
+                    {
+                        "slot_id": "0",
+                        "user_id": "9321049",
+                        "user_display_name": "Cool_User",
+                        "user_login": "cool_user",
+                        "is_live": true,
+                        "volume": 100,
+                        "assigned_at": "2023-01-02T04:16:53.325Z",
+                        "audio_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": true
+                        },
+                        "video_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": true
+                        }
+                    }
+                ]
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/session?broadcaster_id=9321049"
+    );
+
+    let res = CreateGuestStarSessionRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res.id.as_str(), "2KFRQbFtpmfyD3IevNRnCzOPRJI");
+    assert_eq!(res.guests[0].slot_id.as_str(), "0");
+}
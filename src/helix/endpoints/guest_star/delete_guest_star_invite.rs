@@ -0,0 +1,162 @@
+//! Revokes a previously sent invite for a Guest Star session.
+//! [`delete-guest-star-invite`](https://dev.twitch.tv/docs/api/reference#delete-guest-star-invite)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [DeleteGuestStarInviteRequest]
+//!
+//! To use this endpoint, construct a [`DeleteGuestStarInviteRequest`] with the [`DeleteGuestStarInviteRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::guest_star::delete_guest_star_invite;
+//! let request = delete_guest_star_invite::DeleteGuestStarInviteRequest::new(
+//!     "9321049",
+//!     "9321049",
+//!     "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+//!     "144601104",
+//! );
+//! ```
+//!
+//! ## Response: [DeleteGuestStarInvite]
+//!
+//! Send the request to receive the response with [`HelixClient::req_delete()`](helix::HelixClient::req_delete).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, guest_star::delete_guest_star_invite};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = delete_guest_star_invite::DeleteGuestStarInviteRequest::new(
+//!     "9321049",
+//!     "9321049",
+//!     "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+//!     "144601104",
+//! );
+//! let response: delete_guest_star_invite::DeleteGuestStarInvite = client.req_delete(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestDelete::create_request)
+//! and parse the [`http::Response`] with [`DeleteGuestStarInviteRequest::parse_response(None, &request.get_uri(), response)`](DeleteGuestStarInviteRequest::parse_response)
+
+use super::*;
+use helix::RequestDelete;
+
+/// Query Parameters for [Delete Guest Star Invite](super::delete_guest_star_invite)
+///
+/// [`delete-guest-star-invite`](https://dev.twitch.tv/docs/api/reference#delete-guest-star-invite)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct DeleteGuestStarInviteRequest<'a> {
+    /// The ID of the broadcaster running the Guest Star session.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub broadcaster_id: Cow<'a, types::UserIdRef>,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub moderator_id: Cow<'a, types::UserIdRef>,
+    /// The ID of the session for the invite to be revoked on behalf of the broadcaster.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub session_id: Cow<'a, types::GuestStarSessionIdRef>,
+    /// Twitch User ID for the guest to revoke the Guest Star session invite from.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub guest_id: Cow<'a, types::UserIdRef>,
+}
+
+impl<'a> DeleteGuestStarInviteRequest<'a> {
+    /// Revoke the invite of a guest to this Guest Star session
+    pub fn new(
+        broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        session_id: impl types::IntoCow<'a, types::GuestStarSessionIdRef> + 'a,
+        guest_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+    ) -> Self {
+        Self {
+            broadcaster_id: broadcaster_id.into_cow(),
+            moderator_id: moderator_id.into_cow(),
+            session_id: session_id.into_cow(),
+            guest_id: guest_id.into_cow(),
+        }
+    }
+}
+
+/// Return Values for [Delete Guest Star Invite](super::delete_guest_star_invite)
+///
+/// [`delete-guest-star-invite`](https://dev.twitch.tv/docs/api/reference#delete-guest-star-invite)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum DeleteGuestStarInvite {
+    /// 204 - Successfully revoked the invite.
+    Success,
+}
+
+impl Request for DeleteGuestStarInviteRequest<'_> {
+    type PaginationData = ();
+    type Response = DeleteGuestStarInvite;
+
+    const PATH: &'static str = "guest_star/invites";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![any(
+        twitch_oauth2::Scope::ChannelManageGuestStar,
+        twitch_oauth2::Scope::ModeratorManageGuestStar,
+    )];
+}
+
+impl RequestDelete for DeleteGuestStarInviteRequest<'_> {
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestDeleteError>
+    where
+        Self: Sized,
+    {
+        match status {
+            http::StatusCode::NO_CONTENT => Ok(helix::Response::with_data(
+                DeleteGuestStarInvite::Success,
+                request,
+            )),
+            _ => Err(helix::HelixRequestDeleteError::InvalidResponse {
+                reason: "unexpected status",
+                response: response.to_string(),
+                status,
+                uri: uri.clone(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = DeleteGuestStarInviteRequest::new(
+        "9321049",
+        "9321049",
+        "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+        "144601104",
+    );
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/invites?broadcaster_id=9321049&moderator_id=9321049&session_id=2KFRQbFtpmfyD3IevNRnCzOPRJI&guest_id=144601104"
+    );
+
+    dbg!(DeleteGuestStarInviteRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
@@ -0,0 +1,155 @@
+//! Gets the channel settings for configuration of the Guest Star feature for a particular host.
+//! [`get-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#get-channel-guest-star-settings)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetChannelGuestStarSettingsRequest]
+//!
+//! To use this endpoint, construct a [`GetChannelGuestStarSettingsRequest`] with the [`GetChannelGuestStarSettingsRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::guest_star::get_channel_guest_star_settings;
+//! let request =
+//!     get_channel_guest_star_settings::GetChannelGuestStarSettingsRequest::new(
+//!         "9321049", "9321049",
+//!     );
+//! ```
+//!
+//! ## Response: [GuestStarSettings]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, guest_star::get_channel_guest_star_settings};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_channel_guest_star_settings::GetChannelGuestStarSettingsRequest::new("9321049", "9321049");
+//! let response: helix::guest_star::GuestStarSettings = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetChannelGuestStarSettingsRequest::parse_response(None, &request.get_uri(), response)`](GetChannelGuestStarSettingsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Channel Guest Star Settings](super::get_channel_guest_star_settings)
+///
+/// [`get-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#get-channel-guest-star-settings)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct GetChannelGuestStarSettingsRequest<'a> {
+    /// The ID of the broadcaster you want to get guest star settings for.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub broadcaster_id: Cow<'a, types::UserIdRef>,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub moderator_id: Cow<'a, types::UserIdRef>,
+}
+
+impl<'a> GetChannelGuestStarSettingsRequest<'a> {
+    /// Get the Guest Star settings of a broadcaster
+    pub fn new(
+        broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+    ) -> Self {
+        Self {
+            broadcaster_id: broadcaster_id.into_cow(),
+            moderator_id: moderator_id.into_cow(),
+        }
+    }
+}
+
+/// Return Values for [Get Channel Guest Star Settings](super::get_channel_guest_star_settings)
+///
+/// [`get-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#get-channel-guest-star-settings)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct GuestStarSettings {
+    /// Flag determining if Guest Star moderators have access to control whether a guest is live once assigned to a slot.
+    pub is_moderator_send_live_enabled: bool,
+    /// Number of slots the Guest Star call interface will allow the host to add to a call. Required to be between 1 and 6.
+    pub slot_count: usize,
+    /// Flag determining if Browser Sources subscribed to sessions on this channel should output audio.
+    pub is_browser_source_audio_enabled: bool,
+    /// This setting determines how the guests within a session should be laid out within the browser source.
+    pub group_layout: GroupLayout,
+    /// View only token to generate browser source URLs.
+    pub browser_source_token: String,
+}
+
+impl Request for GetChannelGuestStarSettingsRequest<'_> {
+    type PaginationData = ();
+    type Response = GuestStarSettings;
+
+    const PATH: &'static str = "guest_star/channel_settings";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![any(
+        twitch_oauth2::Scope::ChannelReadGuestStar,
+        twitch_oauth2::Scope::ChannelManageGuestStar,
+        twitch_oauth2::Scope::ModeratorReadGuestStar,
+        twitch_oauth2::Scope::ModeratorManageGuestStar,
+    )];
+}
+
+impl RequestGet for GetChannelGuestStarSettingsRequest<'_> {
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, <Self as Request>::Response>, helix::HelixRequestGetError>
+    where
+        Self: Sized,
+    {
+        helix::parse_single_return(request, uri, response, status)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetChannelGuestStarSettingsRequest::new("9321049", "9321049");
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "is_moderator_send_live_enabled": true,
+                "slot_count": 4,
+                "is_browser_source_audio_enabled": true,
+                "group_layout": "TILED_LAYOUT",
+                "browser_source_token": "eihq8rew7q3hgierufhi3q"
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/channel_settings?broadcaster_id=9321049&moderator_id=9321049"
+    );
+
+    let res = GetChannelGuestStarSettingsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res.slot_count, 4);
+    assert_eq!(res.group_layout, GroupLayout::TiledLayout);
+}
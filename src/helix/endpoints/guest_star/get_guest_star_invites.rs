@@ -0,0 +1,177 @@
+//! Provides the caller with a list of pending invites to a Guest Star session, including the invitee’s ready status while joining the waiting room.
+//! [`get-guest-star-invites`](https://dev.twitch.tv/docs/api/reference#get-guest-star-invites)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetGuestStarInvitesRequest]
+//!
+//! To use this endpoint, construct a [`GetGuestStarInvitesRequest`] with the [`GetGuestStarInvitesRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::guest_star::get_guest_star_invites;
+//! let request = get_guest_star_invites::GetGuestStarInvitesRequest::new(
+//!     "9321049",
+//!     "9321049",
+//!     "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+//! );
+//! ```
+//!
+//! ## Response: [GuestStarInvite]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, guest_star::get_guest_star_invites};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_guest_star_invites::GetGuestStarInvitesRequest::new(
+//!     "9321049",
+//!     "9321049",
+//!     "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+//! );
+//! let response: Vec<helix::guest_star::GuestStarInvite> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetGuestStarInvitesRequest::parse_response(None, &request.get_uri(), response)`](GetGuestStarInvitesRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Guest Star Invites](super::get_guest_star_invites)
+///
+/// [`get-guest-star-invites`](https://dev.twitch.tv/docs/api/reference#get-guest-star-invites)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct GetGuestStarInvitesRequest<'a> {
+    /// The ID of the broadcaster running the Guest Star session.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub broadcaster_id: Cow<'a, types::UserIdRef>,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub moderator_id: Cow<'a, types::UserIdRef>,
+    /// The session ID to query for invite status.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub session_id: Cow<'a, types::GuestStarSessionIdRef>,
+}
+
+impl<'a> GetGuestStarInvitesRequest<'a> {
+    /// Get the pending invites of this Guest Star session
+    pub fn new(
+        broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        session_id: impl types::IntoCow<'a, types::GuestStarSessionIdRef> + 'a,
+    ) -> Self {
+        Self {
+            broadcaster_id: broadcaster_id.into_cow(),
+            moderator_id: moderator_id.into_cow(),
+            session_id: session_id.into_cow(),
+        }
+    }
+}
+
+/// Return Values for [Get Guest Star Invites](super::get_guest_star_invites)
+///
+/// [`get-guest-star-invites`](https://dev.twitch.tv/docs/api/reference#get-guest-star-invites)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct GuestStarInvite {
+    /// Twitch User ID corresponding to the invited guest.
+    pub user_id: types::UserId,
+    /// Timestamp when this user was invited to the session.
+    pub invited_at: types::Timestamp,
+    /// Status representing the invited user’s join state.
+    pub status: InviteStatus,
+    /// Flag signaling that the invited user has chosen to disable their local video device. The user has hidden themselves, but they may choose to reveal their video feed upon joining the session.
+    pub is_video_enabled: bool,
+    /// Flag signaling that the invited user has chosen to disable their local audio device. The user has muted themselves, but they may choose to unmute their audio feed upon joining the session.
+    pub is_audio_enabled: bool,
+    /// Flag signaling that the invited user has a video device available for sharing.
+    pub is_video_available: bool,
+    /// Flag signaling that the invited user has an audio device available for sharing.
+    pub is_audio_available: bool,
+}
+
+/// The join state of an invited user
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InviteStatus {
+    /// The user has been invited to the session but has not acknowledged it.
+    Invited,
+    /// The invited user has acknowledged the invite and joined the waiting room, but may still be setting up their media devices or otherwise preparing to join the call.
+    Accepted,
+    /// The invited user has signaled they are ready to join the call from the waiting room.
+    Ready,
+    /// An unknown status, contains the raw string provided by Twitch.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl Request for GetGuestStarInvitesRequest<'_> {
+    type PaginationData = ();
+    type Response = Vec<GuestStarInvite>;
+
+    const PATH: &'static str = "guest_star/invites";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![any(
+        twitch_oauth2::Scope::ChannelReadGuestStar,
+        twitch_oauth2::Scope::ChannelManageGuestStar,
+        twitch_oauth2::Scope::ModeratorReadGuestStar,
+        twitch_oauth2::Scope::ModeratorManageGuestStar,
+    )];
+}
+
+impl RequestGet for GetGuestStarInvitesRequest<'_> {}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetGuestStarInvitesRequest::new("9321049", "9321049", "2KFRQbFtpmfyD3IevNRnCzOPRJI");
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "user_id": "144601104",
+                "invited_at": "2023-01-02T04:16:53.325Z",
+                "status": "INVITED",
+                "is_audio_enabled": false,
+                "is_video_enabled": true,
+                "is_audio_available": true,
+                "is_video_available": true
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/invites?broadcaster_id=9321049&moderator_id=9321049&session_id=2KFRQbFtpmfyD3IevNRnCzOPRJI"
+    );
+
+    let res = GetGuestStarInvitesRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].status, InviteStatus::Invited);
+    assert!(!res[0].is_audio_enabled);
+}
@@ -0,0 +1,265 @@
+//! Gets information about an ongoing Guest Star session for a particular channel.
+//! [`get-guest-star-session`](https://dev.twitch.tv/docs/api/reference#get-guest-star-session)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetGuestStarSessionRequest]
+//!
+//! To use this endpoint, construct a [`GetGuestStarSessionRequest`] with the [`GetGuestStarSessionRequest::new()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::guest_star::get_guest_star_session;
+//! let request = get_guest_star_session::GetGuestStarSessionRequest::new(
+//!     "9321049", "9321049",
+//! );
+//! ```
+//!
+//! ## Response: [GuestStarSession]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, guest_star::get_guest_star_session};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_guest_star_session::GetGuestStarSessionRequest::new("9321049", "9321049");
+//! let response: Option<helix::guest_star::GuestStarSession> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetGuestStarSessionRequest::parse_response(None, &request.get_uri(), response)`](GetGuestStarSessionRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Guest Star Session](super::get_guest_star_session)
+///
+/// [`get-guest-star-session`](https://dev.twitch.tv/docs/api/reference#get-guest-star-session)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct GetGuestStarSessionRequest<'a> {
+    /// ID for the user hosting the Guest Star session.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub broadcaster_id: Cow<'a, types::UserIdRef>,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub moderator_id: Cow<'a, types::UserIdRef>,
+}
+
+impl<'a> GetGuestStarSessionRequest<'a> {
+    /// Get the active Guest Star session of a broadcaster
+    pub fn new(
+        broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+        moderator_id: impl types::IntoCow<'a, types::UserIdRef> + 'a,
+    ) -> Self {
+        Self {
+            broadcaster_id: broadcaster_id.into_cow(),
+            moderator_id: moderator_id.into_cow(),
+        }
+    }
+}
+
+/// Return Values for [Get Guest Star Session](super::get_guest_star_session), [Create Guest Star Session](super::create_guest_star_session) and [End Guest Star Session](super::end_guest_star_session)
+///
+/// [`get-guest-star-session`](https://dev.twitch.tv/docs/api/reference#get-guest-star-session)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct GuestStarSession {
+    /// ID uniquely representing the Guest Star session.
+    pub id: types::GuestStarSessionId,
+    /// List of guests currently interacting with the Guest Star session.
+    pub guests: Vec<Guest>,
+}
+
+/// A guest in a [Guest Star session](GuestStarSession)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Guest {
+    /// ID representing this guest’s slot assignment.
+    ///
+    /// Host is always in slot "0". Guests are assigned the following consecutive IDs (e.g, "1", "2", "3", etc).
+    pub slot_id: types::GuestStarSlotId,
+    /// Flag determining whether or not the guest is visible in the browser source in the host’s streaming software.
+    pub is_live: bool,
+    /// User ID of the guest assigned to this slot.
+    pub user_id: types::UserId,
+    /// Display name of the guest assigned to this slot.
+    pub user_display_name: types::DisplayName,
+    /// Login of the guest assigned to this slot.
+    pub user_login: types::UserName,
+    /// Value from 0 to 100 representing the host’s volume setting for this guest.
+    pub volume: u8,
+    /// Timestamp when this guest was assigned a slot in the session.
+    pub assigned_at: types::Timestamp,
+    /// Information about the guest’s audio settings.
+    pub audio_settings: GuestMediaSettings,
+    /// Information about the guest’s video settings.
+    pub video_settings: GuestMediaSettings,
+}
+
+/// Audio or video settings of a [Guest]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct GuestMediaSettings {
+    /// Flag determining whether the host is allowing the guest’s audio or video to be seen or heard within the session.
+    pub is_host_enabled: bool,
+    /// Flag determining whether the guest is allowing their audio or video to be transmitted to the session.
+    pub is_guest_enabled: bool,
+    /// Flag determining whether the guest has an appropriate audio or video device available to be transmitted to the session.
+    pub is_available: bool,
+}
+
+impl Request for GetGuestStarSessionRequest<'_> {
+    type PaginationData = ();
+    type Response = Option<GuestStarSession>;
+
+    const PATH: &'static str = "guest_star/session";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![any(
+        twitch_oauth2::Scope::ChannelReadGuestStar,
+        twitch_oauth2::Scope::ChannelManageGuestStar,
+        twitch_oauth2::Scope::ModeratorReadGuestStar,
+        twitch_oauth2::Scope::ModeratorManageGuestStar,
+    )];
+}
+
+impl RequestGet for GetGuestStarSessionRequest<'_> {
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, <Self as Request>::Response>, helix::HelixRequestGetError>
+    where
+        Self: Sized,
+    {
+        let resp = match status {
+            http::StatusCode::OK => {
+                let resp: helix::InnerResponse<helix::request::ZeroOrOne<GuestStarSession>> =
+                    helix::parse_json(response, true).map_err(|e| {
+                        helix::HelixRequestGetError::DeserializeError(
+                            response.to_string(),
+                            e,
+                            uri.clone(),
+                            status,
+                        )
+                    })?;
+                resp.data.0
+            }
+            _ => {
+                return Err(helix::HelixRequestGetError::InvalidResponse {
+                    reason: "unexpected status code",
+                    response: response.to_string(),
+                    status,
+                    uri: uri.clone(),
+                })
+            }
+        };
+        Ok(helix::Response::with_data(resp, request))
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetGuestStarSessionRequest::new("9321049", "9321049");
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "id": "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+                "guests": [
+                    {
+                        "slot_id": "0",
+                        "user_id": "9321049",
+                        "user_display_name": "Cool_User",
+                        "user_login": "cool_user",
+                        "is_live": true,
+                        "volume": 100,
+                        "assigned_at": "2023-01-02T04:16:53.325Z",
+                        "audio_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": true
+                        },
+                        "video_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": true
+                        }
+                    },
+                    {
+                        "slot_id": "1",
+                        "user_id": "144601104",
+                        "user_display_name": "Cool_Guest",
+                        "user_login": "cool_guest",
+                        "is_live": true,
+                        "volume": 100,
+                        "assigned_at": "2023-01-02T04:20:59.325Z",
+                        "audio_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": true
+                        },
+                        "video_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": true
+                        }
+                    }
+                ]
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/session?broadcaster_id=9321049&moderator_id=9321049"
+    );
+
+    let res = GetGuestStarSessionRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data
+        .unwrap();
+    assert_eq!(res.id.as_str(), "2KFRQbFtpmfyD3IevNRnCzOPRJI");
+    assert_eq!(res.guests.len(), 2);
+    assert_eq!(res.guests[1].slot_id.as_str(), "1");
+}
+
+#[cfg(test)]
+#[test]
+fn test_request_no_session() {
+    use helix::*;
+    let req = GetGuestStarSessionRequest::new("9321049", "9321049");
+
+    let data = br#"{"data": []}"#.to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    let res = GetGuestStarSessionRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert!(res.is_none());
+}
@@ -0,0 +1,229 @@
+//! Mutates the channel settings for configuration of the Guest Star feature for a particular host.
+//! [`update-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#update-channel-guest-star-settings)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateChannelGuestStarSettingsRequest]
+//!
+//! To use this endpoint, construct a [`UpdateChannelGuestStarSettingsRequest`] with the [`UpdateChannelGuestStarSettingsRequest::broadcaster_id()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::guest_star::update_channel_guest_star_settings;
+//! let request =
+//!     update_channel_guest_star_settings::UpdateChannelGuestStarSettingsRequest::broadcaster_id(
+//!         "9321049",
+//!     );
+//! ```
+//!
+//! ## Body: [UpdateChannelGuestStarSettingsBody]
+//!
+//! We also need to provide a body to the request containing what we want to change.
+//!
+//! ```
+//! # use twitch_api::helix::guest_star::{update_channel_guest_star_settings, GroupLayout};
+//! let body = update_channel_guest_star_settings::UpdateChannelGuestStarSettingsBody::new()
+//!     .slot_count(4)
+//!     .group_layout(GroupLayout::ScreenshareLayout);
+//! ```
+//!
+//! ## Response: [UpdateChannelGuestStarSettings]
+//!
+//! Send the request to receive the response with [`HelixClient::req_put()`](helix::HelixClient::req_put).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, guest_star::{update_channel_guest_star_settings, GroupLayout}};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = update_channel_guest_star_settings::UpdateChannelGuestStarSettingsRequest::broadcaster_id("9321049");
+//! let body = update_channel_guest_star_settings::UpdateChannelGuestStarSettingsBody::new()
+//!     .slot_count(4)
+//!     .group_layout(GroupLayout::ScreenshareLayout);
+//! let response: update_channel_guest_star_settings::UpdateChannelGuestStarSettings = client.req_put(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPut::create_request)
+//! and parse the [`http::Response`] with [`UpdateChannelGuestStarSettingsRequest::parse_response(None, &request.get_uri(), response)`](UpdateChannelGuestStarSettingsRequest::parse_response)
+
+use super::*;
+use helix::RequestPut;
+
+/// Query Parameters for [Update Channel Guest Star Settings](super::update_channel_guest_star_settings)
+///
+/// [`update-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#update-channel-guest-star-settings)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct UpdateChannelGuestStarSettingsRequest<'a> {
+    /// The ID of the broadcaster you want to update Guest Star settings for.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub broadcaster_id: Cow<'a, types::UserIdRef>,
+}
+
+impl<'a> UpdateChannelGuestStarSettingsRequest<'a> {
+    /// Update the Guest Star settings of this broadcaster
+    pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
+        Self {
+            broadcaster_id: broadcaster_id.into_cow(),
+        }
+    }
+}
+
+/// Body Parameters for [Update Channel Guest Star Settings](super::update_channel_guest_star_settings)
+///
+/// [`update-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#update-channel-guest-star-settings)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[non_exhaustive]
+pub struct UpdateChannelGuestStarSettingsBody {
+    /// Flag determining if Guest Star moderators have access to control whether a guest is live once assigned to a slot.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_moderator_send_live_enabled: Option<bool>,
+    /// Number of slots the Guest Star call interface will allow the host to add to a call. Required to be between 1 and 6.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slot_count: Option<usize>,
+    /// Flag determining if Browser Sources subscribed to sessions on this channel should output audio.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_browser_source_audio_enabled: Option<bool>,
+    /// This setting determines how the guests within a session should be laid out within the browser source.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_layout: Option<GroupLayout>,
+    /// Flag determining if Guest Star should regenerate the auth token associated with the channel’s browser sources.
+    ///
+    /// Providing a true value for this will immediately invalidate all browser sources previously configured in your streaming software.
+    #[cfg_attr(feature = "typed-builder", builder(default, setter(into)))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regenerate_browser_sources: Option<bool>,
+}
+
+impl UpdateChannelGuestStarSettingsBody {
+    /// Settings to change, use the methods to set the values
+    pub fn new() -> Self { Self::default() }
+
+    /// Set whether Guest Star moderators can control if a guest is live
+    pub fn is_moderator_send_live_enabled(mut self, is_moderator_send_live_enabled: bool) -> Self {
+        self.is_moderator_send_live_enabled = Some(is_moderator_send_live_enabled);
+        self
+    }
+
+    /// Set the number of slots in a call, between 1 and 6
+    pub fn slot_count(mut self, slot_count: usize) -> Self {
+        self.slot_count = Some(slot_count);
+        self
+    }
+
+    /// Set whether browser sources should output audio
+    pub fn is_browser_source_audio_enabled(
+        mut self,
+        is_browser_source_audio_enabled: bool,
+    ) -> Self {
+        self.is_browser_source_audio_enabled = Some(is_browser_source_audio_enabled);
+        self
+    }
+
+    /// Set the layout of guests in the browser source
+    pub fn group_layout(mut self, group_layout: GroupLayout) -> Self {
+        self.group_layout = Some(group_layout);
+        self
+    }
+
+    /// Regenerate the auth token of the browser sources, invalidating all existing ones
+    pub fn regenerate_browser_sources(mut self, regenerate_browser_sources: bool) -> Self {
+        self.regenerate_browser_sources = Some(regenerate_browser_sources);
+        self
+    }
+}
+
+impl helix::private::SealedSerialize for UpdateChannelGuestStarSettingsBody {}
+
+/// Return Values for [Update Channel Guest Star Settings](super::update_channel_guest_star_settings)
+///
+/// [`update-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#update-channel-guest-star-settings)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum UpdateChannelGuestStarSettings {
+    /// 204 - Successfully updated channel settings.
+    Success,
+}
+
+impl Request for UpdateChannelGuestStarSettingsRequest<'_> {
+    type PaginationData = ();
+    type Response = UpdateChannelGuestStarSettings;
+
+    const PATH: &'static str = "guest_star/channel_settings";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator =
+        twitch_oauth2::validator![twitch_oauth2::Scope::ChannelManageGuestStar];
+}
+
+impl RequestPut for UpdateChannelGuestStarSettingsRequest<'_> {
+    type Body = UpdateChannelGuestStarSettingsBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPutError>
+    where
+        Self: Sized,
+    {
+        match status {
+            http::StatusCode::NO_CONTENT => Ok(helix::Response::with_data(
+                UpdateChannelGuestStarSettings::Success,
+                request,
+            )),
+            _ => Err(helix::HelixRequestPutError::InvalidResponse {
+                reason: "unexpected status",
+                response: response.to_string(),
+                status,
+                uri: uri.clone(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateChannelGuestStarSettingsRequest::broadcaster_id("9321049");
+
+    let body = UpdateChannelGuestStarSettingsBody::new()
+        .is_moderator_send_live_enabled(false)
+        .group_layout(GroupLayout::ScreenshareLayout);
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"is_moderator_send_live_enabled":false,"group_layout":"SCREENSHARE_LAYOUT"}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/channel_settings?broadcaster_id=9321049"
+    );
+
+    dbg!(
+        UpdateChannelGuestStarSettingsRequest::parse_response(Some(req), &uri, http_response)
+            .unwrap()
+    );
+}
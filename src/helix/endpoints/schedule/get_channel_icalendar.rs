@@ -0,0 +1,633 @@
+//! Gets the broadcaster’s streaming schedule as an [iCalendar](https://datatracker.ietf.org/doc/html/rfc5545).
+//!
+//! [`get-channel-icalendar`](https://dev.twitch.tv/docs/api/reference#get-channel-icalendar)
+//!
+//! ## Notes
+//!
+//! Unlike other Helix endpoints, this endpoint responds with `text/calendar` instead of JSON.
+//! The response is kept as-is in [`ChannelICalendar::calendar`], and the events in it are parsed into [`Segment`]s.
+//!
+//! This endpoint does not require authorization.
+//!
+//! ## Request: [GetChannelICalendarRequest]
+//!
+//! To use this endpoint, construct a [`GetChannelICalendarRequest`] with the [`GetChannelICalendarRequest::broadcaster_id()`] method.
+//!
+//! ```rust
+//! use twitch_api::helix::schedule::get_channel_icalendar;
+//! let request =
+//!     get_channel_icalendar::GetChannelICalendarRequest::broadcaster_id(
+//!         "141981764",
+//!     );
+//! ```
+//!
+//! ## Response: [ChannelICalendar]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api::helix::{self, schedule::get_channel_icalendar};
+//! # use twitch_api::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(&client, token, None, None).await?;
+//! let request = get_channel_icalendar::GetChannelICalendarRequest::broadcaster_id("141981764");
+//! let response: get_channel_icalendar::ChannelICalendar = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetChannelICalendarRequest::parse_response(None, &request.get_uri(), response)`](GetChannelICalendarRequest::parse_response)
+
+use super::*;
+use crate::common::calendar::{civil_from_days, days_from_civil};
+use helix::RequestGet;
+
+/// Query Parameters for [Get Channel iCalendar](super::get_channel_icalendar)
+///
+/// [`get-channel-icalendar`](https://dev.twitch.tv/docs/api/reference#get-channel-icalendar)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "typed-builder", derive(typed_builder::TypedBuilder))]
+#[must_use]
+#[non_exhaustive]
+pub struct GetChannelICalendarRequest<'a> {
+    /// The ID of the broadcaster that owns the streaming schedule you want to get.
+    #[cfg_attr(feature = "typed-builder", builder(setter(into)))]
+    #[cfg_attr(feature = "deser_borrow", serde(borrow = "'a"))]
+    pub broadcaster_id: Cow<'a, types::UserIdRef>,
+}
+
+impl<'a> GetChannelICalendarRequest<'a> {
+    /// Get a broadcasters schedule as an iCalendar
+    pub fn broadcaster_id(broadcaster_id: impl types::IntoCow<'a, types::UserIdRef> + 'a) -> Self {
+        Self {
+            broadcaster_id: broadcaster_id.into_cow(),
+        }
+    }
+}
+
+/// Return Values for [Get Channel iCalendar](super::get_channel_icalendar)
+///
+/// [`get-channel-icalendar`](https://dev.twitch.tv/docs/api/reference#get-channel-icalendar)
+#[derive(PartialEq, Eq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct ChannelICalendar {
+    /// The iCalendar as returned by Twitch.
+    pub calendar: String,
+    /// The events in the calendar.
+    ///
+    /// The iCalendar doesn't contain category IDs or cancellations, so [`Segment::category`] and [`Segment::canceled_until`] are always `None`.
+    /// Recurring events are only included once, starting at their first occurrence.
+    pub segments: Vec<Segment>,
+}
+
+impl ChannelICalendar {
+    /// Parse an iCalendar as returned by [Get Channel iCalendar](super::get_channel_icalendar)
+    pub fn parse(calendar: &str) -> Result<Self, ICalendarError> {
+        let mut time_zones: Vec<(String, TimeZone)> = vec![];
+        let mut events: Vec<Vec<Property>> = vec![];
+        let mut stack: Vec<String> = vec![];
+
+        for line in unfold(calendar) {
+            let property = Property::parse(&line)?;
+            match property.name.as_str() {
+                "BEGIN" => {
+                    let component = property.value.to_ascii_uppercase();
+                    match (stack.last().map(|s| s.as_str()), component.as_str()) {
+                        (_, "VTIMEZONE") => time_zones.push((String::new(), TimeZone::default())),
+                        (Some("VTIMEZONE"), "STANDARD" | "DAYLIGHT") => {
+                            if let Some((_, tz)) = time_zones.last_mut() {
+                                tz.observances.push(Observance::default());
+                            }
+                        }
+                        (_, "VEVENT") => events.push(vec![]),
+                        _ => (),
+                    }
+                    stack.push(component);
+                }
+                "END" => {
+                    if stack.pop().as_deref() != Some(&*property.value.to_ascii_uppercase()) {
+                        return Err(ICalendarError::UnbalancedComponent(property.value));
+                    }
+                }
+                _ => match stack.last().map(|s| s.as_str()) {
+                    Some("VTIMEZONE") if property.name == "TZID" => {
+                        if let Some((id, _)) = time_zones.last_mut() {
+                            *id = property.value;
+                        }
+                    }
+                    Some("STANDARD" | "DAYLIGHT") => {
+                        if let Some(observance) = time_zones
+                            .last_mut()
+                            .and_then(|(_, tz)| tz.observances.last_mut())
+                        {
+                            observance.set(property)?;
+                        }
+                    }
+                    Some("VEVENT") => {
+                        if let Some(event) = events.last_mut() {
+                            event.push(property);
+                        }
+                    }
+                    _ => (),
+                },
+            }
+        }
+        if let Some(component) = stack.pop() {
+            return Err(ICalendarError::UnbalancedComponent(component));
+        }
+
+        let segments = events
+            .into_iter()
+            .map(|event| segment_from_event(event, &time_zones))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            calendar: calendar.to_owned(),
+            segments,
+        })
+    }
+}
+
+/// Errors that can happen when parsing an iCalendar with [`ChannelICalendar::parse`]
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[non_exhaustive]
+pub enum ICalendarError {
+    /// malformed content line: {0:?}
+    MalformedLine(String),
+    /// component `{0}` was not closed correctly
+    UnbalancedComponent(String),
+    /// event is missing the `{0}` property
+    MissingProperty(&'static str),
+    /// invalid date-time: {0:?}
+    InvalidDateTime(String),
+    /// invalid UTC offset: {0:?}
+    InvalidUtcOffset(String),
+    /// unknown time zone: {0:?}
+    UnknownTimeZone(String),
+}
+
+impl Request for GetChannelICalendarRequest<'_> {
+    type PaginationData = ();
+    type Response = ChannelICalendar;
+
+    const PATH: &'static str = "schedule/icalendar";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: twitch_oauth2::Validator = twitch_oauth2::validator![];
+}
+
+impl RequestGet for GetChannelICalendarRequest<'_> {
+    fn parse_inner_response(
+        _request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, <Self as Request>::Response>, helix::HelixRequestGetError>
+    where
+        Self: Sized,
+    {
+        let calendar = ChannelICalendar::parse(response).map_err(|_| {
+            helix::HelixRequestGetError::InvalidResponse {
+                reason: "could not parse response as iCalendar",
+                response: response.to_string(),
+                status,
+                uri: uri.clone(),
+            }
+        })?;
+        Ok(helix::Response::new(calendar, (), None))
+    }
+}
+
+/// A content line, e.g. `DTSTART;TZID=/America/New_York:20210701T140000`
+struct Property {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl Property {
+    fn parse(line: &str) -> Result<Self, ICalendarError> {
+        let malformed = || ICalendarError::MalformedLine(line.to_owned());
+        // the value starts after the first colon that's not inside a quoted parameter value
+        let mut quoted = false;
+        let colon = line
+            .char_indices()
+            .find(|&(_, c)| {
+                if c == '"' {
+                    quoted = !quoted;
+                }
+                c == ':' && !quoted
+            })
+            .map(|(i, _)| i)
+            .ok_or_else(malformed)?;
+        let (head, value) = (&line[..colon], &line[colon + 1..]);
+        let mut parts = head.split(';');
+        let name = parts
+            .next()
+            .filter(|n| !n.is_empty())
+            .ok_or_else(malformed)?;
+        let params = parts
+            .map(|p| {
+                p.split_once('=')
+                    .map(|(k, v)| (k.to_ascii_uppercase(), v.trim_matches('"').to_owned()))
+                    .ok_or_else(malformed)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            name: name.to_ascii_uppercase(),
+            params,
+            value: value.to_owned(),
+        })
+    }
+
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// The value as TEXT, with escaped characters replaced.
+    fn text(&self) -> String {
+        let mut text = String::with_capacity(self.value.len());
+        let mut chars = self.value.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some('n' | 'N') => text.push('\n'),
+                    Some(c) => text.push(c),
+                    None => text.push('\\'),
+                },
+                c => text.push(c),
+            }
+        }
+        text
+    }
+}
+
+/// Splits the calendar into content lines, joining lines that were folded.
+fn unfold(calendar: &str) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    for line in calendar.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ if line.trim().is_empty() => (),
+            _ => lines.push(line.to_owned()),
+        }
+    }
+    lines
+}
+
+fn segment_from_event(
+    event: Vec<Property>,
+    time_zones: &[(String, TimeZone)],
+) -> Result<Segment, ICalendarError> {
+    let get = |name: &'static str| {
+        event
+            .iter()
+            .find(|p| p.name == name)
+            .ok_or(ICalendarError::MissingProperty(name))
+    };
+    let time = |property: &Property| -> Result<types::Timestamp, ICalendarError> {
+        let (local, is_utc) = parse_date_time(&property.value)?;
+        let utc = match property.param("TZID") {
+            _ if is_utc => local,
+            None => local,
+            Some(tzid) => {
+                let (_, tz) = time_zones
+                    .iter()
+                    .find(|(id, _)| id == tzid)
+                    .ok_or_else(|| ICalendarError::UnknownTimeZone(tzid.to_owned()))?;
+                local - tz.utc_offset(local)
+            }
+        };
+        to_timestamp(utc)
+    };
+
+    Ok(Segment {
+        id: get("UID")?.value.clone().into(),
+        start_time: time(get("DTSTART")?)?,
+        end_time: time(get("DTEND")?)?,
+        title: get("SUMMARY").map(|p| p.text()).unwrap_or_default(),
+        canceled_until: None,
+        category: None,
+        is_recurring: get("RRULE").is_ok(),
+    })
+}
+
+/// A `VTIMEZONE` component
+#[derive(Default)]
+struct TimeZone {
+    observances: Vec<Observance>,
+}
+
+impl TimeZone {
+    /// Offset in seconds from UTC at the given local time
+    fn utc_offset(&self, local: i64) -> i64 {
+        let (year, ..) = civil_from_days(local.div_euclid(86400));
+        // the last transition that happened before this time
+        let mut current: Option<(i64, i64)> = None;
+        for observance in &self.observances {
+            for onset in observance.onsets(year - 1..=year) {
+                let instant = onset - observance.offset_from;
+                if instant <= local - observance.offset_to
+                    && current.is_none_or(|(last, _)| instant > last)
+                {
+                    current = Some((instant, observance.offset_to));
+                }
+            }
+        }
+        current
+            .map(|(_, offset)| offset)
+            .or_else(|| self.observances.first().map(|o| o.offset_from))
+            .unwrap_or(0)
+    }
+}
+
+/// A `STANDARD` or `DAYLIGHT` component in a [`TimeZone`]
+#[derive(Default)]
+struct Observance {
+    /// First onset, in local time before the transition
+    start: i64,
+    offset_from: i64,
+    offset_to: i64,
+    /// Additional onsets, in local time before the transition
+    dates: Vec<i64>,
+    rule: Option<YearlyRule>,
+}
+
+impl Observance {
+    fn set(&mut self, property: Property) -> Result<(), ICalendarError> {
+        match property.name.as_str() {
+            "DTSTART" => self.start = parse_date_time(&property.value)?.0,
+            "TZOFFSETFROM" => self.offset_from = parse_utc_offset(&property.value)?,
+            "TZOFFSETTO" => self.offset_to = parse_utc_offset(&property.value)?,
+            "RDATE" => {
+                for date in property.value.split(',') {
+                    self.dates.push(parse_date_time(date)?.0);
+                }
+            }
+            "RRULE" => self.rule = YearlyRule::parse(&property.value)?,
+            _ => (),
+        }
+        Ok(())
+    }
+
+    /// Onsets of this observance, including all explicit dates and the dates of the rule in the given years
+    fn onsets(&self, years: std::ops::RangeInclusive<i64>) -> impl Iterator<Item = i64> + '_ {
+        let (start_year, ..) = civil_from_days(self.start.div_euclid(86400));
+        let time_of_day = self.start.rem_euclid(86400);
+        let rule = self.rule.as_ref();
+        let from_rule = years
+            .filter(move |&year| year >= start_year)
+            .filter_map(move |year| {
+                let rule = rule?;
+                let onset = rule.day_in(year) * 86400 + time_of_day;
+                (onset >= self.start
+                    && rule
+                        .until
+                        .is_none_or(|until| onset - self.offset_from <= until))
+                .then_some(onset)
+            });
+        std::iter::once(self.start)
+            .chain(self.dates.iter().copied())
+            .chain(from_rule)
+    }
+}
+
+/// A `RRULE` like `FREQ=YEARLY;BYMONTH=3;BYDAY=2SU`, as used in time zone observances
+struct YearlyRule {
+    month: u32,
+    /// Which occurrence of the weekday in the month, negative values count from the end of the month
+    week: i64,
+    /// Day of the week, 0 is sunday
+    weekday: i64,
+    /// Last onset, in UTC
+    until: Option<i64>,
+}
+
+impl YearlyRule {
+    /// Parses the rule, returning `None` if the rule is not supported
+    fn parse(rule: &str) -> Result<Option<Self>, ICalendarError> {
+        let (mut yearly, mut month, mut by_day, mut until) = (false, None, None, None);
+        for part in rule.split(';') {
+            match part.split_once('=') {
+                Some(("FREQ", freq)) => yearly = freq == "YEARLY",
+                Some(("BYMONTH", m)) => month = m.parse().ok(),
+                Some(("BYDAY", d)) => by_day = Some(d),
+                Some(("UNTIL", u)) => until = Some(parse_date_time(u)?.0),
+                _ => (),
+            }
+        }
+        let (Some(month), Some(by_day), true) = (month, by_day, yearly) else {
+            return Ok(None);
+        };
+        if !by_day.is_ascii() {
+            return Ok(None);
+        }
+        let split = by_day.len().saturating_sub(2);
+        let (week, weekday) = (&by_day[..split], &by_day[split..]);
+        let weekday = ["SU", "MO", "TU", "WE", "TH", "FR", "SA"]
+            .iter()
+            .position(|&d| d == weekday);
+        match (week.parse::<i64>(), weekday) {
+            (Ok(week), Some(weekday)) if week != 0 => Ok(Some(Self {
+                month,
+                week,
+                weekday: weekday as i64,
+                until,
+            })),
+            _ => Ok(None),
+        }
+    }
+
+    /// Days since the unix epoch of the onset in the given year
+    fn day_in(&self, year: i64) -> i64 {
+        let weekday = |days: i64| (days + 4).rem_euclid(7);
+        if self.week > 0 {
+            let first = days_from_civil(year, self.month, 1);
+            first + (self.weekday - weekday(first)).rem_euclid(7) + 7 * (self.week - 1)
+        } else {
+            let (next_year, next_month) = if self.month == 12 {
+                (year + 1, 1)
+            } else {
+                (year, self.month + 1)
+            };
+            let last = days_from_civil(next_year, next_month, 1) - 1;
+            last - (weekday(last) - self.weekday).rem_euclid(7) - 7 * (-self.week - 1)
+        }
+    }
+}
+
+/// Parses a `DATE` or `DATE-TIME` into seconds since the unix epoch, and whether the time is in UTC.
+fn parse_date_time(value: &str) -> Result<(i64, bool), ICalendarError> {
+    let invalid = || ICalendarError::InvalidDateTime(value.to_owned());
+    let (value, is_utc) = match value.strip_suffix('Z') {
+        Some(v) => (v, true),
+        None => (value, false),
+    };
+    let (date, time) = value.split_once('T').unwrap_or((value, "000000"));
+    if date.len() != 8
+        || time.len() != 6
+        || !(date.chars().chain(time.chars())).all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let num = |s: &str| s.parse::<i64>().map_err(|_| invalid());
+    let (year, month, day) = (num(&date[..4])?, num(&date[4..6])?, num(&date[6..])?);
+    let (hour, minute, second) = (num(&time[..2])?, num(&time[2..4])?, num(&time[4..])?);
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return Err(invalid());
+    }
+    let days = days_from_civil(year, month as u32, day as u32);
+    Ok((days * 86400 + hour * 3600 + minute * 60 + second, is_utc))
+}
+
+/// Parses a `UTC-OFFSET` like `-0500` or `-045602` into seconds
+fn parse_utc_offset(value: &str) -> Result<i64, ICalendarError> {
+    let invalid = || ICalendarError::InvalidUtcOffset(value.to_owned());
+    let (sign, digits) = match value.split_at_checked(1) {
+        Some(("+", d)) => (1, d),
+        Some(("-", d)) => (-1, d),
+        _ => return Err(invalid()),
+    };
+    if !matches!(digits.len(), 4 | 6) || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let num = |s: &str| s.parse::<i64>().map_err(|_| invalid());
+    let seconds = if digits.len() == 6 {
+        num(&digits[4..])?
+    } else {
+        0
+    };
+    Ok(sign * (num(&digits[..2])? * 3600 + num(&digits[2..4])? * 60 + seconds))
+}
+
+fn to_timestamp(seconds: i64) -> Result<types::Timestamp, ICalendarError> {
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86400));
+    let time = seconds.rem_euclid(86400);
+    let timestamp = format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    );
+    types::Timestamp::new(timestamp.clone()).map_err(|_| ICalendarError::InvalidDateTime(timestamp))
+}
+
+#[cfg(test)]
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetChannelICalendarRequest::broadcaster_id("141981764");
+
+    // From twitch docs, shortened
+    let data = "BEGIN:VCALENDAR\r
+PRODID:-//twitch.tv//StreamSchedule//1.0\r
+VERSION:2.0\r
+CALSCALE:GREGORIAN\r
+REFRESH-INTERVAL;VALUE=DURATION:PT1H\r
+NAME:TwitchDev\r
+BEGIN:VTIMEZONE\r
+TZID:/America/New_York\r
+BEGIN:STANDARD\r
+DTSTART:18831118T120358\r
+RDATE:18831118T120358\r
+TZNAME:EST\r
+TZOFFSETFROM:-045602\r
+TZOFFSETTO:-0500\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:20070311T020000\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU\r
+TZNAME:EDT\r
+TZOFFSETFROM:-0500\r
+TZOFFSETTO:-0400\r
+END:DAYLIGHT\r
+BEGIN:STANDARD\r
+DTSTART:20071104T020000\r
+RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r
+TZNAME:EST\r
+TZOFFSETFROM:-0400\r
+TZOFFSETTO:-0500\r
+END:STANDARD\r
+END:VTIMEZONE\r
+BEGIN:VEVENT\r
+UID:e4acc724-371f-402c-81ca-23ada79759d4\r
+DTSTAMP:20210323T040131Z\r
+DTSTART;TZID=/America/New_York:20210701T140000\r
+DTEND;TZID=/America/New_York:20210701T150000\r
+SUMMARY:TwitchDev Monthly Update // July 1\\, 2021\r
+DESCRIPTION:Science & Technology.\r
+CATEGORIES:Science & Technology\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:3ab1b83b-0ec8-4e0c-9a5f-7d1e7aa8e2f1\r
+DTSTAMP:20210323T040131Z\r
+DTSTART;TZID=/America/New_York:20211202T100000\r
+DTEND;TZID=/America/New_York:20211202T113000\r
+RRULE:FREQ=WEEKLY;BYDAY=TH\r
+SUMMARY:TwitchDev Office Hours: a very long title that has been folded\r
+  onto the next line\r
+END:VEVENT\r
+END:VCALENDAR\r
+"
+    .as_bytes()
+    .to_vec();
+
+    let http_response = http::Response::builder()
+        .header("Content-Type", "text/calendar")
+        .body(data.clone())
+        .unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/schedule/icalendar?broadcaster_id=141981764"
+    );
+
+    let res = GetChannelICalendarRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res.calendar.as_bytes(), data);
+    assert_eq!(res.segments.len(), 2);
+
+    let segment = &res.segments[0];
+    assert_eq!(segment.id.as_str(), "e4acc724-371f-402c-81ca-23ada79759d4");
+    assert_eq!(segment.title, "TwitchDev Monthly Update // July 1, 2021");
+    assert_eq!(segment.start_time.as_str(), "2021-07-01T18:00:00Z");
+    assert_eq!(segment.end_time.as_str(), "2021-07-01T19:00:00Z");
+    assert!(!segment.is_recurring);
+
+    let segment = &res.segments[1];
+    assert_eq!(
+        segment.title,
+        "TwitchDev Office Hours: a very long title that has been folded onto the next line"
+    );
+    assert_eq!(segment.start_time.as_str(), "2021-12-02T15:00:00Z");
+    assert_eq!(segment.end_time.as_str(), "2021-12-02T16:30:00Z");
+    assert!(segment.is_recurring);
+}
+
+#[cfg(test)]
+#[test]
+fn test_error_response() {
+    use helix::*;
+    let req = GetChannelICalendarRequest::broadcaster_id("0");
+
+    let data = br#"{"error":"Not Found","status":404,"message":"schedule was not found"}"#.to_vec();
+    let http_response = http::Response::builder().status(404).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert!(matches!(
+        GetChannelICalendarRequest::parse_response(Some(req), &uri, http_response),
+        Err(HelixRequestGetError::Error { status, .. }) if status == http::StatusCode::NOT_FOUND
+    ));
+}
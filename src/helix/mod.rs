@@ -44,23 +44,23 @@
 //!
 //! </details>
 //!
-//! <details><summary style="cursor: pointer">Analytics 🔴 0/2</summary>
+//! <details><summary style="cursor: pointer">Analytics 🟢 2/2</summary>
 //!
 //! | Endpoint | Helper | Module |
 //! |---|---|---|
-//! | [Get Extension Analytics](https://dev.twitch.tv/docs/api/reference#get-extension-analytics) | - | - |
-//! | [Get Game Analytics](https://dev.twitch.tv/docs/api/reference#get-game-analytics) | - | - |
+//! | [Get Extension Analytics](https://dev.twitch.tv/docs/api/reference#get-extension-analytics) | [`HelixClient::get_extension_analytics`] | [`analytics::get_extension_analytics`] |
+//! | [Get Game Analytics](https://dev.twitch.tv/docs/api/reference#get-game-analytics) | [`HelixClient::get_game_analytics`] | [`analytics::get_game_analytics`] |
 //!
 //! </details>
 //!
-//! <details><summary style="cursor: pointer">Bits 🟡 2/4</summary>
+//! <details><summary style="cursor: pointer">Bits 🟢 4/4</summary>
 //!
 //! | Endpoint | Helper | Module |
 //! |---|---|---|
 //! | [Get Bits Leaderboard](https://dev.twitch.tv/docs/api/reference#get-bits-leaderboard) | - | [`bits::get_bits_leaderboard`] |
 //! | [Get Cheermotes](https://dev.twitch.tv/docs/api/reference#get-cheermotes) | - | [`bits::get_cheermotes`] |
-//! | [Get Custom Power-up](https://dev.twitch.tv/docs/api/reference#get-custom-power-up) | - | - |
-//! | [Get Extension Transactions](https://dev.twitch.tv/docs/api/reference#get-extension-transactions) | - | - |
+//! | [Get Custom Power-up](https://dev.twitch.tv/docs/api/reference#get-custom-power-up) | [`HelixClient::get_custom_power_ups`] | [`bits::get_custom_power_ups`] |
+//! | [Get Extension Transactions](https://dev.twitch.tv/docs/api/reference#get-extension-transactions) | [`HelixClient::get_extension_transactions`] | [`bits::get_extension_transactions`] |
 //!
 //! </details>
 //!
@@ -156,12 +156,12 @@
 //!
 //! </details>
 //!
-//! <details><summary style="cursor: pointer">Entitlements 🔴 0/2</summary>
+//! <details><summary style="cursor: pointer">Entitlements 🟢 2/2</summary>
 //!
 //! | Endpoint | Helper | Module |
 //! |---|---|---|
-//! | [Get Drops Entitlements](https://dev.twitch.tv/docs/api/reference#get-drops-entitlements) | - | - |
-//! | [Update Drops Entitlements](https://dev.twitch.tv/docs/api/reference#update-drops-entitlements) | - | - |
+//! | [Get Drops Entitlements](https://dev.twitch.tv/docs/api/reference#get-drops-entitlements) | [`HelixClient::get_drops_entitlements_for_game`] | [`entitlements::get_drops_entitlements`] |
+//! | [Update Drops Entitlements](https://dev.twitch.tv/docs/api/reference#update-drops-entitlements) | [`HelixClient::update_drops_entitlements`] | [`entitlements::update_drops_entitlements`] |
 //!
 //! </details>
 //!
@@ -175,22 +175,22 @@
 //!
 //! </details>
 //!
-//! <details><summary style="cursor: pointer">Extensions 🔴 0/12</summary>
+//! <details><summary style="cursor: pointer">Extensions 🟢 12/12</summary>
 //!
 //! | Endpoint | Helper | Module |
 //! |---|---|---|
-//! | [Get Extension Configuration Segment](https://dev.twitch.tv/docs/api/reference#get-extension-configuration-segment) | - | - |
-//! | [Set Extension Configuration Segment](https://dev.twitch.tv/docs/api/reference#set-extension-configuration-segment) | - | - |
-//! | [Set Extension Required Configuration](https://dev.twitch.tv/docs/api/reference#set-extension-required-configuration) | - | - |
-//! | [Send Extension PubSub Message](https://dev.twitch.tv/docs/api/reference#send-extension-pubsub-message) | - | - |
-//! | [Get Extension Live Channels](https://dev.twitch.tv/docs/api/reference#get-extension-live-channels) | - | - |
-//! | [Get Extension Secrets](https://dev.twitch.tv/docs/api/reference#get-extension-secrets) | - | - |
-//! | [Create Extension Secret](https://dev.twitch.tv/docs/api/reference#create-extension-secret) | - | - |
-//! | [Send Extension Chat Message](https://dev.twitch.tv/docs/api/reference#send-extension-chat-message) | - | - |
-//! | [Get Extensions](https://dev.twitch.tv/docs/api/reference#get-extensions) | - | - |
-//! | [Get Released Extensions](https://dev.twitch.tv/docs/api/reference#get-released-extensions) | - | - |
-//! | [Get Extension Bits Products](https://dev.twitch.tv/docs/api/reference#get-extension-bits-products) | - | - |
-//! | [Update Extension Bits Product](https://dev.twitch.tv/docs/api/reference#update-extension-bits-product) | - | - |
+//! | [Get Extension Configuration Segment](https://dev.twitch.tv/docs/api/reference#get-extension-configuration-segment) | [`HelixClient::get_extension_configuration_segment`] | [`extensions::get_extension_configuration_segment`] |
+//! | [Set Extension Configuration Segment](https://dev.twitch.tv/docs/api/reference#set-extension-configuration-segment) | - | [`extensions::set_extension_configuration_segment`] |
+//! | [Set Extension Required Configuration](https://dev.twitch.tv/docs/api/reference#set-extension-required-configuration) | - | [`extensions::set_extension_required_configuration`] |
+//! | [Send Extension PubSub Message](https://dev.twitch.tv/docs/api/reference#send-extension-pubsub-message) | - | [`extensions::send_extension_pubsub_message`] |
+//! | [Get Extension Live Channels](https://dev.twitch.tv/docs/api/reference#get-extension-live-channels) | [`HelixClient::get_extension_live_channels`] | [`extensions::get_extension_live_channels`] |
+//! | [Get Extension Secrets](https://dev.twitch.tv/docs/api/reference#get-extension-secrets) | [`HelixClient::get_extension_secrets`] | [`extensions::get_extension_secrets`] |
+//! | [Create Extension Secret](https://dev.twitch.tv/docs/api/reference#create-extension-secret) | - | [`extensions::create_extension_secret`] |
+//! | [Send Extension Chat Message](https://dev.twitch.tv/docs/api/reference#send-extension-chat-message) | [`HelixClient::send_extension_chat_message`] | [`extensions::send_extension_chat_message`] |
+//! | [Get Extensions](https://dev.twitch.tv/docs/api/reference#get-extensions) | [`HelixClient::get_extension`] | [`extensions::get_extensions`] |
+//! | [Get Released Extensions](https://dev.twitch.tv/docs/api/reference#get-released-extensions) | [`HelixClient::get_released_extension`] | [`extensions::get_released_extensions`] |
+//! | [Get Extension Bits Products](https://dev.twitch.tv/docs/api/reference#get-extension-bits-products) | [`HelixClient::get_extension_bits_products`] | [`extensions::get_extension_bits_products`] |
+//! | [Update Extension Bits Product](https://dev.twitch.tv/docs/api/reference#update-extension-bits-product) | - | [`extensions::update_extension_bits_product`] |
 //!
 //! </details>
 //!
@@ -211,22 +211,22 @@
 //!
 //! </details>
 //!
-//! <details><summary style="cursor: pointer">Guest Star 🔴 0/12</summary>
+//! <details><summary style="cursor: pointer">Guest Star 🟢 12/12</summary>
 //!
 //! | Endpoint | Helper | Module |
 //! |---|---|---|
-//! | [Get Channel Guest Star Settings](https://dev.twitch.tv/docs/api/reference#get-channel-guest-star-settings) | - | - |
-//! | [Update Channel Guest Star Settings](https://dev.twitch.tv/docs/api/reference#update-channel-guest-star-settings) | - | - |
-//! | [Get Guest Star Session](https://dev.twitch.tv/docs/api/reference#get-guest-star-session) | - | - |
-//! | [Create Guest Star Session](https://dev.twitch.tv/docs/api/reference#create-guest-star-session) | - | - |
-//! | [End Guest Star Session](https://dev.twitch.tv/docs/api/reference#end-guest-star-session) | - | - |
-//! | [Get Guest Star Invites](https://dev.twitch.tv/docs/api/reference#get-guest-star-invites) | - | - |
-//! | [Send Guest Star Invite](https://dev.twitch.tv/docs/api/reference#send-guest-star-invite) | - | - |
-//! | [Delete Guest Star Invite](https://dev.twitch.tv/docs/api/reference#delete-guest-star-invite) | - | - |
-//! | [Assign Guest Star Slot](https://dev.twitch.tv/docs/api/reference#assign-guest-star-slot) | - | - |
-//! | [Update Guest Star Slot](https://dev.twitch.tv/docs/api/reference#update-guest-star-slot) | - | - |
-//! | [Delete Guest Star Slot](https://dev.twitch.tv/docs/api/reference#delete-guest-star-slot) | - | - |
-//! | [Update Guest Star Slot Settings](https://dev.twitch.tv/docs/api/reference#update-guest-star-slot-settings) | - | - |
+//! | [Get Channel Guest Star Settings](https://dev.twitch.tv/docs/api/reference#get-channel-guest-star-settings) | [`HelixClient::get_channel_guest_star_settings`] | [`guest_star::get_channel_guest_star_settings`] |
+//! | [Update Channel Guest Star Settings](https://dev.twitch.tv/docs/api/reference#update-channel-guest-star-settings) | - | [`guest_star::update_channel_guest_star_settings`] |
+//! | [Get Guest Star Session](https://dev.twitch.tv/docs/api/reference#get-guest-star-session) | [`HelixClient::get_guest_star_session`] | [`guest_star::get_guest_star_session`] |
+//! | [Create Guest Star Session](https://dev.twitch.tv/docs/api/reference#create-guest-star-session) | [`HelixClient::create_guest_star_session`] | [`guest_star::create_guest_star_session`] |
+//! | [End Guest Star Session](https://dev.twitch.tv/docs/api/reference#end-guest-star-session) | [`HelixClient::end_guest_star_session`] | [`guest_star::end_guest_star_session`] |
+//! | [Get Guest Star Invites](https://dev.twitch.tv/docs/api/reference#get-guest-star-invites) | [`HelixClient::get_guest_star_invites`] | [`guest_star::get_guest_star_invites`] |
+//! | [Send Guest Star Invite](https://dev.twitch.tv/docs/api/reference#send-guest-star-invite) | [`HelixClient::send_guest_star_invite`] | [`guest_star::send_guest_star_invite`] |
+//! | [Delete Guest Star Invite](https://dev.twitch.tv/docs/api/reference#delete-guest-star-invite) | [`HelixClient::delete_guest_star_invite`] | [`guest_star::delete_guest_star_invite`] |
+//! | [Assign Guest Star Slot](https://dev.twitch.tv/docs/api/reference#assign-guest-star-slot) | [`HelixClient::assign_guest_star_slot`] | [`guest_star::assign_guest_star_slot`] |
+//! | [Update Guest Star Slot](https://dev.twitch.tv/docs/api/reference#update-guest-star-slot) | - | [`guest_star::update_guest_star_slot`] |
+//! | [Delete Guest Star Slot](https://dev.twitch.tv/docs/api/reference#delete-guest-star-slot) | [`HelixClient::delete_guest_star_slot`] | [`guest_star::delete_guest_star_slot`] |
+//! | [Update Guest Star Slot Settings](https://dev.twitch.tv/docs/api/reference#update-guest-star-slot-settings) | - | [`guest_star::update_guest_star_slot_settings`] |
 //!
 //! </details>
 //!
@@ -299,12 +299,12 @@
 //!
 //! </details>
 //!
-//! <details><summary style="cursor: pointer">Schedule 🟡 5/6</summary>
+//! <details><summary style="cursor: pointer">Schedule 🟢 6/6</summary>
 //!
 //! | Endpoint | Helper | Module |
 //! |---|---|---|
 //! | [Get Channel Stream Schedule](https://dev.twitch.tv/docs/api/reference#get-channel-stream-schedule) | - | [`schedule::get_channel_stream_schedule`] |
-//! | [Get Channel iCalendar](https://dev.twitch.tv/docs/api/reference#get-channel-icalendar) | - | - |
+//! | [Get Channel iCalendar](https://dev.twitch.tv/docs/api/reference#get-channel-icalendar) | [`HelixClient::get_channel_icalendar`] | [`schedule::get_channel_icalendar`] |
 //! | [Update Channel Stream Schedule](https://dev.twitch.tv/docs/api/reference#update-channel-stream-schedule) | - | [`schedule::update_channel_stream_schedule`] |
 //! | [Create Channel Stream Schedule Segment](https://dev.twitch.tv/docs/api/reference#create-channel-stream-schedule-segment) | - | [`schedule::create_channel_stream_schedule_segment`] |
 //! | [Update Channel Stream Schedule Segment](https://dev.twitch.tv/docs/api/reference#update-channel-stream-schedule-segment) | - | [`schedule::update_channel_stream_schedule_segment`] |
@@ -416,10 +416,14 @@ pub use endpoints::*;
 #[cfg(feature = "client")]
 #[doc(inline)]
 pub use request::errors::ClientRequestError;
+#[cfg(feature = "twitch_oauth2")]
+#[doc(inline)]
+pub use request::errors::MissingScopeError;
 #[doc(inline)]
 pub use request::errors::{
-    CreateRequestError, HelixRequestDeleteError, HelixRequestGetError, HelixRequestPatchError,
-    HelixRequestPostError, HelixRequestPutError, InvalidUri, SerializeError,
+    CreateRequestError, HelixErrorKind, HelixRequestDeleteError, HelixRequestGetError,
+    HelixRequestPatchError, HelixRequestPostError, HelixRequestPutError, InvalidUri,
+    SerializeError,
 };
 #[doc(inline)]
 pub use request::{Request, RequestDelete, RequestGet, RequestPatch, RequestPost, RequestPut};
@@ -31,19 +31,31 @@ pub trait Request: serde::Serialize + Sized {
     /// Defines layout of the url parameters.
     fn query(&self) -> Result<String, errors::SerializeError> { ser::to_string(self) }
     /// Returns full URI for the request, including query parameters.
+    ///
+    /// The URI is relative to [`TWITCH_HELIX_URL`](crate::TWITCH_HELIX_URL), use [`Request::get_uri_with_base`] to use another location.
     fn get_uri(&self) -> Result<http::Uri, InvalidUri> {
+        self.get_uri_with_base(&crate::TWITCH_HELIX_URL)
+    }
+    /// Returns full URI for the request relative to `base`, including query parameters.
+    ///
+    /// `base` should end with a `/`, e.g `https://api.twitch.tv/helix/`
+    fn get_uri_with_base(&self, base: &url::Url) -> Result<http::Uri, InvalidUri> {
         let query = self.query()?;
-        let url = crate::TWITCH_HELIX_URL
-            .join(<Self as Request>::PATH)
-            .map(|mut u| {
-                u.set_query(Some(&query));
-                u
-            })?;
+        let url = base.join(<Self as Request>::PATH).map(|mut u| {
+            u.set_query(Some(&query));
+            u
+        })?;
         http::Uri::from_str(url.as_str()).map_err(Into::into)
     }
     /// Returns bare URI for the request, NOT including query parameters.
+    ///
+    /// The URI is relative to [`TWITCH_HELIX_URL`](crate::TWITCH_HELIX_URL), use [`Request::get_bare_uri_with_base`] to use another location.
     fn get_bare_uri() -> Result<http::Uri, InvalidUri> {
-        let url = crate::TWITCH_HELIX_URL.join(<Self as Request>::PATH)?;
+        Self::get_bare_uri_with_base(&crate::TWITCH_HELIX_URL)
+    }
+    /// Returns bare URI for the request relative to `base`, NOT including query parameters.
+    fn get_bare_uri_with_base(base: &url::Url) -> Result<http::Uri, InvalidUri> {
+        let url = base.join(<Self as Request>::PATH)?;
         http::Uri::from_str(url.as_str()).map_err(Into::into)
     }
 }
@@ -108,6 +120,7 @@ pub trait RequestPost: Request {
                 status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                 message,
                 uri: uri.clone(),
+                ratelimit_reset: ratelimit_reset(response.headers()),
                 body: response.body().clone(),
             });
         }
@@ -201,6 +214,7 @@ pub trait RequestPatch: Request {
                 status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                 message,
                 uri: uri.clone(),
+                ratelimit_reset: ratelimit_reset(response.headers()),
                 body: response.body().clone(),
             });
         }
@@ -270,6 +284,7 @@ pub trait RequestDelete: Request {
                 status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                 message,
                 uri: uri.clone(),
+                ratelimit_reset: ratelimit_reset(response.headers()),
                 body: response.body().clone(),
             });
         }
@@ -346,6 +361,7 @@ pub trait RequestPut: Request {
                 status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                 message,
                 uri: uri.clone(),
+                ratelimit_reset: ratelimit_reset(response.headers()),
                 body: response.body().clone(),
             });
         }
@@ -416,6 +432,7 @@ pub trait RequestGet: Request {
                 status: status.try_into().unwrap_or(http::StatusCode::BAD_REQUEST),
                 message,
                 uri: uri.clone(),
+                ratelimit_reset: ratelimit_reset(response.headers()),
             });
         }
         <Self as RequestGet>::parse_inner_response(request, uri, text, response.status())
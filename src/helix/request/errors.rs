@@ -36,10 +36,34 @@ pub enum ClientRequestError<RE: std::error::Error + Send + Sync + 'static> {
     /// Got error from DELETE response
     #[error(transparent)]
     HelixRequestDeleteError(#[from] HelixRequestDeleteError),
+    /// Token is missing scopes needed for the request
+    #[error(transparent)]
+    MissingScopeError(#[from] MissingScopeError),
+    /// Could not refresh the token
+    #[error("could not refresh token")]
+    TokenRefreshError(
+        #[source] twitch_oauth2::tokens::errors::RefreshTokenError<crate::client::CompatError<RE>>,
+    ),
+    /// A request shared by multiple lookups of a [`BatchLoader`](crate::helix::BatchLoader) failed
+    #[error("batched request failed")]
+    BatchedRequestError(#[source] std::sync::Arc<dyn std::error::Error + Send + Sync>),
     /// Custom error
     #[error("{0}")]
     Custom(std::borrow::Cow<'static, str>),
 }
+/// Token is missing scopes needed for a request, see [`HelixClient::with_scope_check`](crate::helix::HelixClient::with_scope_check)
+#[cfg(feature = "twitch_oauth2")]
+#[cfg_attr(nightly, doc(cfg(feature = "twitch_oauth2")))]
+#[derive(thiserror::Error, Debug, Clone)]
+#[error("token is missing scopes [{}] needed for `{path}`", .missing.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(", "))]
+#[non_exhaustive]
+pub struct MissingScopeError {
+    /// The [path](crate::helix::Request::PATH) of the endpoint
+    pub path: &'static str,
+    /// The scopes that need to be added to the token
+    pub missing: Vec<twitch_oauth2::Scope>,
+}
+
 /// Could not create request
 #[derive(thiserror::Error, Debug, displaydoc::Display)]
 #[non_exhaustive]
@@ -80,6 +104,8 @@ pub enum HelixRequestGetError {
         message: String,
         /// URI to the endpoint
         uri: http::Uri,
+        /// Time when the rate limit resets, from the `Ratelimit-Reset` header
+        ratelimit_reset: Option<std::time::SystemTime>,
     },
     /// could not parse response as utf8 when calling `GET {2}`
     Utf8Error(hyper::body::Bytes, #[source] std::str::Utf8Error, http::Uri),
@@ -117,6 +143,8 @@ pub enum HelixRequestPutError {
         message: String,
         /// URI to the endpoint
         uri: http::Uri,
+        /// Time when the rate limit resets, from the `Ratelimit-Reset` header
+        ratelimit_reset: Option<std::time::SystemTime>,
         /// Body sent to PUT response
         body: hyper::body::Bytes,
     },
@@ -156,6 +184,8 @@ pub enum HelixRequestPostError {
         message: String,
         /// URI to the endpoint
         uri: http::Uri,
+        /// Time when the rate limit resets, from the `Ratelimit-Reset` header
+        ratelimit_reset: Option<std::time::SystemTime>,
         /// Body sent to POST response
         body: hyper::body::Bytes,
     },
@@ -195,6 +225,8 @@ pub enum HelixRequestPatchError {
         message: String,
         /// URI to the endpoint
         uri: http::Uri,
+        /// Time when the rate limit resets, from the `Ratelimit-Reset` header
+        ratelimit_reset: Option<std::time::SystemTime>,
         /// Body sent to POST response
         body: hyper::body::Bytes,
     },
@@ -234,6 +266,8 @@ pub enum HelixRequestDeleteError {
         message: String,
         /// URI to the endpoint
         uri: http::Uri,
+        /// Time when the rate limit resets, from the `Ratelimit-Reset` header
+        ratelimit_reset: Option<std::time::SystemTime>,
         /// Body sent to DELETE response
         body: hyper::body::Bytes,
     },
@@ -329,3 +363,254 @@ impl_request_deser_error!(
     HelixRequestPutError,
     HelixRequestDeleteError
 );
+
+/// Classification of an error returned by Helix, see [`HelixRequestGetError::kind`]
+///
+/// Helix reports most errors with a status code and a message meant for humans,
+/// this classifies them into the cases a caller can act on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HelixErrorKind {
+    /// Too many requests have been sent, `429 Too Many Requests`
+    RateLimited {
+        /// Time when the rate limit resets, if known
+        reset: Option<std::time::SystemTime>,
+    },
+    /// The token is missing a scope needed for the request
+    MissingScope,
+    /// The token is expired or otherwise invalid
+    InvalidToken,
+    /// The token is valid, but not authorized for the request, e.g. the user in the token does not match the broadcaster
+    Unauthorized,
+    /// The action was refused, e.g. the user is banned, not a moderator or the message was dropped by AutoMod
+    Forbidden,
+    /// The target of the request was not found
+    NotFound,
+    /// The target already exists or is already in the requested state, e.g. a user that is already banned
+    Conflict,
+    /// The request was malformed or is not valid for the target
+    BadRequest,
+    /// Helix failed to process the request, `5xx`
+    ServerError,
+    /// The error could not be classified
+    Other,
+}
+
+impl HelixErrorKind {
+    /// Classify an error from its status code and message.
+    pub fn classify(
+        status: http::StatusCode,
+        message: &str,
+        ratelimit_reset: Option<std::time::SystemTime>,
+    ) -> Self {
+        use http::StatusCode;
+
+        let message = message.to_ascii_lowercase();
+        match status {
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited {
+                reset: ratelimit_reset,
+            },
+            StatusCode::UNAUTHORIZED if message.contains("missing scope") => Self::MissingScope,
+            StatusCode::UNAUTHORIZED
+                if message.is_empty()
+                    || message.contains("invalid oauth token")
+                    || message.contains("invalid access token")
+                    || message.contains("expired")
+                    || message.contains("client id and oauth token do not match") =>
+            {
+                Self::InvalidToken
+            }
+            StatusCode::UNAUTHORIZED => Self::Unauthorized,
+            StatusCode::FORBIDDEN => Self::Forbidden,
+            StatusCode::NOT_FOUND => Self::NotFound,
+            StatusCode::CONFLICT => Self::Conflict,
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY
+                if message.contains("already") =>
+            {
+                Self::Conflict
+            }
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY
+                if message.contains("not found") || message.contains("does not exist") =>
+            {
+                Self::NotFound
+            }
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => Self::BadRequest,
+            status if status.is_server_error() => Self::ServerError,
+            _ => Self::Other,
+        }
+    }
+
+    /// Returns `true` if sending the same request again later might succeed.
+    pub const fn is_retryable(&self) -> bool {
+        matches!(self, Self::RateLimited { .. } | Self::ServerError)
+    }
+
+    /// Returns `true` if the error is caused by the token, and a new token or more scopes are needed.
+    pub const fn is_auth_error(&self) -> bool {
+        matches!(
+            self,
+            Self::MissingScope | Self::InvalidToken | Self::Unauthorized
+        )
+    }
+}
+
+/// Header containing the unix epoch timestamp (in seconds) when the rate limit bucket is reset to full.
+pub(crate) const RATELIMIT_RESET: &str = "ratelimit-reset";
+
+/// Parse one of the numeric `Ratelimit-*` headers.
+pub(crate) fn ratelimit_header(headers: &http::HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Get the time the rate limit resets from the `Ratelimit-Reset` header.
+pub(crate) fn ratelimit_reset(headers: &http::HeaderMap) -> Option<std::time::SystemTime> {
+    let reset = ratelimit_header(headers, RATELIMIT_RESET)?;
+    Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(reset))
+}
+
+macro_rules! impl_error_kind {
+    ($($t:ty),*) => {
+        $(impl $t {
+            /// Classify this error, see [`HelixErrorKind`].
+            pub fn kind(&self) -> HelixErrorKind {
+                match self {
+                    Self::Error {
+                        status,
+                        message,
+                        ratelimit_reset,
+                        ..
+                    } => HelixErrorKind::classify(*status, message, *ratelimit_reset),
+                    Self::DeserializeError(_, _, _, status)
+                    | Self::InvalidResponse { status, .. } => {
+                        HelixErrorKind::classify(*status, "", None)
+                    }
+                    Self::Utf8Error(..) => HelixErrorKind::Other,
+                }
+            }
+
+            /// Returns `true` if sending the same request again later might succeed, see [`HelixErrorKind::is_retryable`].
+            pub fn is_retryable(&self) -> bool { self.kind().is_retryable() }
+
+            /// Returns `true` if the error is caused by the token, see [`HelixErrorKind::is_auth_error`].
+            pub fn is_auth_error(&self) -> bool { self.kind().is_auth_error() }
+        })*
+    };
+}
+
+impl_error_kind!(
+    HelixRequestGetError,
+    HelixRequestPatchError,
+    HelixRequestPostError,
+    HelixRequestPutError,
+    HelixRequestDeleteError
+);
+
+#[cfg(feature = "client")]
+impl<RE: std::error::Error + Send + Sync + 'static> ClientRequestError<RE> {
+    /// Classify this error if it was returned by Helix, see [`HelixErrorKind`].
+    pub fn kind(&self) -> Option<HelixErrorKind> {
+        match self {
+            Self::HelixRequestGetError(e) => Some(e.kind()),
+            Self::HelixRequestPutError(e) => Some(e.kind()),
+            Self::HelixRequestPostError(e) => Some(e.kind()),
+            Self::HelixRequestPatchError(e) => Some(e.kind()),
+            Self::HelixRequestDeleteError(e) => Some(e.kind()),
+            Self::MissingScopeError(_) => Some(HelixErrorKind::MissingScope),
+            Self::BatchedRequestError(e) => e.downcast_ref::<Self>().and_then(Self::kind),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if sending the same request again later might succeed.
+    ///
+    /// Errors from the [`HttpClient`](crate::HttpClient) are assumed to be connection errors and are retryable.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::RequestError(_) | Self::HyperError(_) => true,
+            Self::BatchedRequestError(e) => {
+                e.downcast_ref::<Self>().is_some_and(Self::is_retryable)
+            }
+            _ => self.kind().is_some_and(|k| k.is_retryable()),
+        }
+    }
+
+    /// Returns `true` if the error is caused by the token, and a new token or more scopes are needed.
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            Self::TokenRefreshError(_) => true,
+            Self::BatchedRequestError(e) => {
+                e.downcast_ref::<Self>().is_some_and(Self::is_auth_error)
+            }
+            _ => self.kind().is_some_and(|k| k.is_auth_error()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify() {
+        use http::StatusCode;
+
+        let reset = Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000));
+        assert_eq!(
+            HelixErrorKind::classify(StatusCode::TOO_MANY_REQUESTS, "", reset),
+            HelixErrorKind::RateLimited { reset }
+        );
+        assert_eq!(
+            HelixErrorKind::classify(
+                StatusCode::UNAUTHORIZED,
+                "Missing scope: moderator:manage:banned_users",
+                None
+            ),
+            HelixErrorKind::MissingScope
+        );
+        assert_eq!(
+            HelixErrorKind::classify(StatusCode::UNAUTHORIZED, "Invalid OAuth token", None),
+            HelixErrorKind::InvalidToken
+        );
+        assert_eq!(
+            HelixErrorKind::classify(
+                StatusCode::UNAUTHORIZED,
+                "The ID in broadcaster_id must match the user ID found in the request's OAuth token.",
+                None
+            ),
+            HelixErrorKind::Unauthorized
+        );
+        assert_eq!(
+            HelixErrorKind::classify(
+                StatusCode::BAD_REQUEST,
+                "The user specified in the user_id field is already banned.",
+                None
+            ),
+            HelixErrorKind::Conflict
+        );
+        assert_eq!(
+            HelixErrorKind::classify(StatusCode::FORBIDDEN, "The user is not a moderator.", None),
+            HelixErrorKind::Forbidden
+        );
+        assert_eq!(
+            HelixErrorKind::classify(StatusCode::BAD_GATEWAY, "", None),
+            HelixErrorKind::ServerError
+        );
+        assert!(HelixErrorKind::ServerError.is_retryable());
+        assert!(HelixErrorKind::MissingScope.is_auth_error());
+        assert!(!HelixErrorKind::Conflict.is_retryable());
+    }
+
+    #[test]
+    fn ratelimit_reset_header() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            "Ratelimit-Reset",
+            http::HeaderValue::from_static("1700000000"),
+        );
+        assert_eq!(
+            ratelimit_reset(&headers),
+            Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000))
+        );
+        assert_eq!(ratelimit_reset(&http::HeaderMap::new()), None);
+    }
+}
@@ -16,6 +16,11 @@ where
     ///
     /// See for example [Get Broadcaster Subscriptions](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions) which returns this.
     pub other: Option<serde_json::Map<String, serde_json::Value>>,
+    /// Amount of attempts [`HelixClient`](super::HelixClient) needed to get this response.
+    ///
+    /// This is more than `1` when a [`RetryPolicy`](super::RetryPolicy) retried the request or a [`TokenRefresher`](super::TokenRefresher) sent it again with a refreshed token,
+    /// and `0` when the response was served from a [`ResponseCache`](super::ResponseCache).
+    pub attempts: usize,
 }
 
 impl<R, D> Eq for Response<R, D>
@@ -95,6 +100,7 @@ where
             data,
             pagination_data,
             other,
+            attempts: 1,
         }
     }
 
@@ -100,11 +100,13 @@
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>helix</code></span> | Enables [Helix](helix) endpoints |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>eventsub</code></span> | Enables deserializable structs for [EventSub](eventsub) |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>hmac</code></span> | Enable [message authentication](eventsub::Event::verify_payload) using HMAC on [EventSub](eventsub) |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>eventsub_websocket</code></span> | Enables a [client](eventsub::websocket::Client) for [EventSub](eventsub) WebSockets using `tokio-tungstenite`. Note that this does not enable any TLS backend, enable one of the TLS features of `tokio-tungstenite` |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>pubsub_websocket</code></span> | Enables a [client](pubsub::Client) for [PubSub](pubsub) using `tokio-tungstenite`. Note that this does not enable any TLS backend, enable one of the TLS features of `tokio-tungstenite` |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>time</code></span> | Enable time utilities on [Timestamp](types::Timestamp) |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>all</code></span> | Enables all above features. Do not use this in production, it's better if you specify exactly what you need |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>ureq</code></span> | Enables ureq for [`HttpClient`]. |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>reqwest</code></span> | Enables reqwest for [`HttpClient`]. Note that this does not enable any default TLS backend, if you get `invalid URL, scheme is not http`, specify `reqwest` in your Cargo.toml. By default, `reqwest` uses feature `default-tls` |
-//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>tower</code></span> | Enables using [tower services](client::TowerService) for [`HttpClient`]. |
+//! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>tower</code></span> | Enables using [tower services](client::TowerService) for [`HttpClient`]. With `eventsub` and `hmac`, also enables a [service for receiving EventSub webhooks](eventsub::webhook::WebhookService). |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>beta</code></span> | Enables beta endpoints, topics or features. Breakage may occur, semver compatibility not guaranteed. |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>unsupported</code></span> | Enables undocumented or experimental endpoints, including beta endpoints, topics or features. Breakage may occur, semver compatibility not guaranteed. |
 //! | <span class="module-item stab portability" style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"><code>trace_unknown_fields</code></span> | Logs ignored fields as `WARN` log messages where  applicable. Please consider using this and filing an issue or PR when a new field has been added to the endpoint but not added to this library. |
@@ -174,6 +176,8 @@ macro_rules! mock_env_url {
 
 /// Location of Twitch Helix
 ///
+/// This is the default location used by requests. To use another location for a specific client, see [`HelixClient::with_base_url`](crate::helix::HelixClient::with_base_url).
+///
 /// Can be overridden when feature `mock_api` is enabled with environment variable `TWITCH_HELIX_URL`.
 ///
 /// # Examples
@@ -415,4 +419,75 @@ pub mod tests {
         )
         .expect("could not convert back from cbor");
     }
+
+    /// A [`HttpClient`](crate::HttpClient) responding with queued responses in order, recording the requests it gets.
+    #[cfg(all(feature = "helix", feature = "client"))]
+    #[derive(Default)]
+    pub struct MockClient {
+        responses: std::sync::Mutex<std::collections::VecDeque<(u16, String)>>,
+        /// The method, path and body of the requests made
+        pub requests: std::sync::Mutex<Vec<(http::Method, String, String)>>,
+    }
+
+    #[cfg(all(feature = "helix", feature = "client"))]
+    impl MockClient {
+        /// Create a [`HelixClient`](crate::HelixClient) responding with `responses`
+        pub fn helix(
+            responses: impl IntoIterator<Item = (u16, impl Into<String>)>,
+        ) -> crate::HelixClient<'static, Self> {
+            let client = Self::default();
+            client
+                .responses
+                .lock()
+                .unwrap()
+                .extend(responses.into_iter().map(|(s, b)| (s, b.into())));
+            crate::HelixClient::with_client(client)
+        }
+    }
+
+    /// Error returned by [`MockClient`] when there are no responses left
+    #[cfg(all(feature = "helix", feature = "client"))]
+    #[derive(Debug, thiserror::Error)]
+    #[error("no response left")]
+    pub struct NoResponse;
+
+    #[cfg(all(feature = "helix", feature = "client"))]
+    impl crate::HttpClient for MockClient {
+        type Error = NoResponse;
+
+        fn req(
+            &self,
+            request: crate::client::Request,
+        ) -> impl std::future::Future<Output = Result<crate::client::Response, Self::Error>> + Send + use<>
+        {
+            self.requests.lock().unwrap().push((
+                request.method().clone(),
+                request.uri().path().to_owned(),
+                String::from_utf8(request.body().to_vec()).unwrap(),
+            ));
+            let response = self.responses.lock().unwrap().pop_front();
+            async move {
+                let (status, body) = response.ok_or(NoResponse)?;
+                Ok(http::Response::builder()
+                    .status(status)
+                    .body(body.into())
+                    .unwrap())
+            }
+        }
+    }
+
+    /// A user token which is not validated
+    #[cfg(feature = "twitch_oauth2")]
+    pub fn token() -> twitch_oauth2::UserToken {
+        twitch_oauth2::UserToken::from_existing_unchecked(
+            twitch_oauth2::AccessToken::new("totallyvalidtoken".to_string()),
+            None,
+            twitch_oauth2::ClientId::new("validclientid".to_string()),
+            None,
+            "justintv".into(),
+            "1337".into(),
+            None,
+            None,
+        )
+    }
 }
@@ -0,0 +1,592 @@
+//! Client for [PubSub](https://dev.twitch.tv/docs/pubsub)
+//!
+//! The [`Client`] manages the connections to PubSub for you:
+//!
+//! * Topics are spread over multiple connections, as a connection can only listen to 50 topics.
+//! * [`Client::listen`] waits for the response to the `LISTEN` message, and fails with a [`ListenError`] if Twitch rejected it.
+//! * A `PING` is sent every 4 minutes. If Twitch doesn't answer within 10 seconds, the connection is assumed lost.
+//! * Lost connections and connections Twitch asks to `RECONNECT` are replaced, and their topics are listened to again.
+//!
+//! # Notes
+//!
+//! This does not enable a TLS backend for [`tokio-tungstenite`](https://docs.rs/tokio-tungstenite), to connect to Twitch you need to enable one of its TLS features yourself,
+//! e.g `native-tls` or `rustls-tls-webpki-roots`.
+//!
+//! # Examples
+//!
+//! ```rust, no_run
+//! use futures::StreamExt;
+//! use twitch_api::pubsub::{self, Topic as _};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::UserToken::from_existing_unchecked(
+//! #     twitch_oauth2::AccessToken::new("totallyvalidtoken".to_string()), None,
+//! #     twitch_oauth2::ClientId::new("validclientid".to_string()), None, "justintv".into(), "1337".into(), None, None);
+//! let mut client = pubsub::Client::new(&token);
+//! client
+//!     .listen([pubsub::channel_bits::ChannelBitsEventsV2 { channel_id: 1337 }.into_topic()])
+//!     .await?;
+//!
+//! let mut messages = client.into_stream();
+//! while let Some(message) = messages.next().await {
+//!     if let pubsub::TopicData::ChannelBitsEventsV2 { reply, .. } = message {
+//!         println!("{reply:?}");
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{collections::VecDeque, time::Duration};
+
+use futures::{SinkExt, StreamExt};
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite;
+use twitch_oauth2::TwitchToken;
+
+use super::{listen_command, unlisten_command, Response, TopicData, Topics};
+
+type Socket =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// Maximum amount of topics on one connection
+const MAX_TOPICS: usize = 50;
+/// How often to ping Twitch, Twitch asks for at least once every 5 minutes
+const PING_INTERVAL: Duration = Duration::from_secs(4 * 60);
+/// How long to wait for a `PONG` after a `PING`
+const PONG_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long to wait for the response to a `LISTEN` or `UNLISTEN`
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A client for PubSub, see the [module documentation](self)
+pub struct Client<'a, T>
+where T: TwitchToken + Send + Sync + ?Sized {
+    token: &'a T,
+    url: url::Url,
+    connections: Vec<Connection>,
+    /// Messages received while waiting for a response
+    buffered: VecDeque<TopicData>,
+    /// Counter for nonces
+    nonce: u64,
+}
+
+impl<'a, T> Client<'a, T>
+where T: TwitchToken + Send + Sync + ?Sized
+{
+    /// Create a new client that connects to [`TWITCH_PUBSUB_URL`](crate::TWITCH_PUBSUB_URL).
+    ///
+    /// Nothing happens until topics are listened to with [`Client::listen`].
+    pub fn new(token: &'a T) -> Self {
+        Self {
+            token,
+            url: crate::TWITCH_PUBSUB_URL.clone(),
+            connections: vec![],
+            buffered: VecDeque::new(),
+            nonce: 0,
+        }
+    }
+
+    /// Connect to another location than [`TWITCH_PUBSUB_URL`](crate::TWITCH_PUBSUB_URL)
+    pub fn with_url(mut self, url: url::Url) -> Self {
+        self.url = url;
+        self
+    }
+
+    /// Get all topics that are listened to
+    pub fn topics(&self) -> impl Iterator<Item = &Topics> + '_ {
+        self.connections.iter().flat_map(|c| c.topics.iter())
+    }
+
+    /// Listen to `topics`, opening new connections if needed.
+    ///
+    /// Topics that are already listened to are skipped.
+    /// Returns once Twitch has confirmed all topics, messages received in the meantime are returned by [`Client::next_message`].
+    pub async fn listen(
+        &mut self,
+        topics: impl IntoIterator<Item = Topics>,
+    ) -> Result<(), ClientError> {
+        let mut new: Vec<Topics> = vec![];
+        for topic in topics {
+            if !new.contains(&topic) && !self.topics().any(|t| t == &topic) {
+                new.push(topic);
+            }
+        }
+        while !new.is_empty() {
+            let index = match self
+                .connections
+                .iter()
+                .position(|c| c.topics.len() < MAX_TOPICS)
+            {
+                Some(index) => index,
+                None => {
+                    self.connections.push(Connection::connect(&self.url).await?);
+                    self.connections.len() - 1
+                }
+            };
+            let space = MAX_TOPICS - self.connections[index].topics.len();
+            let topics: Vec<Topics> = new.drain(..space.min(new.len())).collect();
+            self.listen_on(index, topics).await?;
+        }
+        Ok(())
+    }
+
+    /// Stop listening to `topics`, closing connections that are no longer needed.
+    pub async fn unlisten(
+        &mut self,
+        topics: impl IntoIterator<Item = Topics>,
+    ) -> Result<(), ClientError> {
+        let topics: Vec<Topics> = topics.into_iter().collect();
+        let mut index = 0;
+        while index < self.connections.len() {
+            let remove: Vec<Topics> = self.connections[index]
+                .topics
+                .iter()
+                .filter(|t| topics.contains(t))
+                .cloned()
+                .collect();
+            if remove.is_empty() {
+                index += 1;
+                continue;
+            }
+            let connection = &mut self.connections[index];
+            connection.topics.retain(|t| !remove.contains(t));
+            if connection.topics.is_empty() {
+                let mut connection = self.connections.remove(index);
+                let _ = connection.socket.close(None).await;
+                continue;
+            }
+            self.nonce += 1;
+            let nonce = self.nonce.to_string();
+            let command = unlisten_command(&remove, nonce.as_str())?;
+            self.connections[index]
+                .request(command, &nonce, &mut self.buffered)
+                .await
+                .map_err(|error| error.with_topics(&remove))?;
+            index += 1;
+        }
+        Ok(())
+    }
+
+    /// Wait for the next message on any topic.
+    ///
+    /// Lost connections are replaced, if that fails the error is returned and the connection is replaced on the next call.
+    pub async fn next_message(&mut self) -> Result<TopicData, ClientError> {
+        loop {
+            if let Some(data) = self.buffered.pop_front() {
+                return Ok(data);
+            }
+            if self.connections.is_empty() {
+                return Err(ClientError::NotListening);
+            }
+            if let Some(index) = self.connections.iter().position(|c| c.reconnect) {
+                self.reconnect(index).await?;
+                continue;
+            }
+
+            let (index, response) = futures::future::select_all(
+                self.connections
+                    .iter_mut()
+                    .enumerate()
+                    .map(|(i, c)| Box::pin(async move { (i, c.next_response().await) })),
+            )
+            .await
+            .0;
+
+            match response {
+                Ok(Response::Message { data }) => return Ok(data),
+                Ok(Response::Reconnect) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!("twitch asked to reconnect");
+                    self.connections[index].reconnect = true;
+                }
+                // responses to requests that timed out
+                Ok(_) => (),
+                Err(ClientError::Parse(error)) => return Err(ClientError::Parse(error)),
+                Err(_error) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(error = %_error, "pubsub connection lost");
+                    self.connections[index].reconnect = true;
+                }
+            }
+        }
+    }
+
+    /// Get all messages as a stream.
+    ///
+    /// Messages that can't be parsed are skipped. The stream ends on errors that can't be recovered from,
+    /// e.g. when reconnecting fails. Use [`Client::next_message`] to handle these errors yourself.
+    pub fn into_stream(self) -> impl futures::Stream<Item = TopicData> + Send + Unpin + 'a
+    where T: 'a {
+        futures::stream::unfold(self, |mut client| async move {
+            loop {
+                match client.next_message().await {
+                    Ok(data) => return Some((data, client)),
+                    Err(ClientError::Parse(_error)) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(error = %_error, "could not parse pubsub message");
+                    }
+                    Err(_error) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::error!(error = %_error, "pubsub client failed");
+                        return None;
+                    }
+                }
+            }
+        })
+        .boxed()
+    }
+
+    /// Listen to `topics` on the connection at `index`
+    async fn listen_on(&mut self, index: usize, topics: Vec<Topics>) -> Result<(), ClientError> {
+        self.nonce += 1;
+        let nonce = self.nonce.to_string();
+        let command = listen_command(&topics, self.token.token().secret(), nonce.as_str())?;
+        let connection = &mut self.connections[index];
+        match connection
+            .request(command, &nonce, &mut self.buffered)
+            .await
+        {
+            Ok(()) => {
+                connection.topics.extend(topics);
+                Ok(())
+            }
+            Err(error @ ClientError::Listen { .. }) => {
+                if connection.topics.is_empty() {
+                    let mut connection = self.connections.remove(index);
+                    let _ = connection.socket.close(None).await;
+                }
+                Err(error.with_topics(&topics))
+            }
+            Err(_error) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %_error, "pubsub connection lost while listening");
+                // listen to the topics on a new connection instead
+                connection.topics.extend(topics);
+                self.reconnect(index).await
+            }
+        }
+    }
+
+    /// Replace the connection at `index` with a new one listening to the same topics
+    async fn reconnect(&mut self, index: usize) -> Result<(), ClientError> {
+        let topics = self.connections[index].topics.clone();
+        let mut connection = Connection::connect(&self.url).await?;
+        self.nonce += 1;
+        let nonce = self.nonce.to_string();
+        let command = listen_command(&topics, self.token.token().secret(), nonce.as_str())?;
+        connection
+            .request(command, &nonce, &mut self.buffered)
+            .await
+            .map_err(|error| error.with_topics(&topics))?;
+        connection.topics = topics;
+        let mut old = std::mem::replace(&mut self.connections[index], connection);
+        let _ = old.socket.close(None).await;
+        Ok(())
+    }
+}
+
+impl<T> std::fmt::Debug for Client<'_, T>
+where T: TwitchToken + Send + Sync + ?Sized
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("url", &self.url)
+            .field("topics", &self.topics().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Errors from the PubSub [`Client`]
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+#[non_exhaustive]
+pub enum ClientError {
+    /// could not connect to {0}
+    Connect(String, #[source] Box<tungstenite::Error>),
+    /// websocket connection failed
+    Connection(#[source] Box<tungstenite::Error>),
+    /// websocket connection was closed
+    Closed,
+    /// twitch did not answer a ping in time
+    PongTimeout,
+    /// twitch did not answer a request in time
+    MissingResponse,
+    /// not listening to any topics
+    NotListening,
+    /// could not parse message
+    Parse(#[from] crate::DeserError),
+    /// could not serialize command
+    Serialize(#[from] serde_json::Error),
+    /// twitch rejected the request
+    Listen {
+        /// The topics in the request
+        topics: Vec<Topics>,
+        /// The error returned by twitch
+        #[source]
+        source: ListenError,
+    },
+}
+
+impl ClientError {
+    /// Set the topics of a [`ClientError::Listen`]
+    fn with_topics(self, topics: &[Topics]) -> Self {
+        match self {
+            Self::Listen { source, .. } => Self::Listen {
+                topics: topics.to_vec(),
+                source,
+            },
+            error => error,
+        }
+    }
+}
+
+/// Error returned by Twitch in response to a `LISTEN` or `UNLISTEN`
+#[derive(thiserror::Error, Debug, displaydoc::Display, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ListenError {
+    /// the token is invalid or is missing scopes needed for the topics
+    BadAuth,
+    /// a topic is invalid
+    BadTopic,
+    /// the message was malformed
+    BadMessage,
+    /// twitch had an internal error
+    Server,
+    /// twitch returned an unknown error: {0}
+    Other(String),
+}
+
+impl ListenError {
+    fn new(error: String) -> Self {
+        match error.as_str() {
+            "ERR_BADAUTH" => Self::BadAuth,
+            "ERR_BADTOPIC" => Self::BadTopic,
+            "ERR_BADMESSAGE" => Self::BadMessage,
+            "ERR_SERVER" => Self::Server,
+            _ => Self::Other(error),
+        }
+    }
+}
+
+struct Connection {
+    socket: Socket,
+    topics: Vec<Topics>,
+    next_ping: Instant,
+    /// When the connection is considered lost if no `PONG` was received
+    pong_deadline: Option<Instant>,
+    /// Twitch asked to reconnect, or the connection was lost
+    reconnect: bool,
+}
+
+impl Connection {
+    async fn connect(url: &url::Url) -> Result<Self, ClientError> {
+        let (socket, _) = tokio_tungstenite::connect_async(url.as_str())
+            .await
+            .map_err(|e| ClientError::Connect(url.to_string(), Box::new(e)))?;
+        Ok(Self {
+            socket,
+            topics: vec![],
+            next_ping: Instant::now() + PING_INTERVAL,
+            pong_deadline: None,
+            reconnect: false,
+        })
+    }
+
+    /// Send `command` and wait for the response with `nonce`, buffering messages received in the meantime
+    async fn request(
+        &mut self,
+        command: String,
+        nonce: &str,
+        buffered: &mut VecDeque<TopicData>,
+    ) -> Result<(), ClientError> {
+        self.send(command).await?;
+        let response = tokio::time::timeout(RESPONSE_TIMEOUT, async {
+            loop {
+                match self.next_response().await {
+                    Ok(Response::Response(response))
+                        if response.nonce.as_deref() == Some(nonce) =>
+                    {
+                        return Ok(response)
+                    }
+                    Ok(Response::Message { data }) => buffered.push_back(data),
+                    Ok(Response::Reconnect) => self.reconnect = true,
+                    Ok(_) => (),
+                    Err(ClientError::Parse(_error)) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(error = %_error, "could not parse pubsub message");
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+        })
+        .await
+        .map_err(|_| ClientError::MissingResponse)??;
+        match response.error {
+            Some(error) if !error.is_empty() => Err(ClientError::Listen {
+                topics: vec![],
+                source: ListenError::new(error),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Receive the next response, sending a `PING` when needed. `PONG`s are handled here.
+    async fn next_response(&mut self) -> Result<Response, ClientError> {
+        loop {
+            let deadline = self.pong_deadline.unwrap_or(self.next_ping);
+            tokio::select! {
+                text = Self::recv(&mut self.socket) => {
+                    let response = Response::parse(&text?)?;
+                    if matches!(response, Response::Pong) {
+                        self.pong_deadline = None;
+                        self.next_ping = Instant::now() + PING_INTERVAL;
+                        continue;
+                    }
+                    return Ok(response);
+                }
+                _ = tokio::time::sleep_until(deadline) => {
+                    if self.pong_deadline.is_some() {
+                        return Err(ClientError::PongTimeout);
+                    }
+                    self.send(r#"{"type":"PING"}"#.to_owned()).await?;
+                    self.pong_deadline = Some(Instant::now() + PONG_TIMEOUT);
+                }
+            }
+        }
+    }
+
+    async fn send(&mut self, text: String) -> Result<(), ClientError> {
+        self.socket
+            .send(tungstenite::Message::text(text))
+            .await
+            .map_err(|e| ClientError::Connection(Box::new(e)))
+    }
+
+    /// Receive the next text frame. Websocket pings are answered by tungstenite.
+    async fn recv(socket: &mut Socket) -> Result<String, ClientError> {
+        loop {
+            match socket.next().await {
+                Some(Ok(tungstenite::Message::Text(text))) => return Ok(text.to_string()),
+                Some(Ok(tungstenite::Message::Close(_))) | None => return Err(ClientError::Closed),
+                Some(Ok(_)) => (),
+                Some(Err(e)) => return Err(ClientError::Connection(Box::new(e))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pubsub::Topic as _, tests::token};
+    use tokio::net::TcpListener;
+    use tungstenite::Message as WsMessage;
+
+    fn bits(channel_id: u32) -> Topics {
+        crate::pubsub::channel_bits::ChannelBitsEventsV2 { channel_id }.into_topic()
+    }
+
+    fn bits_event(channel_id: u32) -> WsMessage {
+        WsMessage::text(format!(
+            r#"{{"type":"MESSAGE","data":{{"topic":"channel-bits-events-v2.{channel_id}","message":"{{\"data\":{{\"user_name\":\"justintv\",\"channel_name\":\"tmi\",\"user_id\":\"12345\",\"channel_id\":\"{channel_id}\",\"time\":\"2020-10-19T17:50:24.807841596Z\",\"chat_message\":\"Corgo1\",\"bits_used\":5,\"total_bits_used\":29,\"is_anonymous\":false,\"context\":\"cheer\",\"badge_entitlement\":null}},\"version\":\"1.0\",\"message_type\":\"bits_event\",\"message_id\":\"d1831817-95f2-5dfa-8864-f36f16eeb5d8\"}}"}}}}"#
+        ))
+    }
+
+    /// Receive a request, answering it with `error` and returning its topics
+    async fn respond(socket: &mut Socket, error: &str) -> Vec<String> {
+        let Some(Ok(WsMessage::Text(text))) = socket.next().await else {
+            panic!("expected a request");
+        };
+        let request: serde_json::Value = serde_json::from_str(&text).unwrap();
+        socket
+            .send(WsMessage::text(format!(
+                r#"{{"type":"RESPONSE","nonce":"{}","error":"{error}"}}"#,
+                request["nonce"].as_str().unwrap()
+            )))
+            .await
+            .unwrap();
+        request["data"]["topics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t.as_str().unwrap().to_owned())
+            .collect()
+    }
+
+    async fn listen() -> (TcpListener, url::Url) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        (listener, url)
+    }
+
+    async fn accept(listener: &TcpListener) -> Socket {
+        let (stream, _) = listener.accept().await.unwrap();
+        tokio_tungstenite::accept_async(tokio_tungstenite::MaybeTlsStream::Plain(stream))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn listen_and_reconnect() {
+        let (listener, url) = listen().await;
+
+        let server = tokio::spawn(async move {
+            let mut first = accept(&listener).await;
+            assert_eq!(respond(&mut first, "").await.len(), MAX_TOPICS);
+            let mut second = accept(&listener).await;
+            assert_eq!(respond(&mut second, "").await.len(), 1);
+
+            second.send(bits_event(1)).await.unwrap();
+            first
+                .send(WsMessage::text(r#"{"type":"RECONNECT"}"#))
+                .await
+                .unwrap();
+            // the topics of the first connection are listened to again
+            let mut replacement = accept(&listener).await;
+            assert_eq!(respond(&mut replacement, "").await.len(), MAX_TOPICS);
+            replacement.send(bits_event(2)).await.unwrap();
+            (first, second, replacement)
+        });
+
+        let token = token();
+        let mut client = Client::new(&token).with_url(url);
+        client.listen((1..=51).map(bits)).await.unwrap();
+        assert_eq!(client.connections.len(), 2);
+
+        let mut channels = vec![];
+        for _ in 0..2 {
+            let TopicData::ChannelBitsEventsV2 { topic, .. } = client.next_message().await.unwrap()
+            else {
+                panic!("unexpected message")
+            };
+            channels.push(topic.channel_id);
+        }
+        // the connections are independent, so the order of the messages is not guaranteed
+        channels.sort_unstable();
+        assert_eq!(channels, [1, 2]);
+        assert_eq!(client.topics().count(), 51);
+        drop(server.await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn bad_auth() {
+        let (listener, url) = listen().await;
+
+        let server = tokio::spawn(async move {
+            let mut socket = accept(&listener).await;
+            respond(&mut socket, "ERR_BADAUTH").await;
+            socket
+        });
+
+        let token = token();
+        let mut client = Client::new(&token).with_url(url);
+        let error = client.listen([bits(1)]).await.unwrap_err();
+        assert!(matches!(
+            error,
+            ClientError::Listen { topics, source: ListenError::BadAuth } if topics == [bits(1)]
+        ));
+        assert!(client.connections.is_empty());
+        drop(server.await.unwrap());
+    }
+}
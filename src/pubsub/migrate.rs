@@ -0,0 +1,702 @@
+#![doc(alias = "eventsub")]
+//! Migrating from PubSub to EventSub
+//!
+//! PubSub has been decommissioned by Twitch in favor of [EventSub](crate::eventsub).
+//! This module helps consumers of PubSub messages to migrate gradually:
+//! [`subscriptions`] gives the EventSub subscriptions replacing a PubSub [topic](Topics),
+//! and [`IntoPubSub`] and [`topic_data`] convert the EventSub notifications back into the messages PubSub would have sent.
+//!
+//! The conversion is lossy, fields that don't exist in EventSub are filled with empty or default values,
+//! and events that PubSub has no equivalent for, or that EventSub doesn't have enough information for, return [`MigrateError::Unsupported`].
+//!
+//! | PubSub topic | EventSub subscriptions |
+//! | ------------ | ---------------------- |
+//! | [`ChannelBitsEventsV2`](channel_bits::ChannelBitsEventsV2) | [`ChannelCheerV1`](channel::ChannelCheerV1) |
+//! | [`ChannelPointsChannelV1`](channel_points::ChannelPointsChannelV1) | [`ChannelPointsCustomRewardRedemptionAddV1`](channel::ChannelPointsCustomRewardRedemptionAddV1), [`ChannelPointsCustomRewardRedemptionUpdateV1`](channel::ChannelPointsCustomRewardRedemptionUpdateV1) |
+//! | [`ChannelSubscribeEventsV1`](channel_subscriptions::ChannelSubscribeEventsV1) | [`ChannelSubscribeV1`](channel::ChannelSubscribeV1), [`ChannelSubscriptionMessageV1`](channel::ChannelSubscriptionMessageV1) |
+//! | [`ChatModeratorActions`](moderation::ChatModeratorActions) | [`ChannelModerateV2`](channel::ChannelModerateV2) |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use twitch_api::{
+//!     eventsub::{self, Event},
+//!     pubsub::{self, migrate, TopicData},
+//! };
+//!
+//! let topic = pubsub::channel_bits::ChannelBitsEventsV2 { channel_id: 1234 };
+//! for subscription in migrate::subscriptions(&topic.into()) {
+//!     println!(
+//!         "subscribe to {} version {}",
+//!         subscription.event_type(),
+//!         subscription.version()
+//!     );
+//! }
+//! # let event = Event::parse(r#"{"subscription":{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","type":"channel.cheer","version":"1","status":"enabled","cost":0,"condition":{"broadcaster_user_id":"1234"},"transport":{"method":"webhook","callback":"https://example.com/webhooks/callback"},"created_at":"2019-11-16T10:11:12.634234626Z"},"event":{"is_anonymous":false,"user_id":"1234","user_login":"cool_user","user_name":"Cool_User","broadcaster_user_id":"1234","broadcaster_user_login":"cooler_user","broadcaster_user_name":"Cooler_User","message":"pogchamp","bits":1000}}"#)?;
+//! # let timestamp = twitch_api::types::Timestamp::from_static("2019-11-16T10:11:12.634234626Z");
+//! // handle events as if they came from PubSub
+//! match migrate::topic_data(event, &timestamp) {
+//!     Ok(TopicData::ChannelBitsEventsV2 { reply, .. }) => println!("{reply:?}"),
+//!     Ok(_) => {}
+//!     Err(migrate::MigrateError::Unsupported) => {}
+//!     Err(e) => return Err(e.into()),
+//! }
+//! # Ok::<(), Box<dyn std::error::Error + 'static>>(())
+//! ```
+use serde_json::json;
+
+use super::{channel_bits, channel_points, channel_subscriptions, moderation, TopicData, Topics};
+use crate::{
+    common::calendar,
+    eventsub::{self, channel, EventSubscription, EventType},
+    types,
+};
+
+/// Get the EventSub subscriptions replacing `topic`.
+///
+/// Returns an empty list if there is no replacement for the topic in this module.
+pub fn subscriptions(topic: &Topics) -> Vec<Subscription> {
+    match topic {
+        Topics::ChannelBitsEventsV2(topic) => vec![Subscription::ChannelCheerV1(
+            channel::ChannelCheerV1::broadcaster_user_id(topic.channel_id.to_string()),
+        )],
+        Topics::ChannelPointsChannelV1(topic) => vec![
+            Subscription::ChannelPointsCustomRewardRedemptionAddV1(
+                channel::ChannelPointsCustomRewardRedemptionAddV1::broadcaster_user_id(
+                    topic.channel_id.to_string(),
+                ),
+            ),
+            Subscription::ChannelPointsCustomRewardRedemptionUpdateV1(
+                channel::ChannelPointsCustomRewardRedemptionUpdateV1::broadcaster_user_id(
+                    topic.channel_id.to_string(),
+                ),
+            ),
+        ],
+        Topics::ChannelSubscribeEventsV1(topic) => vec![
+            Subscription::ChannelSubscribeV1(channel::ChannelSubscribeV1::broadcaster_user_id(
+                topic.channel_id.to_string(),
+            )),
+            Subscription::ChannelSubscriptionMessageV1(
+                channel::ChannelSubscriptionMessageV1::broadcaster_user_id(
+                    topic.channel_id.to_string(),
+                ),
+            ),
+        ],
+        Topics::ChatModeratorActions(topic) => {
+            vec![Subscription::ChannelModerateV2(
+                channel::ChannelModerateV2::new(
+                    topic.channel_id.to_string(),
+                    topic.user_id.to_string(),
+                ),
+            )]
+        }
+        _ => vec![],
+    }
+}
+
+/// An EventSub subscription replacing a PubSub topic, see [`subscriptions`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Subscription {
+    /// Replaces [`ChannelBitsEventsV2`](channel_bits::ChannelBitsEventsV2)
+    ChannelCheerV1(channel::ChannelCheerV1),
+    /// Replaces [`ChannelPointsChannelV1`](channel_points::ChannelPointsChannelV1)
+    ChannelPointsCustomRewardRedemptionAddV1(channel::ChannelPointsCustomRewardRedemptionAddV1),
+    /// Replaces [`ChannelPointsChannelV1`](channel_points::ChannelPointsChannelV1)
+    ChannelPointsCustomRewardRedemptionUpdateV1(
+        channel::ChannelPointsCustomRewardRedemptionUpdateV1,
+    ),
+    /// Replaces [`ChannelSubscribeEventsV1`](channel_subscriptions::ChannelSubscribeEventsV1)
+    ChannelSubscribeV1(channel::ChannelSubscribeV1),
+    /// Replaces [`ChannelSubscribeEventsV1`](channel_subscriptions::ChannelSubscribeEventsV1)
+    ChannelSubscriptionMessageV1(channel::ChannelSubscriptionMessageV1),
+    /// Replaces [`ChatModeratorActions`](moderation::ChatModeratorActions)
+    ChannelModerateV2(channel::ChannelModerateV2),
+}
+
+impl Subscription {
+    /// Event type of the subscription
+    pub const fn event_type(&self) -> EventType {
+        match self {
+            Self::ChannelCheerV1(_) => channel::ChannelCheerV1::EVENT_TYPE,
+            Self::ChannelPointsCustomRewardRedemptionAddV1(_) => {
+                channel::ChannelPointsCustomRewardRedemptionAddV1::EVENT_TYPE
+            }
+            Self::ChannelPointsCustomRewardRedemptionUpdateV1(_) => {
+                channel::ChannelPointsCustomRewardRedemptionUpdateV1::EVENT_TYPE
+            }
+            Self::ChannelSubscribeV1(_) => channel::ChannelSubscribeV1::EVENT_TYPE,
+            Self::ChannelSubscriptionMessageV1(_) => {
+                channel::ChannelSubscriptionMessageV1::EVENT_TYPE
+            }
+            Self::ChannelModerateV2(_) => channel::ChannelModerateV2::EVENT_TYPE,
+        }
+    }
+
+    /// Version of the subscription
+    pub const fn version(&self) -> &'static str {
+        match self {
+            Self::ChannelCheerV1(_) => channel::ChannelCheerV1::VERSION,
+            Self::ChannelPointsCustomRewardRedemptionAddV1(_) => {
+                channel::ChannelPointsCustomRewardRedemptionAddV1::VERSION
+            }
+            Self::ChannelPointsCustomRewardRedemptionUpdateV1(_) => {
+                channel::ChannelPointsCustomRewardRedemptionUpdateV1::VERSION
+            }
+            Self::ChannelSubscribeV1(_) => channel::ChannelSubscribeV1::VERSION,
+            Self::ChannelSubscriptionMessageV1(_) => channel::ChannelSubscriptionMessageV1::VERSION,
+            Self::ChannelModerateV2(_) => channel::ChannelModerateV2::VERSION,
+        }
+    }
+
+    /// Condition of the subscription
+    pub fn condition(&self) -> Result<serde_json::Value, serde_json::Error> {
+        match self {
+            Self::ChannelCheerV1(s) => s.condition(),
+            Self::ChannelPointsCustomRewardRedemptionAddV1(s) => s.condition(),
+            Self::ChannelPointsCustomRewardRedemptionUpdateV1(s) => s.condition(),
+            Self::ChannelSubscribeV1(s) => s.condition(),
+            Self::ChannelSubscriptionMessageV1(s) => s.condition(),
+            Self::ChannelModerateV2(s) => s.condition(),
+        }
+    }
+}
+
+/// Errors when converting EventSub notifications into PubSub messages
+#[derive(Debug, thiserror::Error, displaydoc::Display)]
+#[non_exhaustive]
+pub enum MigrateError {
+    /// PubSub has no equivalent message for this event
+    Unsupported,
+    /// id `{0}` can not be used in a PubSub topic
+    InvalidId(types::UserId),
+    /// timestamp `{0}` could not be parsed
+    InvalidTimestamp(types::Timestamp),
+    /// could not create PubSub message
+    Message(#[from] serde_json::Error),
+}
+
+/// An EventSub notification that can be converted into the message PubSub would have sent.
+pub trait IntoPubSub {
+    /// The PubSub message
+    type Reply;
+
+    /// Convert the notification, `timestamp` is the time the EventSub message was sent.
+    fn into_pubsub(self, timestamp: &types::TimestampRef) -> Result<Self::Reply, MigrateError>;
+}
+
+/// Convert an EventSub notification into the message PubSub would have sent on the [topic it replaces](subscriptions).
+///
+/// `timestamp` is the time the EventSub message was sent, e.g the `Twitch-Eventsub-Message-Timestamp` header
+/// or the [`message_timestamp`](eventsub::NotificationMetadata::message_timestamp) of a websocket notification.
+pub fn topic_data(
+    event: eventsub::Event,
+    timestamp: &types::TimestampRef,
+) -> Result<TopicData, MigrateError> {
+    use eventsub::{Event, Message, Payload};
+
+    fn notification<E: EventSubscription + Clone>(
+        payload: Payload<E>,
+    ) -> Result<(E, E::Payload), MigrateError> {
+        match payload.message {
+            Message::Notification(notification) => {
+                Ok((payload.subscription.condition, notification))
+            }
+            _ => Err(MigrateError::Unsupported),
+        }
+    }
+
+    Ok(match event {
+        Event::ChannelCheerV1(payload) => {
+            let (condition, notification) = notification(payload)?;
+            TopicData::ChannelBitsEventsV2 {
+                topic: channel_bits::ChannelBitsEventsV2 {
+                    channel_id: topic_id(&condition.broadcaster_user_id)?,
+                },
+                reply: Box::new(notification.into_pubsub(timestamp)?),
+            }
+        }
+        Event::ChannelPointsCustomRewardRedemptionAddV1(payload) => {
+            let (condition, notification) = notification(payload)?;
+            TopicData::ChannelPointsChannelV1 {
+                topic: channel_points::ChannelPointsChannelV1 {
+                    channel_id: topic_id(&condition.broadcaster_user_id)?,
+                },
+                reply: Box::new(notification.into_pubsub(timestamp)?),
+            }
+        }
+        Event::ChannelPointsCustomRewardRedemptionUpdateV1(payload) => {
+            let (condition, notification) = notification(payload)?;
+            TopicData::ChannelPointsChannelV1 {
+                topic: channel_points::ChannelPointsChannelV1 {
+                    channel_id: topic_id(&condition.broadcaster_user_id)?,
+                },
+                reply: Box::new(notification.into_pubsub(timestamp)?),
+            }
+        }
+        Event::ChannelSubscribeV1(payload) => {
+            let (condition, notification) = notification(payload)?;
+            TopicData::ChannelSubscribeEventsV1 {
+                topic: channel_subscriptions::ChannelSubscribeEventsV1 {
+                    channel_id: topic_id(&condition.broadcaster_user_id)?,
+                },
+                reply: Box::new(notification.into_pubsub(timestamp)?),
+            }
+        }
+        Event::ChannelSubscriptionMessageV1(payload) => {
+            let (condition, notification) = notification(payload)?;
+            TopicData::ChannelSubscribeEventsV1 {
+                topic: channel_subscriptions::ChannelSubscribeEventsV1 {
+                    channel_id: topic_id(&condition.broadcaster_user_id)?,
+                },
+                reply: Box::new(notification.into_pubsub(timestamp)?),
+            }
+        }
+        Event::ChannelModerateV2(payload) => {
+            let (condition, notification) = notification(payload)?;
+            TopicData::ChatModeratorActions {
+                topic: moderation::ChatModeratorActions {
+                    user_id: topic_id(&condition.moderator_user_id)?,
+                    channel_id: topic_id(&condition.broadcaster_user_id)?,
+                },
+                reply: Box::new(notification.into_pubsub(timestamp)?),
+            }
+        }
+        _ => return Err(MigrateError::Unsupported),
+    })
+}
+
+fn topic_id(id: &types::UserIdRef) -> Result<u32, MigrateError> {
+    id.as_str()
+        .parse()
+        .map_err(|_| MigrateError::InvalidId(id.to_owned()))
+}
+
+/// Converts into a [`bits_event`](channel_bits::ChannelBitsEventsV2Reply::BitsEvent).
+///
+/// `total_bits_used` and `message_id` are not available in EventSub and are set to `0` and an empty string.
+/// Anonymous cheers have an empty `user_id` and `user_name`.
+impl IntoPubSub for channel::ChannelCheerV1Payload {
+    type Reply = channel_bits::ChannelBitsEventsV2Reply;
+
+    fn into_pubsub(self, timestamp: &types::TimestampRef) -> Result<Self::Reply, MigrateError> {
+        Ok(serde_json::from_value(json!({
+            "message_type": "bits_event",
+            "message_id": "",
+            "version": "1.0",
+            "is_anonymous": self.is_anonymous,
+            "data": {
+                "badge_entitlement": null,
+                "bits_used": self.bits,
+                "channel_id": self.broadcaster_user_id,
+                "channel_name": self.broadcaster_user_login,
+                "chat_message": self.message,
+                "context": "cheer",
+                "is_anonymous": self.is_anonymous,
+                "time": timestamp,
+                "total_bits_used": 0,
+                "user_id": self.user_id.as_ref().map_or("", |id| id.as_str()),
+                "user_name": self.user_login.as_ref().map_or("", |login| login.as_str()),
+            },
+        }))?)
+    }
+}
+
+/// Converts into a [`sub`](channel_subscriptions::ChannelSubscribeEventsV1Reply::Sub).
+///
+/// Gifted subscriptions return [`MigrateError::Unsupported`], as EventSub doesn't say who gifted them.
+impl IntoPubSub for channel::ChannelSubscribeV1Payload {
+    type Reply = channel_subscriptions::ChannelSubscribeEventsV1Reply;
+
+    fn into_pubsub(self, timestamp: &types::TimestampRef) -> Result<Self::Reply, MigrateError> {
+        if self.is_gift {
+            return Err(MigrateError::Unsupported);
+        }
+        Ok(serde_json::from_value(json!({
+            "context": "sub",
+            "benefit_end_month": 0,
+            "channel_id": self.broadcaster_user_id,
+            "channel_name": self.broadcaster_user_login,
+            "cumulative_months": 1,
+            "is_gift": false,
+            "months": 0,
+            "multi_month_duration": 1,
+            "sub_message": { "emotes": [], "message": "" },
+            "sub_plan": self.tier,
+            "sub_plan_name": sub_plan_name(&self.broadcaster_user_login),
+            "time": timestamp,
+            "user_id": self.user_id,
+            "user_name": self.user_login,
+            "display_name": self.user_name,
+        }))?)
+    }
+}
+
+/// Converts into a [`resub`](channel_subscriptions::ChannelSubscribeEventsV1Reply::ReSub).
+impl IntoPubSub for channel::ChannelSubscriptionMessageV1Payload {
+    type Reply = channel_subscriptions::ChannelSubscribeEventsV1Reply;
+
+    fn into_pubsub(self, timestamp: &types::TimestampRef) -> Result<Self::Reply, MigrateError> {
+        let emotes = self
+            .message
+            .emotes
+            .iter()
+            .map(|emote| json!({ "id": emote.id, "start": emote.begin, "end": emote.end }))
+            .collect::<Vec<_>>();
+        Ok(serde_json::from_value(json!({
+            "context": "resub",
+            "benefit_end_month": null,
+            "channel_id": self.broadcaster_user_id,
+            "channel_name": self.broadcaster_user_login,
+            "cumulative_months": self.cumulative_months,
+            "is_gift": false,
+            "streak_months": self.streak_months,
+            "months": 0,
+            "multi_month_duration": self.duration_months,
+            "sub_message": { "emotes": emotes, "message": self.message.text },
+            "sub_plan": self.tier,
+            "sub_plan_name": sub_plan_name(&self.broadcaster_user_login),
+            "time": timestamp,
+            "user_id": self.user_id,
+            "user_name": self.user_login,
+            "display_name": self.user_name,
+        }))?)
+    }
+}
+
+fn sub_plan_name(channel: &types::UserNameRef) -> String {
+    format!("Channel Subscription ({channel})")
+}
+
+/// Converts into a [`reward-redeemed`](channel_points::ChannelPointsChannelV1Reply::RewardRedeemed).
+///
+/// EventSub only has the id, title, prompt and cost of the reward, other fields of the reward are set to their defaults.
+impl IntoPubSub for channel::ChannelPointsCustomRewardRedemptionAddV1Payload {
+    type Reply = channel_points::ChannelPointsChannelV1Reply;
+
+    fn into_pubsub(self, timestamp: &types::TimestampRef) -> Result<Self::Reply, MigrateError> {
+        use channel::channel_points_custom_reward_redemption::RedemptionStatus;
+
+        let status = match self.status {
+            RedemptionStatus::Fulfilled => "FULFILLED",
+            RedemptionStatus::Unfulfilled => "UNFULFILLED",
+            _ => "ACTION_TAKEN",
+        };
+        Ok(serde_json::from_value(json!({
+            "type": "reward-redeemed",
+            "data": {
+                "timestamp": timestamp,
+                "redemption": redemption(
+                    &self.broadcaster_user_id,
+                    &self.id,
+                    &self.redeemed_at,
+                    &self.reward,
+                    status,
+                    (&self.user_id, &self.user_login, &self.user_name),
+                    &self.user_input,
+                ),
+            },
+        }))?)
+    }
+}
+
+/// Converts into a [`redemption-status-update`](channel_points::ChannelPointsChannelV1Reply::RedemptionStatusUpdate).
+///
+/// EventSub only has the id, title, prompt and cost of the reward, other fields of the reward are set to their defaults.
+impl IntoPubSub for channel::ChannelPointsCustomRewardRedemptionUpdateV1Payload {
+    type Reply = channel_points::ChannelPointsChannelV1Reply;
+
+    fn into_pubsub(self, timestamp: &types::TimestampRef) -> Result<Self::Reply, MigrateError> {
+        Ok(serde_json::from_value(json!({
+            "type": "redemption-status-update",
+            "data": {
+                "timestamp": timestamp,
+                "redemption": redemption(
+                    &self.broadcaster_user_id,
+                    &self.id,
+                    &self.redeemed_at,
+                    &self.reward,
+                    "ACTION_TAKEN",
+                    (&self.user_id, &self.user_login, &self.user_name),
+                    &self.user_input,
+                ),
+            },
+        }))?)
+    }
+}
+
+fn redemption(
+    channel_id: &types::UserIdRef,
+    id: &types::RedemptionIdRef,
+    redeemed_at: &types::TimestampRef,
+    reward: &channel::channel_points_custom_reward_redemption::Reward,
+    status: &str,
+    (user_id, user_login, user_name): (
+        &types::UserIdRef,
+        &types::UserNameRef,
+        &types::DisplayNameRef,
+    ),
+    user_input: &str,
+) -> serde_json::Value {
+    json!({
+        "channel_id": channel_id,
+        "id": id,
+        "redeemed_at": redeemed_at,
+        "reward": {
+            "background_color": "",
+            "channel_id": channel_id,
+            "cooldown_expires_at": null,
+            "cost": reward.cost,
+            "default_image": null,
+            "global_cooldown": { "is_enabled": false, "global_cooldown_seconds": 0 },
+            "id": reward.id,
+            "image": null,
+            "is_enabled": true,
+            "is_in_stock": true,
+            "is_paused": false,
+            "is_sub_only": false,
+            "is_user_input_required": !user_input.is_empty(),
+            "max_per_stream": { "is_enabled": false, "max_per_stream": 0 },
+            "max_per_user_per_stream": { "is_enabled": false, "max_per_user_per_stream": 0 },
+            "prompt": reward.prompt,
+            "redemptions_redeemed_current_stream": null,
+            "should_redemptions_skip_request_queue": false,
+            "template_id": null,
+            "title": reward.title,
+            "updated_for_indicator_at": null,
+        },
+        "status": status,
+        "user": { "id": user_id, "login": user_login, "display_name": user_name },
+        "user_input": (!user_input.is_empty()).then_some(user_input),
+        "cursor": null,
+    })
+}
+
+/// Whole seconds from `start` until `end`, rounded to the nearest second
+fn seconds_between(
+    start: &types::TimestampRef,
+    end: &types::TimestampRef,
+) -> Result<i64, MigrateError> {
+    let nanos = |timestamp: &types::TimestampRef| {
+        calendar::parse_rfc3339(timestamp.as_str())
+            .map(|(secs, nanos)| i128::from(secs) * 1_000_000_000 + i128::from(nanos))
+            .ok_or_else(|| MigrateError::InvalidTimestamp(timestamp.to_owned()))
+    };
+    let duration = nanos(end)? - nanos(start)?;
+    Ok(((duration + 500_000_000).div_euclid(1_000_000_000)) as i64)
+}
+
+/// Converts into a [`ChatModeratorActionsReply`](moderation::ChatModeratorActionsReply).
+///
+/// The duration of timeouts is the time from the message `timestamp` until the timeout [expires](channel::moderate::Timeout::expires_at), in seconds.
+/// Changes to automod terms, warnings and actions in shared chat return [`MigrateError::Unsupported`].
+impl IntoPubSub for channel::ChannelModerateV2Payload {
+    type Reply = moderation::ChatModeratorActionsReply;
+
+    fn into_pubsub(self, timestamp: &types::TimestampRef) -> Result<Self::Reply, MigrateError> {
+        use channel::moderate::ActionV2;
+
+        // (moderation_action, args, target user)
+        let (action, args, target): (
+            &str,
+            Vec<String>,
+            Option<(&types::UserId, &types::UserName)>,
+        ) = match &self.action {
+            ActionV2::Mod(channel::moderate::Mod {
+                user_id,
+                user_login,
+                ..
+            })
+            | ActionV2::Unmod(channel::moderate::Unmod {
+                user_id,
+                user_login,
+                ..
+            }) => {
+                let (type_, action) = if matches!(self.action, ActionV2::Mod(_)) {
+                    ("moderator_added", "mod")
+                } else {
+                    ("moderator_removed", "unmod")
+                };
+                return Ok(serde_json::from_value(json!({
+                    "type": type_,
+                    "data": {
+                        "channel_id": self.broadcaster_user_id,
+                        "target_user_id": user_id,
+                        "moderation_action": action,
+                        "target_user_login": user_login,
+                        "created_by_user_id": self.moderator_user_id,
+                        "created_by": self.moderator_user_login,
+                    },
+                }))?);
+            }
+            ActionV2::ApproveUnbanRequest(request) | ActionV2::DenyUnbanRequest(request) => {
+                let (type_, action) = if request.is_approved {
+                    ("approve_unban_request", "APPROVE_UNBAN_REQUEST")
+                } else {
+                    ("deny_unban_request", "DENY_UNBAN_REQUEST")
+                };
+                return Ok(serde_json::from_value(json!({
+                    "type": type_,
+                    "data": {
+                        "created_by_id": self.moderator_user_id,
+                        "created_by_login": self.moderator_user_login,
+                        "moderation_action": action,
+                        "moderator_message": request.moderator_message,
+                        "target_user_id": request.user_id,
+                        "target_user_login": request.user_login,
+                    },
+                }))?);
+            }
+            ActionV2::Followers(followers) => (
+                "followers",
+                vec![followers.follow_duration_minutes.to_string()],
+                None,
+            ),
+            ActionV2::FollowersOff => ("followersoff", vec![], None),
+            ActionV2::Slow(slow) => ("slow", vec![slow.wait_time_seconds.to_string()], None),
+            ActionV2::SlowOff => ("slowoff", vec![], None),
+            ActionV2::EmoteOnly => ("emoteonly", vec![], None),
+            ActionV2::EmoteOnlyOff => ("emoteonlyoff", vec![], None),
+            ActionV2::Subscribers => ("subscribers", vec![], None),
+            ActionV2::SubscribersOff => ("subscribersoff", vec![], None),
+            ActionV2::Uniquechat => ("r9kbeta", vec![], None),
+            ActionV2::UniquechatOff => ("r9kbetaoff", vec![], None),
+            ActionV2::Raid(raid) => ("raid", vec![raid.user_login.to_string()], None),
+            ActionV2::Unraid(unraid) => ("unraid", vec![unraid.user_login.to_string()], None),
+            ActionV2::Vip(vip) => (
+                "vip",
+                vec![vip.user_login.to_string()],
+                Some((&vip.user_id, &vip.user_login)),
+            ),
+            ActionV2::Unvip(unvip) => (
+                "unvip",
+                vec![unvip.user_login.to_string()],
+                Some((&unvip.user_id, &unvip.user_login)),
+            ),
+            ActionV2::Ban(ban) => (
+                "ban",
+                vec![
+                    ban.user_login.to_string(),
+                    ban.reason.clone().unwrap_or_default(),
+                ],
+                Some((&ban.user_id, &ban.user_login)),
+            ),
+            ActionV2::Unban(unban) => (
+                "unban",
+                vec![unban.user_login.to_string()],
+                Some((&unban.user_id, &unban.user_login)),
+            ),
+            ActionV2::Timeout(timeout) => (
+                "timeout",
+                vec![
+                    timeout.user_login.to_string(),
+                    seconds_between(timestamp, &timeout.expires_at)?.to_string(),
+                    timeout.reason.clone().unwrap_or_default(),
+                ],
+                Some((&timeout.user_id, &timeout.user_login)),
+            ),
+            ActionV2::Untimeout(untimeout) => (
+                "untimeout",
+                vec![untimeout.user_login.to_string()],
+                Some((&untimeout.user_id, &untimeout.user_login)),
+            ),
+            ActionV2::Delete(delete) => (
+                "delete",
+                vec![
+                    delete.user_login.to_string(),
+                    delete.message_body.clone(),
+                    delete.message_id.to_string(),
+                ],
+                Some((&delete.user_id, &delete.user_login)),
+            ),
+            _ => return Err(MigrateError::Unsupported),
+        };
+        let type_ = if target.is_some() {
+            "chat_login_moderation"
+        } else {
+            "chat_channel_moderation"
+        };
+        let (target_user_id, target_user_login) =
+            target.map_or(("", ""), |(id, login)| (id.as_str(), login.as_str()));
+        Ok(serde_json::from_value(json!({
+            "type": "moderation_action",
+            "data": {
+                "type": type_,
+                "moderation_action": action,
+                "args": args,
+                "created_by": self.moderator_user_login,
+                "created_by_user_id": self.moderator_user_id,
+                "created_at": timestamp,
+                "msg_id": "",
+                "target_user_id": target_user_id,
+                "target_user_login": target_user_login,
+                "from_automod": false,
+            },
+        }))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eventsub::Event;
+
+    fn timestamp() -> types::Timestamp {
+        types::Timestamp::from_static("2024-11-27T20:01:50.171283653Z")
+    }
+
+    #[test]
+    fn subscriptions_for_topics() {
+        let topic = moderation::ChatModeratorActions {
+            user_id: 1234,
+            channel_id: 5678,
+        };
+        let subs = subscriptions(&topic.into());
+        assert_eq!(
+            subs,
+            [Subscription::ChannelModerateV2(
+                channel::ChannelModerateV2::new("5678", "1234")
+            )]
+        );
+        assert_eq!(subs[0].event_type(), EventType::ChannelModerate);
+        assert_eq!(subs[0].version(), "2");
+
+        let topic = channel_points::ChannelPointsChannelV1 { channel_id: 1234 };
+        assert_eq!(subscriptions(&topic.into()).len(), 2);
+    }
+
+    #[test]
+    fn cheer() {
+        let event = Event::parse(r#"{"subscription":{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","type":"channel.cheer","version":"1","status":"enabled","cost":0,"condition":{"broadcaster_user_id":"1337"},"transport":{"method":"webhook","callback":"https://example.com/webhooks/callback"},"created_at":"2019-11-16T10:11:12.634234626Z"},"event":{"is_anonymous":false,"user_id":"1234","user_login":"cool_user","user_name":"Cool_User","broadcaster_user_id":"1337","broadcaster_user_login":"cooler_user","broadcaster_user_name":"Cooler_User","message":"cheer100 pogchamp","bits":100}}"#).unwrap();
+        let TopicData::ChannelBitsEventsV2 { topic, reply } =
+            topic_data(event, &timestamp()).unwrap()
+        else {
+            panic!("wrong topic")
+        };
+        assert_eq!(topic.channel_id, 1337);
+        let channel_bits::ChannelBitsEventsV2Reply::BitsEvent { data, .. } = *reply;
+        assert_eq!(data.bits_used, 100);
+        assert_eq!(data.user_id.as_str(), "1234");
+        assert_eq!(data.chat_message, "cheer100 pogchamp");
+    }
+
+    #[test]
+    fn moderate_timeout() {
+        let event = Event::parse(r#"{"subscription":{"id":"5a893cd8-c10e-4198-a620-e9f35fd6ccd6","status":"enabled","type":"channel.moderate","version":"2","condition":{"broadcaster_user_id":"129546453","moderator_user_id":"129546453"},"transport":{"method":"websocket","session_id":"AgoQMmDnfVEhRI6iLiBniEWHkxIGY2VsbC1j"},"created_at":"2024-11-27T20:01:50.171283653Z","cost":0},"event":{"broadcaster_user_id":"129546453","broadcaster_user_login":"nerixyz","broadcaster_user_name":"nerixyz","source_broadcaster_user_id":null,"source_broadcaster_user_login":null,"source_broadcaster_user_name":null,"moderator_user_id":"129546453","moderator_user_login":"nerixyz","moderator_user_name":"nerixyz","action":"timeout","followers":null,"slow":null,"vip":null,"unvip":null,"mod":null,"unmod":null,"ban":null,"unban":null,"timeout":{"user_id":"141981764","user_login":"twitchdev","user_name":"TwitchDev","reason":"test Kappa","expires_at":"2024-11-27T20:01:55.358149527Z"},"untimeout":null,"raid":null,"unraid":null,"delete":null,"automod_terms":null,"unban_request":null,"warn":null,"shared_chat_ban":null,"shared_chat_unban":null,"shared_chat_timeout":null,"shared_chat_untimeout":null,"shared_chat_delete":null}}"#).unwrap();
+        let TopicData::ChatModeratorActions { topic, reply } =
+            topic_data(event, &timestamp()).unwrap()
+        else {
+            panic!("wrong topic")
+        };
+        assert_eq!(topic.user_id, 129546453);
+        let moderation::ChatModeratorActionsReply::ModerationAction(action) = *reply else {
+            panic!("wrong reply")
+        };
+        assert_eq!(
+            action.moderation_action,
+            moderation::ModerationActionCommand::Timeout
+        );
+        assert_eq!(action.target_user_id.as_str(), "141981764");
+        assert_eq!(action.args, ["twitchdev", "5", "test Kappa"]);
+    }
+}
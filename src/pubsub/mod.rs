@@ -1,7 +1,10 @@
 #![allow(deprecated)]
 //! Holds serializable pubsub stuff
 //!
-//! Use [`listen_command()`] to send subscription listen and parse the responses with [`Response::parse`]
+//! Use [`listen_command()`] to send subscription listen and parse the responses with [`Response::parse`],
+//! or use the [`Client`](client::Client) behind feature `pubsub_websocket` to manage the connections for you.
+//!
+//! PubSub is being replaced by [EventSub](crate::eventsub), see [`migrate`] for converting EventSub notifications into PubSub messages.
 //!
 //! # Undocumented features
 //!
@@ -92,12 +95,18 @@ pub mod channel_points;
 #[cfg(feature = "unsupported")]
 pub mod channel_sub_gifts;
 pub mod channel_subscriptions;
+#[cfg(feature = "pubsub_websocket")]
+#[cfg_attr(nightly, doc(cfg(feature = "pubsub_websocket")))]
+pub mod client;
 #[cfg(feature = "unsupported")]
 pub mod community_points;
 #[cfg(feature = "unsupported")]
 pub mod following;
 #[cfg(feature = "unsupported")]
 pub mod hypetrain;
+#[cfg(feature = "eventsub")]
+#[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
+pub mod migrate;
 pub mod moderation;
 #[cfg(feature = "unsupported")]
 pub mod raid;
@@ -105,6 +114,11 @@ pub mod user_moderation_notifications;
 #[cfg(feature = "unsupported")]
 pub mod video_playback;
 
+#[cfg(feature = "pubsub_websocket")]
+#[cfg_attr(nightly, doc(cfg(feature = "pubsub_websocket")))]
+#[doc(inline)]
+pub use client::Client;
+
 use crate::parse_json;
 
 /// A logical partition of messages that clients may subscribe to, to get messages.